@@ -0,0 +1,68 @@
+//!
+//! The `solc --combined-json` output.
+//!
+
+pub mod contract;
+pub mod selector;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use self::contract::Contract;
+
+///
+/// The `solc --combined-json` output.
+///
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct CombinedJson {
+    /// The contract entries, keyed by the full contract path.
+    pub contracts: BTreeMap<String, Contract>,
+    /// The `solc` compiler version.
+    pub version: String,
+}
+
+impl CombinedJson {
+    /// The output file name.
+    pub const FILE_NAME: &'static str = "combined.json";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(version: String) -> Self {
+        Self {
+            contracts: BTreeMap::new(),
+            version,
+        }
+    }
+
+    ///
+    /// Writes the JSON to the terminal.
+    ///
+    pub fn write_to_terminal(&self) -> anyhow::Result<()> {
+        serde_json::to_writer(std::io::stdout(), self)
+            .map_err(|error| anyhow::anyhow!("Stdout writing error: {error}"))
+    }
+
+    ///
+    /// Writes the JSON to the `combined.json` file in the specified directory.
+    ///
+    pub fn write_to_directory(
+        &self,
+        output_directory: &Path,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        let output_path = output_directory.join(Self::FILE_NAME);
+        if output_path.exists() && !overwrite {
+            anyhow::bail!(
+                "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
+            );
+        }
+
+        std::fs::create_dir_all(output_directory)?;
+        std::fs::write(
+            output_path.as_path(),
+            serde_json::to_vec(self).expect("Always valid"),
+        )
+        .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))
+    }
+}
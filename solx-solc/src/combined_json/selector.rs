@@ -0,0 +1,90 @@
+//!
+//! The `solc --combined-json` expected output selector.
+//!
+
+use std::collections::BTreeSet;
+
+use crate::standard_json::input::settings::selection::selector::Selector as StandardJsonInputSettingsSelector;
+
+///
+/// The `solc --combined-json` expected output selector.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Selector {
+    /// The ABI JSON.
+    ABI,
+    /// The deploy bytecode.
+    Bytecode,
+    /// The runtime bytecode.
+    BytecodeRuntime,
+    /// The metadata.
+    Metadata,
+    /// The function signature hashes JSON.
+    Hashes,
+    /// The storage layout.
+    StorageLayout,
+    /// The user documentation.
+    Userdoc,
+    /// The developer documentation.
+    Devdoc,
+}
+
+impl Selector {
+    /// The supported selectors, as accepted on the command line.
+    pub const SUPPORTED: [&'static str; 8] = [
+        "abi",
+        "bin",
+        "bin-runtime",
+        "metadata",
+        "hashes",
+        "storage-layout",
+        "userdoc",
+        "devdoc",
+    ];
+
+    ///
+    /// Parses the comma-separated list of selectors passed via `--combined-json`.
+    ///
+    pub fn from_cli(value: &str) -> anyhow::Result<BTreeSet<Self>> {
+        value
+            .split(',')
+            .map(|selector| selector.trim().parse())
+            .collect()
+    }
+}
+
+impl std::str::FromStr for Selector {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "abi" => Self::ABI,
+            "bin" => Self::Bytecode,
+            "bin-runtime" => Self::BytecodeRuntime,
+            "metadata" => Self::Metadata,
+            "hashes" => Self::Hashes,
+            "storage-layout" => Self::StorageLayout,
+            "userdoc" => Self::Userdoc,
+            "devdoc" => Self::Devdoc,
+            selector => anyhow::bail!(
+                "Invalid option to --combined-json: `{selector}`. Supported options: {}.",
+                Self::SUPPORTED.join(", ")
+            ),
+        })
+    }
+}
+
+impl From<Selector> for StandardJsonInputSettingsSelector {
+    fn from(selector: Selector) -> Self {
+        match selector {
+            Selector::ABI => Self::ABI,
+            Selector::Bytecode => Self::BytecodeObject,
+            Selector::BytecodeRuntime => Self::RuntimeBytecodeObject,
+            Selector::Metadata => Self::Metadata,
+            Selector::Hashes => Self::MethodIdentifiers,
+            Selector::StorageLayout => Self::StorageLayout,
+            Selector::Userdoc => Self::Userdoc,
+            Selector::Devdoc => Self::Devdoc,
+        }
+    }
+}
@@ -9,10 +9,14 @@
 #![allow(clippy::should_implement_trait)]
 #![allow(clippy::result_large_err)]
 
+pub mod combined_json;
 pub mod solc;
 pub mod standard_json;
 pub mod version;
 
+pub use self::combined_json::contract::Contract as CombinedJsonContract;
+pub use self::combined_json::selector::Selector as CombinedJsonSelector;
+pub use self::combined_json::CombinedJson;
 pub use self::solc::Compiler;
 pub use self::standard_json::input::language::Language as StandardJsonInputLanguage;
 pub use self::standard_json::input::settings::libraries::Libraries as StandardJsonInputLibraries;
@@ -20,17 +24,27 @@ pub use self::standard_json::input::settings::metadata::Metadata as StandardJson
 pub use self::standard_json::input::settings::optimizer::Optimizer as StandardJsonInputOptimizer;
 pub use self::standard_json::input::settings::selection::selector::Selector as StandardJsonInputSelector;
 pub use self::standard_json::input::settings::selection::Selection as StandardJsonInputSelection;
+pub use self::standard_json::input::settings::warnings::filter::Filter as StandardJsonInputWarningsFilter;
+pub use self::standard_json::input::settings::warnings::Warnings as StandardJsonInputWarnings;
 pub use self::standard_json::input::settings::Settings as StandardJsonInputSettings;
 pub use self::standard_json::input::source::Source as StandardJsonInputSource;
 pub use self::standard_json::input::Input as StandardJsonInput;
+pub use self::standard_json::output::contract::evm::bytecode::reference::Reference as StandardJsonOutputContractEVMBytecodeReference;
 pub use self::standard_json::output::contract::evm::bytecode::Bytecode as StandardJsonOutputContractEVMBytecode;
 pub use self::standard_json::output::contract::evm::extra_metadata::recursive_function::RecursiveFunction as StandardJsonOutputContractEVMExtraMetadataRecursiveFunction;
 pub use self::standard_json::output::contract::evm::extra_metadata::ExtraMetadata as StandardJsonOutputContractEVMExtraMetadata;
+pub use self::standard_json::output::contract::evm::gas_estimates::creation::Creation as StandardJsonOutputContractEVMGasEstimatesCreation;
+pub use self::standard_json::output::contract::evm::gas_estimates::GasEstimates as StandardJsonOutputContractEVMGasEstimates;
 pub use self::standard_json::output::contract::evm::EVM as StandardJsonOutputContractEVM;
 pub use self::standard_json::output::contract::Contract as StandardJsonOutputContract;
 pub use self::standard_json::output::error::collectable::Collectable as CollectableError;
+pub use self::standard_json::output::error::collected::Collected as CollectedDiagnostics;
+pub use self::standard_json::output::error::collected::Errors as CollectedErrors;
+pub use self::standard_json::output::error::mapped_location::MappedLocation as StandardJsonOutputErrorMappedLocation;
+pub use self::standard_json::output::error::secondary_source_location::SecondarySourceLocation as StandardJsonOutputErrorSecondarySourceLocation;
 pub use self::standard_json::output::error::source_location::SourceLocation as StandardJsonOutputErrorSourceLocation;
 pub use self::standard_json::output::error::Error as StandardJsonOutputError;
+pub use self::standard_json::output::source::lint::Lint as StandardJsonOutputSourceLint;
 pub use self::standard_json::output::Output as StandardJsonOutput;
 pub use self::version::Version;
 
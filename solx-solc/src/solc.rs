@@ -6,10 +6,13 @@ use std::ffi::CStr;
 use std::ffi::CString;
 use std::path::PathBuf;
 
+use crate::standard_json::input::language::Language as StandardJsonInputLanguage;
 use crate::standard_json::input::settings::libraries::Libraries as StandardJsonInputSettingsLibraries;
 use crate::standard_json::input::settings::optimizer::Optimizer as StandardJsonInputSettingsOptimizer;
+use crate::standard_json::input::settings::selection::selector::Selector as StandardJsonInputSettingsSelector;
 use crate::standard_json::input::Input as StandardJsonInput;
 use crate::standard_json::output::error::Error as StandardJsonOutputError;
+use crate::standard_json::output::source::lint::Lint as StandardJsonOutputSourceLint;
 use crate::standard_json::output::Output as StandardJsonOutput;
 use crate::version::Version;
 
@@ -65,12 +68,29 @@ impl Compiler {
         include_paths: Vec<String>,
         allow_paths: Option<String>,
     ) -> anyhow::Result<StandardJsonOutput> {
+        let output_selection = input_json.settings.output_selection.clone();
         input_json.settings.output_selection.retain_solc();
         input_json
             .settings
             .output_selection
             .extend(input_json.settings.via_ir);
 
+        let mut disabled_lints = input_json.settings.solx_disabled_lints.clone();
+        if input_json.settings.via_ir {
+            disabled_lints.insert(StandardJsonOutputSourceLint::InternalFunctionPointer);
+        }
+        let is_ast_requested = input_json
+            .settings
+            .output_selection
+            .contains(&StandardJsonInputSettingsSelector::AST);
+        let is_linting_enabled = input_json.language == StandardJsonInputLanguage::Solidity
+            && StandardJsonOutputSourceLint::ALL
+                .iter()
+                .any(|lint| !disabled_lints.contains(lint));
+        if is_linting_enabled {
+            input_json.settings.output_selection.extend_with_ast();
+        }
+
         let input_string = serde_json::to_string(input_json).expect("Always valid");
         let input_c_string = CString::new(input_string).expect("Always valid");
 
@@ -146,7 +166,15 @@ impl Compiler {
                 None => true,
             });
         solc_output.errors.append(messages);
-        solc_output.preprocess_ast(&input_json.sources, &self.version)?;
+        solc_output.warning_settings = input_json.settings.solx_warnings.clone();
+        solc_output.output_selection = Some(output_selection);
+        solc_output.preprocess_ast(&input_json.sources, &self.version, &disabled_lints)?;
+        if !is_ast_requested {
+            for source in solc_output.sources.values_mut() {
+                source.ast = None;
+            }
+        }
+        solc_output.warning_settings.apply(&mut solc_output.errors);
         solc_output.remove_evm_artifacts();
 
         Ok(solc_output)
@@ -6,13 +6,18 @@ pub mod libraries;
 pub mod metadata;
 pub mod optimizer;
 pub mod selection;
+pub mod warnings;
 
 use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use crate::standard_json::output::source::lint::Lint;
 
 use self::libraries::Libraries;
 use self::metadata::Metadata;
 use self::optimizer::Optimizer;
 use self::selection::Selection;
+use self::warnings::Warnings;
 
 ///
 /// The `solc --standard-json` input settings.
@@ -52,6 +57,18 @@ pub struct Settings {
     /// The extra LLVM options.
     #[serde(default, skip_serializing)]
     pub llvm_options: Vec<String>,
+    /// The persistent compilation cache directory.
+    #[serde(default, skip_serializing)]
+    pub cache_dir: Option<PathBuf>,
+    /// Whether to output the compilation timing and statistics report.
+    #[serde(default, skip_serializing)]
+    pub solx_statistics: bool,
+    /// The solx-specific AST lints to disable.
+    #[serde(default, skip_serializing)]
+    pub solx_disabled_lints: BTreeSet<Lint>,
+    /// The warning suppression and promotion settings.
+    #[serde(default, skip_serializing)]
+    pub solx_warnings: Warnings,
 }
 
 impl Settings {
@@ -86,6 +103,10 @@ impl Settings {
             output_selection,
             metadata,
             llvm_options,
+            cache_dir: None,
+            solx_statistics: false,
+            solx_disabled_lints: BTreeSet::new(),
+            solx_warnings: Warnings::default(),
         }
     }
 
@@ -24,11 +24,18 @@ impl Selection {
     /// A shortcut constructor.
     ///
     pub fn new(via_ir: bool) -> Self {
+        let mut selectors = BTreeSet::new();
+        selectors.insert(via_ir.into());
+        Self::new_required(selectors)
+    }
+
+    ///
+    /// Creates the selection of `selectors` for all contracts.
+    ///
+    pub fn new_required(selectors: BTreeSet<Selector>) -> Self {
         let mut root = BTreeMap::new();
         let mut inner = BTreeMap::new();
-        let mut set = BTreeSet::new();
-        set.insert(via_ir.into());
-        inner.insert("*".to_owned(), set);
+        inner.insert("*".to_owned(), selectors);
         root.insert("*".to_owned(), inner);
         Self { inner: root }
     }
@@ -44,12 +51,28 @@ impl Selection {
         }
     }
 
+    ///
+    /// Requests the AST of all source files.
+    ///
+    pub fn extend_with_ast(&mut self) {
+        for file in self.inner.values_mut() {
+            file.entry(String::new()).or_default().insert(Selector::AST);
+        }
+    }
+
     ///
     /// Retains only the selectors that request data from `solc`.
     ///
+    /// The method identifiers are requested along with gas estimates, as they are used to
+    /// name the external functions. They are pruned from the output of the contracts they
+    /// were not requested for.
+    ///
     pub fn retain_solc(&mut self) {
         for file in self.inner.values_mut() {
             for contract in file.values_mut() {
+                if contract.contains(&Selector::GasEstimates) {
+                    contract.insert(Selector::MethodIdentifiers);
+                }
                 contract.retain(Selector::is_received_from_solc);
             }
         }
@@ -64,9 +87,53 @@ impl Selection {
     pub fn to_prune(&self, via_ir: bool) -> BTreeSet<Selector> {
         let mut selection = BTreeSet::new();
         selection.insert(via_ir.into());
+        for selector in [
+            Selector::BytecodeSourceMap,
+            Selector::RuntimeBytecodeSourceMap,
+            Selector::Assembly,
+            Selector::BytecodeOpcodes,
+            Selector::RuntimeBytecodeOpcodes,
+            Selector::GasEstimates,
+            Selector::MethodIdentifiers,
+        ] {
+            if !self.contains(&selector) && !self.contains(&Selector::EVM) {
+                selection.insert(selector);
+            }
+        }
         selection
     }
 
+    ///
+    /// Whether the selector is requested for at least one contract.
+    ///
+    pub fn contains(&self, selector: &Selector) -> bool {
+        self.inner
+            .values()
+            .flat_map(|file| file.values())
+            .any(|contract| contract.contains(selector))
+    }
+
+    ///
+    /// Whether the selector is requested for the contract `name` in the file `path`.
+    ///
+    pub fn is_selected(&self, path: &str, name: &str, selector: &Selector) -> bool {
+        [path, "*"]
+            .into_iter()
+            .filter_map(|path| self.inner.get(path))
+            .flat_map(|file| [name, "*"].into_iter().filter_map(|name| file.get(name)))
+            .any(|contract| contract.contains(selector) || contract.contains(&Selector::EVM))
+    }
+
+    ///
+    /// Whether at least one selector is requested for at least one contract.
+    ///
+    pub fn has_selectors(&self) -> bool {
+        self.inner
+            .values()
+            .flat_map(|file| file.values())
+            .any(|contract| !contract.is_empty())
+    }
+
     ///
     /// Whether the selection is empty.
     ///
@@ -46,6 +46,25 @@ pub enum Selector {
     /// The runtime bytecode.
     #[serde(rename = "evm.deployedBytecode.object")]
     RuntimeBytecodeObject,
+    /// The deploy bytecode source map.
+    #[serde(rename = "evm.bytecode.sourceMap")]
+    BytecodeSourceMap,
+    /// The runtime bytecode source map.
+    #[serde(rename = "evm.deployedBytecode.sourceMap")]
+    RuntimeBytecodeSourceMap,
+
+    /// The assembly text of the final bytecode.
+    #[serde(rename = "evm.assembly")]
+    Assembly,
+    /// The deploy bytecode opcodes.
+    #[serde(rename = "evm.bytecode.opcodes")]
+    BytecodeOpcodes,
+    /// The runtime bytecode opcodes.
+    #[serde(rename = "evm.deployedBytecode.opcodes")]
+    RuntimeBytecodeOpcodes,
+    /// The gas estimates.
+    #[serde(rename = "evm.gasEstimates")]
+    GasEstimates,
 
     /// The catch-all variant.
     #[serde(other, skip_serializing)]
@@ -59,7 +78,15 @@ impl Selector {
     pub fn is_received_from_solc(&self) -> bool {
         !matches!(
             self,
-            Self::BytecodeObject | Self::RuntimeBytecodeObject | Self::Other
+            Self::BytecodeObject
+                | Self::RuntimeBytecodeObject
+                | Self::BytecodeSourceMap
+                | Self::RuntimeBytecodeSourceMap
+                | Self::Assembly
+                | Self::BytecodeOpcodes
+                | Self::RuntimeBytecodeOpcodes
+                | Self::GasEstimates
+                | Self::Other
         )
     }
 }
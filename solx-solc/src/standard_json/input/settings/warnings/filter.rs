@@ -0,0 +1,109 @@
+//!
+//! The `solc --standard-json` input settings warning filter.
+//!
+
+use std::str::FromStr;
+
+use crate::standard_json::output::error::Error as StandardJsonOutputError;
+
+///
+/// The `solc --standard-json` input settings warning filter.
+///
+/// Matches warnings by error code and by source path glob. The absent fields match any warning.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Filter {
+    /// The warning error code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The source path glob.
+    ///
+    /// `*` and `?` do not match `/`, whereas `**` matches any sequence of characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+impl Filter {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(code: Option<String>, path: Option<String>) -> Self {
+        Self { code, path }
+    }
+
+    ///
+    /// Whether the filter matches the message.
+    ///
+    /// The messages without a source location never match filters with a path.
+    ///
+    pub fn matches(&self, message: &StandardJsonOutputError) -> bool {
+        if let Some(code) = self.code.as_deref() {
+            if message.error_code.as_deref() != Some(code) {
+                return false;
+            }
+        }
+        if let Some(path) = self.path.as_deref() {
+            match message.source_location.as_ref() {
+                Some(source_location) => {
+                    if !Self::matches_glob(path.as_bytes(), source_location.file.as_bytes()) {
+                        return false;
+                    }
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+
+    ///
+    /// Matches the path against the glob pattern.
+    ///
+    fn matches_glob(pattern: &[u8], path: &[u8]) -> bool {
+        match pattern {
+            [] => path.is_empty(),
+            [b'*', b'*', rest @ ..] => {
+                let rest = rest.strip_prefix(b"/").unwrap_or(rest);
+                (0..=path.len()).any(|index| Self::matches_glob(rest, &path[index..]))
+            }
+            [b'*', rest @ ..] => (0..=path.len())
+                .take_while(|&index| index == 0 || path[index - 1] != b'/')
+                .any(|index| Self::matches_glob(rest, &path[index..])),
+            [b'?', rest @ ..] => match path {
+                [character, path @ ..] if *character != b'/' => Self::matches_glob(rest, path),
+                _ => false,
+            },
+            [character, rest @ ..] => match path {
+                [path_character, path @ ..] if path_character == character => {
+                    Self::matches_glob(rest, path)
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+impl FromStr for Filter {
+    type Err = anyhow::Error;
+
+    ///
+    /// Parses the `<code>`, `<code>:<path glob>`, or `*:<path glob>` filter.
+    ///
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (code, path) = match string.split_once(':') {
+            Some((code, path)) => (code, Some(path)),
+            None => (string, None),
+        };
+        if code.is_empty() || path.is_some_and(|path| path.is_empty()) {
+            anyhow::bail!(
+                "Invalid warning filter `{string}`. Expected `<code>`, `<code>:<path glob>`, or `*:<path glob>`."
+            );
+        }
+
+        let code = match code {
+            "*" => None,
+            code => Some(code.to_owned()),
+        };
+        Ok(Self::new(code, path.map(|path| path.to_owned())))
+    }
+}
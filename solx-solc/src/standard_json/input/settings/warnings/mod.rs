@@ -0,0 +1,79 @@
+//!
+//! The `solc --standard-json` input settings warnings.
+//!
+
+pub mod filter;
+
+use crate::standard_json::output::error::Error as StandardJsonOutputError;
+
+use self::filter::Filter;
+
+///
+/// The `solc --standard-json` input settings warnings.
+///
+/// The filters are checked in the following order: `ignore`, `allow`, `deny`.
+/// The first matching group decides whether the warning is removed, kept, or promoted to an error.
+/// The warnings that do not match any filter are promoted to errors if `denyAll` is set.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Warnings {
+    /// The filters of warnings to remove.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<Filter>,
+    /// The filters of warnings to keep as warnings.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<Filter>,
+    /// The filters of warnings to promote to errors.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<Filter>,
+    /// Whether to promote all warnings to errors.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deny_all: bool,
+}
+
+impl Warnings {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(ignore: Vec<Filter>, allow: Vec<Filter>, deny: Vec<Filter>, deny_all: bool) -> Self {
+        Self {
+            ignore,
+            allow,
+            deny,
+            deny_all,
+        }
+    }
+
+    ///
+    /// Whether the settings leave warnings unchanged.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.ignore.is_empty() && self.allow.is_empty() && self.deny.is_empty() && !self.deny_all
+    }
+
+    ///
+    /// Removes the ignored warnings and promotes the denied ones to errors.
+    ///
+    pub fn apply(&self, messages: &mut Vec<StandardJsonOutputError>) {
+        if self.is_empty() {
+            return;
+        }
+
+        messages.retain(|message| {
+            message.severity != "warning"
+                || !self.ignore.iter().any(|filter| filter.matches(message))
+        });
+        for message in messages
+            .iter_mut()
+            .filter(|message| message.severity == "warning")
+        {
+            if self.allow.iter().any(|filter| filter.matches(message)) {
+                continue;
+            }
+            if self.deny_all || self.deny.iter().any(|filter| filter.matches(message)) {
+                message.promote_to_error();
+            }
+        }
+    }
+}
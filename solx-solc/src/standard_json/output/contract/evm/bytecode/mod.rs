@@ -0,0 +1,110 @@
+//!
+//! The `solc --standard-json` output contract EVM bytecode.
+//!
+
+pub mod reference;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use self::reference::Reference;
+
+///
+/// The `solc --standard-json` output contract EVM bytecode.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bytecode {
+    /// Bytecode object.
+    pub object: String,
+
+    /// Unlinked deployable references.
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeSet::is_empty",
+        skip_deserializing
+    )]
+    pub unlinked_references: BTreeSet<String>,
+    /// The library references in the linked bytecode, keyed by the source file and library name.
+    #[serde(default, skip_deserializing)]
+    pub link_references: BTreeMap<String, BTreeMap<String, Vec<Reference>>>,
+    /// The immutable references, keyed by the immutable identifier.
+    /// Only set for the runtime bytecode.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub immutable_references: Option<BTreeMap<String, Vec<Reference>>>,
+    /// Binary object format.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub format: Option<era_compiler_common::ObjectFormat>,
+    /// The `solc`-compatible compressed source map.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub source_map: Option<String>,
+    /// The space-separated list of opcodes.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub opcodes: Option<String>,
+}
+
+impl Bytecode {
+    /// The length of a library address reference.
+    pub const LINK_REFERENCE_LENGTH: usize = era_compiler_common::BYTE_LENGTH_ETH_ADDRESS;
+
+    /// The length of an immutable value reference.
+    pub const IMMUTABLE_REFERENCE_LENGTH: usize = era_compiler_common::BYTE_LENGTH_FIELD;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The `link_references` are keyed by the library full paths, e.g. `Library.sol:Library`.
+    ///
+    pub fn new(
+        object: String,
+        unlinked_references: BTreeSet<String>,
+        link_references: BTreeMap<String, BTreeSet<usize>>,
+        immutable_references: Option<BTreeMap<String, BTreeSet<u64>>>,
+        format: era_compiler_common::ObjectFormat,
+        source_map: Option<String>,
+        opcodes: Option<String>,
+    ) -> Self {
+        let mut link_references_by_file: BTreeMap<String, BTreeMap<String, Vec<Reference>>> =
+            BTreeMap::new();
+        for (full_path, offsets) in link_references.into_iter() {
+            let (path, name) = full_path
+                .rsplit_once(':')
+                .unwrap_or(("", full_path.as_str()));
+            link_references_by_file
+                .entry(path.to_owned())
+                .or_default()
+                .insert(
+                    name.to_owned(),
+                    offsets
+                        .into_iter()
+                        .map(|offset| Reference::new(offset, Self::LINK_REFERENCE_LENGTH))
+                        .collect(),
+                );
+        }
+
+        let immutable_references = immutable_references.map(|immutable_references| {
+            immutable_references
+                .into_iter()
+                .map(|(identifier, offsets)| {
+                    let references = offsets
+                        .into_iter()
+                        .map(|offset| {
+                            Reference::new(offset as usize, Self::IMMUTABLE_REFERENCE_LENGTH)
+                        })
+                        .collect();
+                    (identifier, references)
+                })
+                .collect()
+        });
+
+        Self {
+            object,
+            unlinked_references,
+            link_references: link_references_by_file,
+            immutable_references,
+            format: Some(format),
+            source_map,
+            opcodes,
+        }
+    }
+}
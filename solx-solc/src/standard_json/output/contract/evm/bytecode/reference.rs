@@ -0,0 +1,25 @@
+//!
+//! The `solc --standard-json` output contract EVM bytecode reference.
+//!
+
+///
+/// The `solc --standard-json` output contract EVM bytecode reference.
+///
+/// Describes a bytecode range which is filled with a library address or an immutable value.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Reference {
+    /// The range start offset.
+    pub start: usize,
+    /// The range length.
+    pub length: usize,
+}
+
+impl Reference {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(start: usize, length: usize) -> Self {
+        Self { start, length }
+    }
+}
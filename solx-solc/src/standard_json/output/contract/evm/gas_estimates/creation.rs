@@ -0,0 +1,34 @@
+//!
+//! The `solc --standard-json` output contract EVM deploy code gas estimates.
+//!
+
+use crate::standard_json::output::contract::evm::gas_estimates::GasEstimates;
+
+///
+/// The `solc --standard-json` output contract EVM deploy code gas estimates.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Creation {
+    /// The cost of storing the runtime code.
+    pub code_deposit_cost: String,
+    /// The cost of executing the deploy code.
+    pub execution_cost: String,
+    /// The sum of the above.
+    pub total_cost: String,
+}
+
+impl Creation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(code_deposit_cost: u64, execution_cost: Option<u64>) -> Self {
+        Self {
+            code_deposit_cost: code_deposit_cost.to_string(),
+            execution_cost: GasEstimates::cost_to_string(execution_cost),
+            total_cost: GasEstimates::cost_to_string(
+                execution_cost.map(|execution_cost| code_deposit_cost + execution_cost),
+            ),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//!
+//! The `solc --standard-json` output contract EVM gas estimates.
+//!
+
+pub mod creation;
+
+use std::collections::BTreeMap;
+
+use self::creation::Creation;
+
+///
+/// The `solc --standard-json` output contract EVM gas estimates.
+///
+/// The costs are decimal strings, or `infinite` if the cost cannot be bounded statically.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasEstimates {
+    /// The deploy code costs.
+    pub creation: Creation,
+    /// The external function costs, keyed by the function signature.
+    #[serde(default)]
+    pub external: BTreeMap<String, String>,
+    /// The internal function costs, keyed by the function signature.
+    ///
+    /// Always empty, as the internal functions are inlined or merged by LLVM and cannot be
+    /// mapped back to their signatures in the final bytecode. The field is kept for
+    /// compatibility with the `solc` output format.
+    #[serde(default)]
+    pub internal: BTreeMap<String, String>,
+}
+
+impl GasEstimates {
+    /// The cost of paths that cannot be bounded statically.
+    pub const INFINITE: &'static str = "infinite";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(creation: Creation, external: BTreeMap<String, String>) -> Self {
+        Self {
+            creation,
+            external,
+            internal: BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// Converts an optional cost into its string representation.
+    ///
+    pub fn cost_to_string(cost: Option<u64>) -> String {
+        match cost {
+            Some(cost) => cost.to_string(),
+            None => Self::INFINITE.to_owned(),
+        }
+    }
+}
@@ -4,12 +4,14 @@
 
 pub mod bytecode;
 pub mod extra_metadata;
+pub mod gas_estimates;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use self::bytecode::Bytecode;
 use self::extra_metadata::ExtraMetadata;
+use self::gas_estimates::GasEstimates;
 
 ///
 /// The `solc --standard-json` output contract EVM data.
@@ -23,12 +25,18 @@ pub struct EVM {
     /// The contract runtime bytecode.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deployed_bytecode: Option<Bytecode>,
+    /// The contract EVM assembly text of the final bytecode.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub assembly: Option<String>,
     /// The contract EVM legacy assembly code.
     #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
     pub legacy_assembly: serde_json::Value,
     /// The contract function signatures.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub method_identifiers: BTreeMap<String, String>,
+    /// The contract gas estimates.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub gas_estimates: Option<GasEstimates>,
 
     /// The extra EVMLA metadata.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -44,19 +52,34 @@ impl EVM {
         deploy_bytecode: String,
         deploy_object_format: era_compiler_common::ObjectFormat,
         deploy_unlinked_libraries: BTreeSet<String>,
+        deploy_link_references: BTreeMap<String, BTreeSet<usize>>,
+        deploy_source_map: Option<String>,
+        deploy_opcodes: Option<String>,
         runtime_bytecode: String,
         runtime_object_format: era_compiler_common::ObjectFormat,
         runtime_unlinked_libraries: BTreeSet<String>,
+        runtime_link_references: BTreeMap<String, BTreeSet<usize>>,
+        runtime_immutable_references: BTreeMap<String, BTreeSet<u64>>,
+        runtime_source_map: Option<String>,
+        runtime_opcodes: Option<String>,
     ) {
         self.bytecode = Some(Bytecode::new(
             deploy_bytecode,
             deploy_unlinked_libraries,
+            deploy_link_references,
+            None,
             deploy_object_format,
+            deploy_source_map,
+            deploy_opcodes,
         ));
         self.deployed_bytecode = Some(Bytecode::new(
             runtime_bytecode,
             runtime_unlinked_libraries,
+            runtime_link_references,
+            Some(runtime_immutable_references),
             runtime_object_format,
+            runtime_source_map,
+            runtime_opcodes,
         ));
     }
 
@@ -66,8 +89,10 @@ impl EVM {
     pub fn is_empty(&self) -> bool {
         self.bytecode.is_none()
             && self.deployed_bytecode.is_none()
+            && self.assembly.is_none()
             && self.legacy_assembly.is_null()
             && self.method_identifiers.is_empty()
+            && self.gas_estimates.is_none()
             && self.extra_metadata.is_none()
     }
 }
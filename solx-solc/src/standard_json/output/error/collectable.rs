@@ -4,6 +4,8 @@
 
 use std::io::Write;
 
+use crate::standard_json::output::error::collected::Collected;
+use crate::standard_json::output::error::collected::Errors as CollectedErrors;
 use crate::standard_json::output::error::Error;
 
 ///
@@ -60,7 +62,7 @@ pub trait Collectable {
             .write_all(
                 self.errors()
                     .iter()
-                    .map(|error| error.to_string())
+                    .map(|error| error.to_terminal_string())
                     .collect::<Vec<String>>()
                     .join("\n")
                     .as_bytes(),
@@ -82,10 +84,35 @@ pub trait Collectable {
             "{}",
             warnings
                 .into_iter()
-                .map(|error| error.to_string())
+                .map(|error| error.to_terminal_string())
                 .collect::<Vec<String>>()
                 .join("\n")
         )
         .expect("Stderr writing error");
     }
+
+    ///
+    /// Removes warnings from the list of messages and writes them, bailing if there is at least one error.
+    ///
+    /// If `collected` is provided, the warnings and errors are appended to it instead of being written
+    /// to stderr, and the returned error is [`CollectedErrors`].
+    ///
+    fn take_and_write_messages(&mut self, collected: Option<&mut Collected>) -> anyhow::Result<()> {
+        let collected = match collected {
+            Some(collected) => collected,
+            None => {
+                self.take_and_write_warnings();
+                return self.check_errors();
+            }
+        };
+
+        collected.messages.extend(self.take_warnings());
+        if self.has_errors() {
+            collected
+                .messages
+                .extend(self.errors().into_iter().cloned());
+            return Err(CollectedErrors.into());
+        }
+        Ok(())
+    }
 }
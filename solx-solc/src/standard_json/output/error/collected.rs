@@ -0,0 +1,79 @@
+//!
+//! The collected diagnostics.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::standard_json::input::source::Source as StandardJsonInputSource;
+use crate::standard_json::output::error::Error;
+
+///
+/// The diagnostics collected to be written at once in a machine-readable format.
+///
+/// Along with the messages, the source code of the files they refer to is kept,
+/// so the source locations can be resolved exactly as the files have been compiled.
+///
+#[derive(Debug, Default)]
+pub struct Collected {
+    /// The collected messages.
+    pub messages: Vec<Error>,
+    /// The source code of the compiled files, keyed by their paths in the messages.
+    pub sources: BTreeMap<String, String>,
+}
+
+impl Collected {
+    ///
+    /// Adds the source code of the input `sources` that have been read.
+    ///
+    pub fn add_sources(&mut self, sources: &BTreeMap<String, StandardJsonInputSource>) {
+        for (path, source) in sources.iter() {
+            if let Some(content) = source.content() {
+                self.sources
+                    .entry(path.to_owned())
+                    .or_insert_with(|| content.to_owned());
+            }
+        }
+    }
+
+    ///
+    /// Reads the source code of the `paths` that have not been added yet.
+    ///
+    /// Each path is resolved against the `directories` in order, like the `solc` import callback
+    /// does with the base and include paths. The files that cannot be read are skipped.
+    ///
+    pub fn read_sources<P>(&mut self, paths: impl IntoIterator<Item = P>, directories: &[&Path])
+    where
+        P: AsRef<Path>,
+    {
+        for path in paths.into_iter() {
+            let path = path.as_ref();
+            let key = path.to_string_lossy();
+            if self.sources.contains_key(key.as_ref()) {
+                continue;
+            }
+            if let Some(content) = directories
+                .iter()
+                .find_map(|directory| std::fs::read_to_string(directory.join(path)).ok())
+            {
+                self.sources.insert(key.to_string(), content);
+            }
+        }
+    }
+}
+
+///
+/// The error returned after the compilation errors have been collected.
+///
+/// The errors themselves are written along with the other collected diagnostics.
+///
+#[derive(Debug)]
+pub struct Errors;
+
+impl std::error::Error for Errors {}
+
+impl std::fmt::Display for Errors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The compilation errors have been collected.")
+    }
+}
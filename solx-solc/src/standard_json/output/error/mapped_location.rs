@@ -8,22 +8,45 @@ use crate::standard_json::output::error::source_location::SourceLocation;
 /// The mapped error location.
 ///
 /// It can be resolved from `solc` AST error location if the source code is provided.
+/// The lines are one-based, whereas the columns are zero-based and counted in characters.
 ///
-#[derive(Debug)]
-pub struct MappedLocation<'a> {
+#[derive(Debug, Clone)]
+pub struct MappedLocation {
     /// The source file path.
     pub path: String,
     /// The line number.
     pub line: Option<usize>,
     /// The column number.
     pub column: Option<usize>,
-    /// The error area length.
+    /// The last line number.
+    pub end_line: Option<usize>,
+    /// The column number right after the error area on the last line.
+    pub end_column: Option<usize>,
+    /// The error area length in characters.
     pub length: Option<usize>,
-    /// The source code line to print.
-    pub source_code_line: Option<&'a str>,
+    /// The source code lines from `line` to `end_line` to print.
+    pub source_code_lines: Vec<String>,
 }
 
-impl<'a> MappedLocation<'a> {
+impl MappedLocation {
+    /// The maximum number of error area lines to print. The lines in the middle are elided.
+    pub const MAX_PRINTED_LINES: usize = 6;
+
+    /// The ANSI escape code of the error color.
+    pub const COLOR_ERROR: &'static str = "\x1b[1;31m";
+
+    /// The ANSI escape code of the warning color.
+    pub const COLOR_WARNING: &'static str = "\x1b[1;33m";
+
+    /// The ANSI escape code of the note color.
+    pub const COLOR_NOTE: &'static str = "\x1b[1;36m";
+
+    /// The ANSI escape code of the gutter and arrow color.
+    pub const COLOR_GUTTER: &'static str = "\x1b[1;34m";
+
+    /// The ANSI escape code resetting the color.
+    pub const COLOR_RESET: &'static str = "\x1b[0m";
+
     ///
     /// A shortcut constructor.
     ///
@@ -32,8 +55,10 @@ impl<'a> MappedLocation<'a> {
             path,
             line: None,
             column: None,
+            end_line: None,
+            end_column: None,
             length: None,
-            source_code_line: None,
+            source_code_lines: vec![],
         }
     }
 
@@ -44,86 +69,205 @@ impl<'a> MappedLocation<'a> {
         path: String,
         line: usize,
         column: usize,
+        end_line: usize,
+        end_column: usize,
         length: usize,
-        source_code_line: Option<&'a str>,
+        source_code_lines: Vec<String>,
     ) -> Self {
         Self {
             path,
             line: Some(line),
             column: Some(column),
+            end_line: Some(end_line),
+            end_column: Some(end_column),
             length: Some(length),
-            source_code_line,
+            source_code_lines,
         }
     }
 
     ///
     /// A shortcut constructor from `solc` AST source location.
     ///
+    /// The `start` and `end` offsets are in bytes, as reported by `solc`.
+    ///
     pub fn try_from_source_location(
         source_location: &SourceLocation,
-        source_code: Option<&'a str>,
+        source_code: Option<&str>,
     ) -> Self {
         let source_code = match source_code {
             Some(source_code) => source_code,
             None => return Self::new(source_location.file.to_owned()),
         };
-        if source_location.start <= 0 || source_location.end <= 0 {
+        if source_location.start < 0 || source_location.end < source_location.start {
             return Self::new(source_location.file.to_owned());
         }
         let start = source_location.start as usize;
-        let end = source_location.end as usize;
-
-        let mut cursor = 1;
-        for (line, source_line) in source_code.lines().enumerate() {
-            let cursor_next = cursor + source_line.len() + 1;
-
-            if cursor <= start && start <= cursor_next {
-                let line = line + 1;
-                let column = start - cursor;
-                let length = end - start;
-                return Self::new_with_location(
-                    source_location.file.to_owned(),
-                    line,
-                    column,
-                    length,
-                    Some(source_line),
-                );
+        let end = std::cmp::min(source_location.end as usize, source_code.len());
+        if start > source_code.len() {
+            return Self::new(source_location.file.to_owned());
+        }
+
+        let mut line_offset = 0;
+        let mut start_position = None;
+        let mut source_code_lines = Vec::new();
+        for (index, raw_line) in source_code.split_inclusive('\n').enumerate() {
+            let source_line = raw_line.trim_end_matches(['\n', '\r']);
+            let line_end_offset = line_offset + raw_line.len();
+
+            if start_position.is_none() && start < line_end_offset {
+                start_position = Some((
+                    index + 1,
+                    Self::characters_count(source_line, start - line_offset),
+                ));
+            }
+            if start_position.is_some() {
+                source_code_lines.push(source_line.to_owned());
+            }
+            if let Some((line, column)) = start_position {
+                if end <= line_end_offset || end == start {
+                    let end_line = index + 1;
+                    let end_column = if end == start {
+                        column
+                    } else {
+                        Self::characters_count(source_line, end - line_offset)
+                    };
+                    let length = source_code
+                        .get(start..end)
+                        .map(|area| area.chars().count())
+                        .unwrap_or(end - start);
+                    return Self::new_with_location(
+                        source_location.file.to_owned(),
+                        line,
+                        column,
+                        end_line,
+                        end_column,
+                        length,
+                        source_code_lines,
+                    );
+                }
             }
 
-            cursor = cursor_next;
+            line_offset = line_end_offset;
         }
 
         Self::new(source_location.file.to_owned())
     }
-}
 
-impl std::fmt::Display for MappedLocation<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let mut path = self.path.clone();
-        if let Some(line) = self.line {
-            path.push(':');
-            path.push_str(line.to_string().as_str());
-            if let Some(column) = self.column {
-                path.push(':');
-                path.push_str(column.to_string().as_str());
-                if let (Some(source_code_line), Some(length)) = (self.source_code_line, self.length)
+    ///
+    /// Writes the location, coloring the error area markers with the ANSI escape `color` if it is set.
+    ///
+    /// The arrow and line number gutters are colored along with the markers.
+    ///
+    pub fn write<W>(&self, f: &mut W, color: Option<&str>) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        let (gutter_color, marker_color, reset) = match color {
+            Some(color) => (Self::COLOR_GUTTER, color, Self::COLOR_RESET),
+            None => ("", "", ""),
+        };
+
+        let (line, column, end_line, end_column) =
+            match (self.line, self.column, self.end_line, self.end_column) {
+                (Some(line), Some(column), Some(end_line), Some(end_column))
+                    if !self.source_code_lines.is_empty() =>
                 {
-                    let line_number_length = line.to_string().len();
-                    writeln!(f, "{} --> {path}", " ".repeat(line_number_length))?;
-                    writeln!(f, " {} |", " ".repeat(line_number_length))?;
-                    writeln!(f, " {line} | {source_code_line}")?;
-                    writeln!(
+                    (line, column, end_line, end_column)
+                }
+                (Some(line), Some(column), _, _) => {
+                    return writeln!(
                         f,
-                        " {} | {} {}",
-                        " ".repeat(line_number_length),
-                        " ".repeat(column),
-                        "^".repeat(std::cmp::min(length, source_code_line.len() - column))
-                    )?;
+                        "{gutter_color}-->{reset} {}:{line}:{}",
+                        self.path,
+                        column + 1
+                    );
+                }
+                _ => return writeln!(f, "{gutter_color}-->{reset} {}", self.path),
+            };
+
+        let line_number_length = end_line.to_string().len();
+        let padding = " ".repeat(line_number_length);
+        writeln!(
+            f,
+            "{padding} {gutter_color}-->{reset} {}:{line}:{}",
+            self.path,
+            column + 1
+        )?;
+        writeln!(f, "{gutter_color} {padding} |{reset}")?;
+
+        let lines_count = self.source_code_lines.len();
+        for (index, source_code_line) in self.source_code_lines.iter().enumerate() {
+            let is_elided = lines_count > Self::MAX_PRINTED_LINES
+                && index >= Self::MAX_PRINTED_LINES / 2
+                && index < lines_count - Self::MAX_PRINTED_LINES / 2;
+            if is_elided {
+                if index == Self::MAX_PRINTED_LINES / 2 {
+                    writeln!(f, "{gutter_color} {padding} |{reset} ...")?;
                 }
+                continue;
+            }
+
+            let line_number = line + index;
+            let line_length = source_code_line.chars().count();
+            let marker_start = if index == 0 {
+                column
+            } else {
+                source_code_line
+                    .chars()
+                    .take_while(|character| character.is_whitespace())
+                    .count()
+            };
+            let marker_end = if line_number == end_line {
+                end_column
+            } else {
+                line_length
+            };
+
+            writeln!(
+                f,
+                "{gutter_color} {line_number:>line_number_length$} |{reset} {source_code_line}"
+            )?;
+            if marker_start < marker_end || line_number == end_line {
+                writeln!(
+                    f,
+                    "{gutter_color} {padding} |{reset} {marker_color}{}{reset}",
+                    Self::marker(source_code_line, marker_start, marker_end)
+                )?;
             }
-        } else {
-            writeln!(f, "--> {path}")?;
         }
         Ok(())
     }
+
+    ///
+    /// Returns the number of characters in the first `bytes` of the line.
+    ///
+    /// The offsets inside a multi-byte character are rounded down to the character start,
+    /// and the offsets past the end of the line are clamped to the line length.
+    ///
+    fn characters_count(line: &str, bytes: usize) -> usize {
+        line.char_indices()
+            .take_while(|(offset, _)| *offset < bytes)
+            .count()
+    }
+
+    ///
+    /// Returns the error area marker line for the source code line.
+    ///
+    /// The tabs before the area are preserved to keep the marker aligned with the code.
+    ///
+    fn marker(source_code_line: &str, start: usize, end: usize) -> String {
+        let prefix: String = source_code_line
+            .chars()
+            .take(start)
+            .map(|character| if character == '\t' { '\t' } else { ' ' })
+            .collect();
+        let length = std::cmp::max(end.saturating_sub(start), 1);
+        format!("{prefix}{}", "^".repeat(length))
+    }
+}
+
+impl std::fmt::Display for MappedLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.write(f, None)
+    }
 }
@@ -3,14 +3,18 @@
 //!
 
 pub mod collectable;
+pub mod collected;
 pub mod mapped_location;
+pub mod secondary_source_location;
 pub mod source_location;
 
 use std::collections::BTreeMap;
+use std::io::IsTerminal;
 
 use crate::standard_json::input::source::Source as StandardJsonInputSource;
 
 use self::mapped_location::MappedLocation;
+use self::secondary_source_location::SecondarySourceLocation;
 use self::source_location::SourceLocation;
 
 ///
@@ -33,8 +37,18 @@ pub struct Error {
     /// The error location data.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_location: Option<SourceLocation>,
+    /// The related source locations.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub secondary_source_locations: Vec<SecondarySourceLocation>,
     /// The error type.
     pub r#type: String,
+
+    /// The resolved primary location the formatted message is rendered with.
+    #[serde(skip)]
+    pub mapped_location: Option<MappedLocation>,
+    /// The resolved secondary locations the formatted message is rendered with, along with their notes.
+    #[serde(skip)]
+    pub secondary_mapped_locations: Vec<(String, MappedLocation)>,
 }
 
 impl Error {
@@ -54,36 +68,59 @@ impl Error {
     where
         S: std::fmt::Display,
     {
-        let message = message.to_string();
+        let mapped_location = source_location.as_ref().map(|source_location| {
+            MappedLocation::try_from_source_location(
+                source_location,
+                Self::source_code(sources, source_location.file.as_str()),
+            )
+        });
 
-        let message_trimmed = message.trim();
-        let mut formatted_message = if message_trimmed.starts_with(r#type) {
-            message_trimmed.to_owned()
-        } else {
-            format!("{type}: {message_trimmed}")
-        };
-        formatted_message.push('\n');
-        if let Some(ref source_location) = source_location {
-            let source_code = sources.and_then(|sources| {
-                sources
-                    .get(source_location.file.as_str())
-                    .and_then(|source| source.content())
-            });
-            let mapped_location =
-                MappedLocation::try_from_source_location(source_location, source_code);
-            formatted_message.push_str(mapped_location.to_string().as_str());
-            formatted_message.push('\n');
-        }
-
-        Self {
+        let mut error = Self {
             component: "general".to_owned(),
             error_code: error_code.map(|code| code.to_string()),
-            formatted_message,
-            message,
+            formatted_message: String::new(),
+            message: message.to_string(),
             severity: r#type.to_lowercase(),
             source_location,
+            secondary_source_locations: vec![],
             r#type: r#type.to_owned(),
-        }
+
+            mapped_location,
+            secondary_mapped_locations: vec![],
+        };
+        error.formatted_message = error.render(false);
+        error
+    }
+
+    ///
+    /// Renders the `solc` message again with the `sources`, including its secondary locations.
+    ///
+    /// `solc` renders only the first line of the error area, so its messages are rendered
+    /// like the solx ones for the terminal. The messages in the files missing from `sources`
+    /// are left intact.
+    ///
+    pub fn render_with_sources(&mut self, sources: &BTreeMap<String, StandardJsonInputSource>) {
+        let source_location = match self.source_location.as_ref() {
+            Some(source_location) => source_location,
+            None => return,
+        };
+        let source_code = match Self::source_code(Some(sources), source_location.file.as_str()) {
+            Some(source_code) => source_code,
+            None => return,
+        };
+
+        self.mapped_location = Some(MappedLocation::try_from_source_location(
+            source_location,
+            Some(source_code),
+        ));
+        self.secondary_mapped_locations = self
+            .secondary_source_locations
+            .iter()
+            .map(|secondary_source_location| {
+                Self::map_secondary_source_location(secondary_source_location, Some(sources))
+            })
+            .collect();
+        self.formatted_message = self.render(false);
     }
 
     ///
@@ -115,6 +152,135 @@ impl Error {
     {
         Self::new("Warning", error_code, message, source_location, sources)
     }
+
+    ///
+    /// Promotes the warning to an error, e.g. if it is denied by the warning settings.
+    ///
+    pub fn promote_to_error(&mut self) {
+        if self.is_rendered() {
+            self.severity = "error".to_owned();
+            self.r#type = "Error".to_owned();
+            self.formatted_message = self.render(false);
+            return;
+        }
+
+        if let Some(formatted_message) = self.formatted_message.strip_prefix(self.r#type.as_str()) {
+            self.formatted_message = format!("Error{formatted_message}");
+        }
+        self.severity = "error".to_owned();
+        self.r#type = "Error".to_owned();
+    }
+
+    ///
+    /// Returns the formatted message to print to the terminal.
+    ///
+    /// The message is colored if stderr is a terminal and the `NO_COLOR` environment variable is not set.
+    /// The messages rendered by `solc` are only colored if they have been rendered again with the sources.
+    ///
+    pub fn to_terminal_string(&self) -> String {
+        if self.is_rendered()
+            && std::io::stderr().is_terminal()
+            && std::env::var_os("NO_COLOR").is_none()
+        {
+            self.render(true)
+        } else {
+            self.formatted_message.to_owned()
+        }
+    }
+
+    ///
+    /// Whether the formatted message is rendered by solx from the resolved locations.
+    ///
+    fn is_rendered(&self) -> bool {
+        self.source_location.is_none() || self.mapped_location.is_some()
+    }
+
+    ///
+    /// Renders the message with its resolved locations, coloring it with ANSI escape codes if `is_colored` is set.
+    ///
+    fn render(&self, is_colored: bool) -> String {
+        let (color, note_color, reset) = if is_colored {
+            let color = match self.r#type.as_str() {
+                "Warning" => MappedLocation::COLOR_WARNING,
+                "Info" => MappedLocation::COLOR_NOTE,
+                _ => MappedLocation::COLOR_ERROR,
+            };
+            (
+                Some(color),
+                Some(MappedLocation::COLOR_NOTE),
+                MappedLocation::COLOR_RESET,
+            )
+        } else {
+            (None, None, "")
+        };
+
+        let message = self.message.trim();
+        let mut result = match message.strip_prefix(self.r#type.as_str()) {
+            Some(message) => format!(
+                "{}{}{reset}{message}",
+                color.unwrap_or_default(),
+                self.r#type
+            ),
+            None => format!(
+                "{}{}:{reset} {message}",
+                color.unwrap_or_default(),
+                self.r#type
+            ),
+        };
+        result.push('\n');
+        if let Some(mapped_location) = self.mapped_location.as_ref() {
+            mapped_location
+                .write(&mut result, color)
+                .expect("Always valid");
+            result.push('\n');
+        }
+        for (note, mapped_location) in self.secondary_mapped_locations.iter() {
+            result.push_str(
+                format!(
+                    "{}Note:{reset} {}\n",
+                    note_color.unwrap_or_default(),
+                    note.trim()
+                )
+                .as_str(),
+            );
+            mapped_location
+                .write(&mut result, note_color)
+                .expect("Always valid");
+            result.push('\n');
+        }
+        result
+    }
+
+    ///
+    /// Resolves the secondary source location, returning it along with its note.
+    ///
+    fn map_secondary_source_location(
+        secondary_source_location: &SecondarySourceLocation,
+        sources: Option<&BTreeMap<String, StandardJsonInputSource>>,
+    ) -> (String, MappedLocation) {
+        let note = secondary_source_location
+            .message
+            .to_owned()
+            .unwrap_or_else(|| "The related code is here.".to_owned());
+        let source_location = SourceLocation::from(secondary_source_location);
+        let mapped_location = MappedLocation::try_from_source_location(
+            &source_location,
+            Self::source_code(sources, source_location.file.as_str()),
+        );
+        (note, mapped_location)
+    }
+
+    ///
+    /// Returns the source code of the file at `path`, if it is among the `sources`.
+    ///
+    fn source_code<'a>(
+        sources: Option<&'a BTreeMap<String, StandardJsonInputSource>>,
+        path: &str,
+    ) -> Option<&'a str> {
+        sources
+            .and_then(|sources| sources.get(path))
+            .and_then(|source| source.content())
+    }
 }
 
 impl From<(&str, &era_compiler_llvm_context::EVMWarning)> for Error {
@@ -0,0 +1,44 @@
+//!
+//! The `solc --standard-json` output error secondary source location.
+//!
+
+use crate::standard_json::output::error::source_location::SourceLocation;
+
+///
+/// The `solc --standard-json` output error secondary source location.
+///
+/// Points at the code related to the error, e.g. a previous declaration of an identifier.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecondarySourceLocation {
+    /// The source file path.
+    pub file: String,
+    /// The start location.
+    pub start: isize,
+    /// The end location.
+    pub end: isize,
+    /// The note message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl SecondarySourceLocation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(file: String, start: isize, end: isize, message: Option<String>) -> Self {
+        Self {
+            file,
+            start,
+            end,
+            message,
+        }
+    }
+}
+
+impl From<&SecondarySourceLocation> for SourceLocation {
+    fn from(location: &SecondarySourceLocation) -> Self {
+        Self::new_with_offsets(location.file.to_owned(), location.start, location.end)
+    }
+}
@@ -13,6 +13,8 @@ use rayon::iter::IntoParallelRefIterator;
 use rayon::iter::ParallelIterator;
 
 use crate::standard_json::input::settings::selection::selector::Selector as StandardJSONInputSettingsSelector;
+use crate::standard_json::input::settings::selection::Selection as StandardJSONInputSettingsSelection;
+use crate::standard_json::input::settings::warnings::Warnings as StandardJSONInputSettingsWarnings;
 use crate::standard_json::input::source::Source as StandardJSONInputSource;
 use crate::version::Version;
 
@@ -20,6 +22,7 @@ use self::contract::Contract;
 use self::error::collectable::Collectable as CollectableError;
 use self::error::source_location::SourceLocation as JsonOutputErrorSourceLocation;
 use self::error::Error as JsonOutputError;
+use self::source::lint::Lint;
 use self::source::Source;
 
 ///
@@ -36,6 +39,15 @@ pub struct Output {
     /// The compilation errors and warnings.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<JsonOutputError>,
+    /// The compilation timing and statistics report.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statistics: Option<serde_json::Value>,
+    /// The warning suppression and promotion settings.
+    #[serde(skip)]
+    pub warning_settings: StandardJSONInputSettingsWarnings,
+    /// The output selection requested by the user, if it was extended for `solc`.
+    #[serde(skip)]
+    pub output_selection: Option<StandardJSONInputSettingsSelection>,
 }
 
 impl Output {
@@ -58,6 +70,9 @@ impl Output {
             contracts: BTreeMap::new(),
             sources,
             errors: std::mem::take(messages),
+            statistics: None,
+            warning_settings: StandardJSONInputSettingsWarnings::default(),
+            output_selection: None,
         }
     }
 
@@ -71,6 +86,9 @@ impl Output {
             contracts: BTreeMap::new(),
             sources: BTreeMap::new(),
             errors: messages,
+            statistics: None,
+            warning_settings: StandardJSONInputSettingsWarnings::default(),
+            output_selection: None,
         }
     }
 
@@ -81,12 +99,26 @@ impl Output {
         mut self,
         selection_to_prune: BTreeSet<StandardJSONInputSettingsSelector>,
     ) -> ! {
+        self.warning_settings.apply(&mut self.errors);
+
         let contracts = self
             .contracts
-            .values_mut()
-            .flat_map(|contracts| contracts.values_mut())
-            .collect::<Vec<&mut Contract>>();
-        for contract in contracts.into_iter() {
+            .iter_mut()
+            .flat_map(|(path, contracts)| {
+                contracts
+                    .iter_mut()
+                    .map(move |(name, contract)| (path.as_str(), name.as_str(), contract))
+            })
+            .collect::<Vec<(&str, &str, &mut Contract)>>();
+        for (path, name, contract) in contracts.into_iter() {
+            let is_unrequested = |selector: StandardJSONInputSettingsSelector| {
+                selection_to_prune.contains(&selector)
+                    || self
+                        .output_selection
+                        .as_ref()
+                        .map(|selection| !selection.is_selected(path, name, &selector))
+                        .unwrap_or_default()
+            };
             if selection_to_prune.contains(&StandardJSONInputSettingsSelector::Yul) {
                 contract.ir_optimized = String::new();
             }
@@ -94,6 +126,42 @@ impl Output {
                 if selection_to_prune.contains(&StandardJSONInputSettingsSelector::EVMLA) {
                     evm.legacy_assembly = serde_json::Value::Null;
                 }
+                if selection_to_prune
+                    .contains(&StandardJSONInputSettingsSelector::BytecodeSourceMap)
+                {
+                    if let Some(ref mut bytecode) = evm.bytecode {
+                        bytecode.source_map = None;
+                    }
+                }
+                if selection_to_prune
+                    .contains(&StandardJSONInputSettingsSelector::RuntimeBytecodeSourceMap)
+                {
+                    if let Some(ref mut bytecode) = evm.deployed_bytecode {
+                        bytecode.source_map = None;
+                    }
+                }
+                if selection_to_prune.contains(&StandardJSONInputSettingsSelector::Assembly) {
+                    evm.assembly = None;
+                }
+                if selection_to_prune.contains(&StandardJSONInputSettingsSelector::BytecodeOpcodes)
+                {
+                    if let Some(ref mut bytecode) = evm.bytecode {
+                        bytecode.opcodes = None;
+                    }
+                }
+                if selection_to_prune
+                    .contains(&StandardJSONInputSettingsSelector::RuntimeBytecodeOpcodes)
+                {
+                    if let Some(ref mut bytecode) = evm.deployed_bytecode {
+                        bytecode.opcodes = None;
+                    }
+                }
+                if is_unrequested(StandardJSONInputSettingsSelector::GasEstimates) {
+                    evm.gas_estimates = None;
+                }
+                if is_unrequested(StandardJSONInputSettingsSelector::MethodIdentifiers) {
+                    evm.method_identifiers.clear();
+                }
             }
             if contract
                 .evm
@@ -149,6 +217,7 @@ impl Output {
         &mut self,
         sources: &BTreeMap<String, StandardJSONInputSource>,
         version: &Version,
+        disabled_lints: &BTreeSet<Lint>,
     ) -> anyhow::Result<()> {
         let id_paths: BTreeMap<usize, &String> = self
             .sources
@@ -163,7 +232,9 @@ impl Output {
                 source
                     .ast
                     .as_ref()
-                    .map(|ast| Source::get_messages(ast, &id_paths, sources, version))
+                    .map(|ast| {
+                        Source::get_messages(ast, &id_paths, sources, version, disabled_lints)
+                    })
                     .unwrap_or_default()
             })
             .flatten()
@@ -183,6 +254,7 @@ impl CollectableError for Output {
     }
 
     fn take_warnings(&mut self) -> Vec<JsonOutputError> {
+        self.warning_settings.apply(&mut self.errors);
         let warnings = self
             .errors
             .iter()
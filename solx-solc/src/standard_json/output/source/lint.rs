@@ -0,0 +1,247 @@
+//!
+//! The solx-specific AST lint.
+//!
+
+use std::str::FromStr;
+
+///
+/// The solx-specific AST lint.
+///
+/// Checks a single `solc` AST node for patterns that are rejected by the solx backend,
+/// are unsafe, or are known to be expensive to compile.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Lint {
+    /// The `selfdestruct` usage, which is not supported by the backend.
+    Selfdestruct,
+    /// The `callcode` usage, which is not supported by the backend.
+    Callcode,
+    /// The `pc()` usage in inline assembly, which is not supported by the backend.
+    AssemblyPc,
+    /// The `tx.origin` usage.
+    TxOrigin,
+    /// The code size checks used to tell externally owned accounts from contracts.
+    ExtcodesizeCheck,
+    /// The internal function pointers, which may cause EthIR blow-up.
+    InternalFunctionPointer,
+}
+
+impl Lint {
+    /// The list of all lints.
+    pub const ALL: [Self; 6] = [
+        Self::Selfdestruct,
+        Self::Callcode,
+        Self::AssemblyPc,
+        Self::TxOrigin,
+        Self::ExtcodesizeCheck,
+        Self::InternalFunctionPointer,
+    ];
+
+    ///
+    /// Returns the lint error code.
+    ///
+    /// The codes are out of the four-digit range used by `solc`.
+    ///
+    pub fn code(&self) -> isize {
+        match self {
+            Self::Selfdestruct => 10001,
+            Self::Callcode => 10002,
+            Self::AssemblyPc => 10003,
+            Self::TxOrigin => 10004,
+            Self::ExtcodesizeCheck => 10005,
+            Self::InternalFunctionPointer => 10006,
+        }
+    }
+
+    ///
+    /// Checks the AST node and returns the warning message if the lint is triggered.
+    ///
+    pub fn check(&self, node: &serde_json::Value) -> Option<&'static str> {
+        let is_triggered = match self {
+            Self::Selfdestruct => {
+                Self::is_identifier(node, "selfdestruct") || Self::is_yul_call(node, "selfdestruct")
+            }
+            Self::Callcode => {
+                Self::is_member_access(node, "callcode") || Self::is_yul_call(node, "callcode")
+            }
+            Self::AssemblyPc => Self::is_yul_call(node, "pc"),
+            Self::TxOrigin => {
+                Self::is_member_access(node, "origin")
+                    && node
+                        .get("expression")
+                        .is_some_and(|expression| Self::is_identifier(expression, "tx"))
+            }
+            Self::ExtcodesizeCheck => Self::is_code_size_check(node),
+            Self::InternalFunctionPointer => {
+                Self::node_type(node) == Some("VariableDeclaration")
+                    && node.get("typeName").is_some_and(|type_name| {
+                        Self::node_type(type_name) == Some("FunctionTypeName")
+                            && type_name.get("visibility").and_then(|value| value.as_str())
+                                == Some("internal")
+                    })
+            }
+        };
+        if !is_triggered {
+            return None;
+        }
+
+        Some(match self {
+            Self::Selfdestruct => {
+                "The `SELFDESTRUCT` instruction is not supported by the solx backend, so the contract will fail to compile. \
+                Besides, since EIP-6780 the instruction only destroys contracts created in the same transaction."
+            }
+            Self::Callcode => {
+                "The `CALLCODE` instruction is not supported by the solx backend, so the contract will fail to compile. \
+                Consider using `delegatecall` instead."
+            }
+            Self::AssemblyPc => {
+                "The `PC` instruction is not supported by the solx backend, so the contract will fail to compile. \
+                The program counter is not preserved by LLVM code generation and cannot be relied on."
+            }
+            Self::TxOrigin => {
+                "Using `tx.origin` for authorization makes the contract vulnerable to phishing attacks, \
+                as any contract called by the owner can act on their behalf. Consider using `msg.sender` instead."
+            }
+            Self::ExtcodesizeCheck => {
+                "Code size checks cannot reliably tell externally owned accounts from contracts, \
+                as the code size of a contract is zero during its construction and of an address \
+                where a contract will be deployed later."
+            }
+            Self::InternalFunctionPointer => {
+                "Internal function pointers make the control flow of the EVM assembly pipeline hard to analyze, \
+                which may cause exponential growth of EthIR and the compilation time. \
+                Consider compiling via IR with `--via-ir`."
+            }
+        })
+    }
+
+    ///
+    /// Returns the AST node type.
+    ///
+    fn node_type(node: &serde_json::Value) -> Option<&str> {
+        node.get("nodeType").and_then(|value| value.as_str())
+    }
+
+    ///
+    /// Whether the node is a Solidity identifier with the specified name.
+    ///
+    fn is_identifier(node: &serde_json::Value, name: &str) -> bool {
+        Self::node_type(node) == Some("Identifier")
+            && node.get("name").and_then(|value| value.as_str()) == Some(name)
+    }
+
+    ///
+    /// Whether the node is a Solidity member access with the specified member name.
+    ///
+    fn is_member_access(node: &serde_json::Value, member_name: &str) -> bool {
+        Self::node_type(node) == Some("MemberAccess")
+            && node.get("memberName").and_then(|value| value.as_str()) == Some(member_name)
+    }
+
+    ///
+    /// Whether the node is a Yul function call with the specified function name.
+    ///
+    fn is_yul_call(node: &serde_json::Value, name: &str) -> bool {
+        Self::node_type(node) == Some("YulFunctionCall")
+            && node
+                .get("functionName")
+                .and_then(|function_name| function_name.get("name"))
+                .and_then(|value| value.as_str())
+                == Some(name)
+    }
+
+    ///
+    /// Whether the node is a Yul literal with the specified value.
+    ///
+    fn is_yul_literal(node: &serde_json::Value, value: &str) -> bool {
+        Self::node_type(node) == Some("YulLiteral")
+            && node.get("value").and_then(|literal| literal.as_str()) == Some(value)
+    }
+
+    ///
+    /// Whether the node compares the code size of an address to zero.
+    ///
+    /// Matches `address.code.length` compared to `0` in Solidity, and `iszero(extcodesize(...))`
+    /// or `gt(extcodesize(...), 0)` in inline assembly.
+    ///
+    fn is_code_size_check(node: &serde_json::Value) -> bool {
+        match Self::node_type(node) {
+            Some("BinaryOperation") => {
+                let is_code_length = |operand: &serde_json::Value| {
+                    Self::is_member_access(operand, "length")
+                        && operand
+                            .get("expression")
+                            .is_some_and(|expression| Self::is_member_access(expression, "code"))
+                };
+                let is_zero = |operand: &serde_json::Value| {
+                    Self::node_type(operand) == Some("Literal")
+                        && operand.get("value").and_then(|value| value.as_str()) == Some("0")
+                };
+                match (node.get("leftExpression"), node.get("rightExpression")) {
+                    (Some(left), Some(right)) => {
+                        (is_code_length(left) && is_zero(right))
+                            || (is_zero(left) && is_code_length(right))
+                    }
+                    _ => false,
+                }
+            }
+            Some("YulFunctionCall") => {
+                let arguments = match node.get("arguments").and_then(|value| value.as_array()) {
+                    Some(arguments) => arguments,
+                    None => return false,
+                };
+                if Self::is_yul_call(node, "iszero") {
+                    arguments
+                        .first()
+                        .is_some_and(|argument| Self::is_yul_call(argument, "extcodesize"))
+                } else if Self::is_yul_call(node, "gt") {
+                    arguments
+                        .first()
+                        .is_some_and(|argument| Self::is_yul_call(argument, "extcodesize"))
+                        && arguments
+                            .get(1)
+                            .is_some_and(|argument| Self::is_yul_literal(argument, "0"))
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for Lint {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|lint| lint.to_string() == string)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid lint: `{string}`. Available lints: {}.",
+                    Self::ALL
+                        .iter()
+                        .map(|lint| format!("`{lint}`"))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+
+impl std::fmt::Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Selfdestruct => write!(f, "selfdestruct"),
+            Self::Callcode => write!(f, "callcode"),
+            Self::AssemblyPc => write!(f, "assembly-pc"),
+            Self::TxOrigin => write!(f, "tx-origin"),
+            Self::ExtcodesizeCheck => write!(f, "extcodesize-check"),
+            Self::InternalFunctionPointer => write!(f, "internal-function-pointer"),
+        }
+    }
+}
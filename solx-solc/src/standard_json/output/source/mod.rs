@@ -2,12 +2,18 @@
 //! The `solc --standard-json` output source.
 //!
 
+pub mod lint;
+
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 
 use crate::standard_json::input::source::Source as StandardJSONInputSource;
+use crate::standard_json::output::error::source_location::SourceLocation as StandardJsonOutputErrorSourceLocation;
 use crate::standard_json::output::error::Error as StandardJsonOutputError;
 use crate::version::Version;
 
+use self::lint::Lint;
+
 ///
 /// The `solc --standard-json` output source.
 ///
@@ -34,11 +40,14 @@ impl Source {
     ///
     /// Returns the list of messages for some specific parts of the AST.
     ///
+    /// Runs the solx-specific lints on each AST node, skipping the disabled ones.
+    ///
     pub fn get_messages(
         ast: &serde_json::Value,
-        _id_paths: &BTreeMap<usize, &String>,
-        _sources: &BTreeMap<String, StandardJSONInputSource>,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
         _solc_version: &Version,
+        disabled_lints: &BTreeSet<Lint>,
     ) -> Vec<StandardJsonOutputError> {
         let mut messages = Vec::new();
 
@@ -47,19 +56,41 @@ impl Source {
                 for element in array.iter() {
                     messages.extend(Self::get_messages(
                         element,
-                        _id_paths,
-                        _sources,
+                        id_paths,
+                        sources,
                         _solc_version,
+                        disabled_lints,
                     ));
                 }
             }
             serde_json::Value::Object(object) => {
+                for lint in Lint::ALL.iter() {
+                    if disabled_lints.contains(lint) {
+                        continue;
+                    }
+                    if let Some(message) = lint.check(ast) {
+                        let source_location = object
+                            .get("src")
+                            .and_then(|value| value.as_str())
+                            .and_then(|src| {
+                                StandardJsonOutputErrorSourceLocation::try_from_ast(src, id_paths)
+                            });
+                        messages.push(StandardJsonOutputError::new_warning(
+                            Some(lint.code()),
+                            message,
+                            source_location,
+                            Some(sources),
+                        ));
+                    }
+                }
+
                 for (_key, value) in object.iter() {
                     messages.extend(Self::get_messages(
                         value,
-                        _id_paths,
-                        _sources,
+                        id_paths,
+                        sources,
                         _solc_version,
+                        disabled_lints,
                     ));
                 }
             }
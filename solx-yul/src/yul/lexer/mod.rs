@@ -8,14 +8,19 @@ pub mod token;
 #[cfg(test)]
 mod tests;
 
+use std::collections::BTreeMap;
+
 use self::error::Error;
+use self::token::lexeme::comment::annotation::Annotation;
 use self::token::lexeme::comment::Comment;
 use self::token::lexeme::identifier::Identifier;
+use self::token::lexeme::keyword::Keyword;
 use self::token::lexeme::literal::integer::Integer as IntegerLiteral;
 use self::token::lexeme::literal::string::String as StringLiteral;
 use self::token::lexeme::symbol::Symbol;
 use self::token::lexeme::Lexeme;
 use self::token::location::Location;
+use self::token::source_location::SourceLocation;
 use self::token::Token;
 
 ///
@@ -30,6 +35,10 @@ pub struct Lexer {
     location: Location,
     /// The peeked lexeme, waiting to be fetched.
     peeked: Option<Token>,
+    /// The Solidity source location from the last `@src` annotation, reset at object boundaries.
+    source_location: Option<SourceLocation>,
+    /// The source file table from the last `@use-src` annotation, waiting to be taken by an object.
+    source_files: Option<BTreeMap<usize, String>>,
 }
 
 impl Lexer {
@@ -44,6 +53,8 @@ impl Lexer {
             offset: 0,
             location: Location::default(),
             peeked: None,
+            source_location: None,
+            source_files: None,
         }
     }
 
@@ -70,6 +81,16 @@ impl Lexer {
             }
 
             if let Some(token) = Comment::parse(input) {
+                match Annotation::parse(input.get(..token.length).unwrap_or(input)) {
+                    Some(Annotation::SourceLocation(source_location)) => {
+                        self.source_location = Some(source_location);
+                    }
+                    Some(Annotation::UseSource(source_files)) => {
+                        self.source_files = Some(source_files);
+                    }
+                    None => {}
+                }
+
                 self.offset += token.length;
                 self.location
                     .shift_down(token.location.line, token.location.column);
@@ -78,6 +99,7 @@ impl Lexer {
 
             if let Some(mut token) = StringLiteral::parse(input) {
                 token.location = self.location;
+                token.source_location = self.source_location;
 
                 self.offset += token.length;
                 self.location.shift_right(token.length);
@@ -86,6 +108,7 @@ impl Lexer {
 
             if let Some(mut token) = IntegerLiteral::parse(input) {
                 token.location = self.location;
+                token.source_location = self.source_location;
 
                 self.offset += token.length;
                 self.location.shift_right(token.length);
@@ -93,7 +116,11 @@ impl Lexer {
             }
 
             if let Some(mut token) = Identifier::parse(input) {
+                if token.lexeme == Lexeme::Keyword(Keyword::Object) {
+                    self.source_location = None;
+                }
                 token.location = self.location;
+                token.source_location = self.source_location;
 
                 self.offset += token.length;
                 self.location.shift_right(token.length);
@@ -102,6 +129,7 @@ impl Lexer {
 
             if let Some(mut token) = Symbol::parse(input) {
                 token.location = self.location;
+                token.source_location = self.source_location;
 
                 self.offset += token.length;
                 self.location.shift_right(token.length);
@@ -120,6 +148,13 @@ impl Lexer {
         Ok(Token::new(self.location, Lexeme::EndOfFile, 0))
     }
 
+    ///
+    /// Takes the source file table from the last `@use-src` annotation.
+    ///
+    pub fn take_source_files(&mut self) -> Option<BTreeMap<usize, String>> {
+        self.source_files.take()
+    }
+
     ///
     /// Peeks the next lexeme without advancing the iterator.
     ///
@@ -5,6 +5,7 @@
 use crate::yul::lexer::error::Error;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::Lexer;
 
 #[test]
@@ -89,3 +90,100 @@ object "Test" {
         }
     }
 }
+
+#[test]
+fn source_location_annotations() {
+    let input = r#"
+/// @use-src 0:"contracts/A.sol", 1:"contracts/B.sol"
+object "A_15" {
+    code {
+        /// @src 0:62:135  "contract A {..."
+        mstore(64, memoryguard(128))
+        /** @src 1:10:20 */ return(0, 0)
+    }
+}
+    "#;
+
+    let mut lexer = Lexer::new(input.to_owned());
+    let object = lexer.next().expect("Always valid");
+    assert_eq!(object.source_location, None);
+
+    let source_files = lexer.take_source_files().expect("Always exists");
+    assert_eq!(
+        source_files.get(&0).map(String::as_str),
+        Some("contracts/A.sol")
+    );
+    assert_eq!(
+        source_files.get(&1).map(String::as_str),
+        Some("contracts/B.sol")
+    );
+
+    let mut tokens = Vec::new();
+    loop {
+        let token = lexer.next().expect("Always valid");
+        if token.lexeme == Lexeme::EndOfFile {
+            break;
+        }
+        tokens.push(token);
+    }
+
+    let mstore = tokens
+        .iter()
+        .find(|token| token.lexeme.to_string() == "mstore")
+        .expect("Always exists");
+    assert_eq!(
+        mstore.source_location,
+        Some(SourceLocation::new(0, 62, 135))
+    );
+
+    let r#return = tokens
+        .iter()
+        .find(|token| token.lexeme.to_string() == "return")
+        .expect("Always exists");
+    assert_eq!(
+        r#return.source_location,
+        Some(SourceLocation::new(1, 10, 20))
+    );
+}
+
+#[test]
+fn source_location_reset_at_object() {
+    let input = r#"
+object "A_15" {
+    code {
+        /// @src 0:62:135  "contract A {..."
+        mstore(64, memoryguard(128))
+    }
+    object "A_15_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+    let mut lexer = Lexer::new(input.to_owned());
+    let mut tokens = Vec::new();
+    loop {
+        let token = lexer.next().expect("Always valid");
+        if token.lexeme == Lexeme::EndOfFile {
+            break;
+        }
+        tokens.push(token);
+    }
+
+    let mstore = tokens
+        .iter()
+        .find(|token| token.lexeme.to_string() == "mstore")
+        .expect("Always exists");
+    assert_eq!(
+        mstore.source_location,
+        Some(SourceLocation::new(0, 62, 135))
+    );
+
+    let r#return = tokens
+        .iter()
+        .find(|token| token.lexeme.to_string() == "return")
+        .expect("Always exists");
+    assert_eq!(r#return.source_location, None);
+}
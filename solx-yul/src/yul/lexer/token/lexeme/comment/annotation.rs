@@ -0,0 +1,76 @@
+//!
+//! The comment annotation.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::yul::lexer::token::source_location::SourceLocation;
+
+///
+/// The comment annotation emitted by `solc` to map the Yul code back to the Solidity sources.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Annotation {
+    /// The `@src <file>:<start>:<end>` annotation, applied to the code that follows it.
+    SourceLocation(SourceLocation),
+    /// The `@use-src <file>:"<path>", ...` annotation, declaring the source file table of an object.
+    UseSource(BTreeMap<usize, String>),
+}
+
+impl Annotation {
+    /// The source location annotation tag.
+    pub const TAG_SOURCE_LOCATION: &'static str = "@src";
+    /// The source file table annotation tag.
+    pub const TAG_USE_SOURCE: &'static str = "@use-src";
+
+    ///
+    /// Parses the annotation from the comment text, if there is any.
+    ///
+    /// Malformed annotations are ignored, as they are comments and must not break the compilation.
+    ///
+    pub fn parse(comment: &str) -> Option<Self> {
+        if let Some(position) = comment.find(Self::TAG_USE_SOURCE) {
+            return Self::parse_use_source(&comment[position + Self::TAG_USE_SOURCE.len()..])
+                .map(Self::UseSource);
+        }
+
+        let position = comment.find(Self::TAG_SOURCE_LOCATION)?;
+        comment[position + Self::TAG_SOURCE_LOCATION.len()..]
+            .split_whitespace()
+            .next()?
+            .parse::<SourceLocation>()
+            .ok()
+            .map(Self::SourceLocation)
+    }
+
+    ///
+    /// Parses the `@use-src` file table, e.g. `0:"A.sol", 1:"B.sol"`.
+    ///
+    fn parse_use_source(mut input: &str) -> Option<BTreeMap<usize, String>> {
+        let mut files = BTreeMap::new();
+        loop {
+            input = input.trim_start();
+            let (index, rest) = input.split_once(':')?;
+            let index = index.trim().parse::<usize>().ok()?;
+
+            let rest = rest.strip_prefix('"')?;
+            let mut path = String::with_capacity(rest.len());
+            let mut characters = rest.char_indices();
+            let end = loop {
+                match characters.next()? {
+                    (_, '\\') => path.push(characters.next()?.1),
+                    (position, '"') => break position,
+                    (_, character) => path.push(character),
+                }
+            };
+            files.insert(index, path);
+
+            input = rest[end + 1..].trim_start();
+            match input.strip_prefix(',') {
+                Some(rest) => input = rest,
+                None => break,
+            }
+        }
+        Some(files)
+    }
+}
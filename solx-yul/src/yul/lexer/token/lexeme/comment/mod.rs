@@ -2,6 +2,7 @@
 //! The comment lexeme.
 //!
 
+pub mod annotation;
 pub mod multi_line;
 pub mod single_line;
 
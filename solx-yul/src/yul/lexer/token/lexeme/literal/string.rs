@@ -83,6 +83,93 @@ impl String {
     }
 }
 
+impl String {
+    ///
+    /// Returns the hexadecimal representation of the string bytes, with the escape sequences resolved.
+    ///
+    pub fn to_hexadecimal(&self) -> anyhow::Result<std::string::String> {
+        if self.is_hexadecimal {
+            return Ok(self.inner.clone());
+        }
+
+        let bytes = self.inner.as_bytes();
+        let mut hex_string = std::string::String::with_capacity(bytes.len() * 2);
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] != b'\\' {
+                hex_string.push_str(format!("{:02x}", bytes[index]).as_str());
+                index += 1;
+                continue;
+            }
+            index += 1;
+
+            match bytes.get(index) {
+                Some(b'x') => {
+                    let digits = Self::escape_digits(bytes, index + 1, 2)?;
+                    let byte = u8::from_str_radix(digits, era_compiler_common::BASE_HEXADECIMAL)
+                        .map_err(|error| anyhow::anyhow!("Invalid byte `{digits}`: {error}"))?;
+                    hex_string.push_str(format!("{byte:02x}").as_str());
+                    index += 3;
+                }
+                Some(b'u') => {
+                    let digits = Self::escape_digits(bytes, index + 1, 4)?;
+                    let codepoint =
+                        u32::from_str_radix(digits, era_compiler_common::BASE_HEXADECIMAL)
+                            .map_err(|error| {
+                                anyhow::anyhow!("Invalid codepoint `{digits}`: {error}")
+                            })?;
+                    let unicode_char = char::from_u32(codepoint)
+                        .ok_or_else(|| anyhow::anyhow!("Invalid codepoint {codepoint}"))?;
+                    let mut unicode_bytes = [0u8; 4];
+                    for byte in unicode_char.encode_utf8(&mut unicode_bytes).as_bytes() {
+                        hex_string.push_str(format!("{byte:02x}").as_str());
+                    }
+                    index += 5;
+                }
+                Some(b't') => {
+                    hex_string.push_str("09");
+                    index += 1;
+                }
+                Some(b'n') => {
+                    hex_string.push_str("0a");
+                    index += 1;
+                }
+                Some(b'r') => {
+                    hex_string.push_str("0d");
+                    index += 1;
+                }
+                Some(b'\r') if bytes.get(index + 1) == Some(&b'\n') => {
+                    index += 2;
+                }
+                Some(b'\n') => {
+                    index += 1;
+                }
+                Some(byte) => {
+                    hex_string.push_str(format!("{byte:02x}").as_str());
+                    index += 1;
+                }
+                None => anyhow::bail!("Unterminated escape sequence at the end of the string"),
+            }
+        }
+        Ok(hex_string)
+    }
+
+    ///
+    /// Returns the `count` digits of the escape sequence starting at `start`.
+    ///
+    fn escape_digits(bytes: &[u8], start: usize, count: usize) -> anyhow::Result<&str> {
+        bytes
+            .get(start..start + count)
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Truncated escape sequence `\\{}`",
+                    std::string::String::from_utf8_lossy(&bytes[start - 1..])
+                )
+            })
+    }
+}
+
 impl std::fmt::Display for String {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.inner)
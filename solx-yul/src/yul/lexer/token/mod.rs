@@ -4,14 +4,17 @@
 
 pub mod lexeme;
 pub mod location;
+pub mod source_location;
 
 use self::lexeme::Lexeme;
 use self::location::Location;
+use self::source_location::SourceLocation;
 
 ///
 /// The token.
 ///
 /// Contains a lexeme and its location.
+/// If the lexeme is preceded by a `@src` annotation, the annotated Solidity location is also stored.
 ///
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Token {
@@ -21,6 +24,8 @@ pub struct Token {
     pub lexeme: Lexeme,
     /// The token length, including whitespaces.
     pub length: usize,
+    /// The Solidity source location from the last `@src` annotation.
+    pub source_location: Option<SourceLocation>,
 }
 
 impl Token {
@@ -32,6 +37,7 @@ impl Token {
             location,
             lexeme,
             length,
+            source_location: None,
         }
     }
 }
@@ -0,0 +1,63 @@
+//!
+//! The Solidity source code location.
+//!
+
+///
+/// The Solidity source code location, taken from the `@src` annotation emitted by `solc`.
+///
+/// The values are kept as in the annotation, where `-1` means that the location is unknown.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// The source file index in the `@use-src` table.
+    pub file: isize,
+    /// The start offset in the source file.
+    pub start: isize,
+    /// The end offset in the source file.
+    pub end: isize,
+}
+
+impl SourceLocation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(file: isize, start: isize, end: isize) -> Self {
+        Self { file, start, end }
+    }
+
+    ///
+    /// Whether the location is unknown.
+    ///
+    pub fn is_unknown(&self) -> bool {
+        self.file < 0 || self.start < 0 || self.end < 0
+    }
+
+    ///
+    /// Returns the length of the source range.
+    ///
+    pub fn length(&self) -> isize {
+        if self.is_unknown() {
+            -1
+        } else {
+            self.end - self.start
+        }
+    }
+}
+
+impl std::str::FromStr for SourceLocation {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.splitn(3, ':');
+        let file = parts.next().unwrap_or_default().parse()?;
+        let start = parts.next().unwrap_or_default().parse()?;
+        let end = parts.next().unwrap_or_default().parse()?;
+        Ok(Self::new(file, start, end))
+    }
+}
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.start, self.end)
+    }
+}
@@ -61,4 +61,12 @@ pub enum Error {
         /// The list of invalid attributes.
         values: BTreeSet<String>,
     },
+    /// Duplicate data segment.
+    #[error("{location} Data segment `{identifier}` is already defined")]
+    DuplicateDataSegment {
+        /// The duplicate data segment location.
+        location: Location,
+        /// The duplicate data segment identifier.
+        identifier: String,
+    },
 }
@@ -6,6 +6,7 @@ use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::r#type::Type;
@@ -17,6 +18,8 @@ use crate::yul::parser::r#type::Type;
 pub struct Identifier {
     /// The location.
     pub location: Location,
+    /// The Solidity source location.
+    pub source_location: Option<SourceLocation>,
     /// The inner string.
     pub inner: String,
     /// The type, if it has been explicitly specified.
@@ -32,6 +35,7 @@ impl Identifier {
             location,
             inner,
             r#type: None,
+            source_location: None,
         }
     }
 
@@ -43,6 +47,7 @@ impl Identifier {
             location,
             inner,
             r#type,
+            source_location: None,
         }
     }
 
@@ -63,9 +68,15 @@ impl Identifier {
                 Token {
                     location,
                     lexeme: Lexeme::Identifier(identifier),
+                    source_location,
                     ..
                 } if !expected_comma => {
-                    result.push(Self::new(location, identifier.inner));
+                    result.push(Self {
+                        location,
+                        inner: identifier.inner,
+                        r#type: None,
+                        source_location,
+                    });
                     expected_comma = true;
                 }
                 Token {
@@ -96,6 +107,7 @@ impl Identifier {
                 Token {
                     lexeme: Lexeme::Identifier(identifier),
                     location,
+                    source_location,
                     ..
                 } if !expected_comma => {
                     let r#type = match lexer.peek()? {
@@ -108,7 +120,12 @@ impl Identifier {
                         }
                         _ => None,
                     };
-                    result.push(Self::new_with_type(location, identifier.inner, r#type));
+                    result.push(Self {
+                        location,
+                        inner: identifier.inner,
+                        r#type,
+                        source_location,
+                    });
                     expected_comma = true;
                 }
                 Token {
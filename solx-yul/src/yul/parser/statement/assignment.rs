@@ -9,6 +9,7 @@ use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
@@ -22,6 +23,8 @@ use crate::yul::parser::statement::expression::Expression;
 pub struct Assignment {
     /// The location.
     pub location: Location,
+    /// The Solidity source location.
+    pub source_location: Option<SourceLocation>,
     /// The variable bindings.
     pub bindings: Vec<Identifier>,
     /// The initializing expression.
@@ -34,6 +37,7 @@ impl Assignment {
     ///
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
+        let source_location = token.source_location;
 
         let (location, identifier) = match token {
             Token {
@@ -61,7 +65,13 @@ impl Assignment {
 
                 Ok(Self {
                     location,
-                    bindings: vec![Identifier::new(location, identifier.inner)],
+                    source_location,
+                    bindings: vec![Identifier {
+                        location,
+                        inner: identifier.inner,
+                        r#type: None,
+                        source_location,
+                    }],
                     initializer: Expression::parse(lexer, None)?,
                 })
             }
@@ -71,7 +81,12 @@ impl Assignment {
             } => {
                 let (identifiers, next) = Identifier::parse_list(
                     lexer,
-                    Some(Token::new(location, Lexeme::Identifier(identifier), length)),
+                    Some(Token {
+                        location,
+                        lexeme: Lexeme::Identifier(identifier),
+                        length,
+                        source_location,
+                    }),
                 )?;
 
                 match crate::yul::parser::take_or_next(next, lexer)? {
@@ -91,6 +106,7 @@ impl Assignment {
 
                 Ok(Self {
                     location,
+                    source_location,
                     bindings: identifiers,
                     initializer: Expression::parse(lexer, None)?,
                 })
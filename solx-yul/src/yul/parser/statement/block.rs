@@ -9,6 +9,7 @@ use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::dialect::Dialect;
@@ -28,6 +29,8 @@ where
 {
     /// The location.
     pub location: Location,
+    /// The Solidity source location.
+    pub source_location: Option<SourceLocation>,
     /// The block statements.
     pub statements: Vec<Statement<P>>,
 }
@@ -41,6 +44,7 @@ where
     ///
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
+        let source_location = token.source_location;
 
         let mut statements = Vec::new();
 
@@ -126,6 +130,7 @@ where
 
         Ok(Self {
             location,
+            source_location,
             statements,
         })
     }
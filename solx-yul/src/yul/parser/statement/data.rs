@@ -0,0 +1,171 @@
+//!
+//! The Yul object data segment.
+//!
+
+use crate::yul::error::Error;
+use crate::yul::lexer::token::lexeme::literal::string::String as StringLiteral;
+use crate::yul::lexer::token::lexeme::literal::Literal;
+use crate::yul::lexer::token::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::Token;
+use crate::yul::lexer::Lexer;
+use crate::yul::parser::error::Error as ParserError;
+
+///
+/// The Yul object data segment, e.g. `data "table" hex"0102"`.
+///
+/// The segment is appended to the object bytecode if it is referenced by `dataoffset` or `datasize`.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq)]
+pub struct Data {
+    /// The location.
+    pub location: Location,
+    /// The segment identifier.
+    pub identifier: String,
+    /// The segment contents, as written in the source code.
+    pub value: StringLiteral,
+}
+
+impl Data {
+    /// The data segment keyword.
+    pub const KEYWORD: &'static str = "data";
+
+    ///
+    /// The element parser.
+    ///
+    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
+        let token = crate::yul::parser::take_or_next(initial, lexer)?;
+
+        let location = match token {
+            Token {
+                lexeme: Lexeme::Identifier(identifier),
+                location,
+                ..
+            } if identifier.inner.as_str() == Self::KEYWORD => location,
+            token => {
+                return Err(ParserError::InvalidToken {
+                    location: token.location,
+                    expected: vec![Self::KEYWORD],
+                    found: token.lexeme.to_string(),
+                }
+                .into());
+            }
+        };
+
+        let identifier = match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Literal(Literal::String(literal)),
+                ..
+            } if !literal.is_hexadecimal => literal.inner,
+            token => {
+                return Err(ParserError::InvalidToken {
+                    location: token.location,
+                    expected: vec!["{string}"],
+                    found: token.lexeme.to_string(),
+                }
+                .into());
+            }
+        };
+
+        let value = match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Literal(Literal::String(literal)),
+                ..
+            } => literal,
+            token => {
+                return Err(ParserError::InvalidToken {
+                    location: token.location,
+                    expected: vec!["{string}"],
+                    found: token.lexeme.to_string(),
+                }
+                .into());
+            }
+        };
+
+        Ok(Self {
+            location,
+            identifier,
+            value,
+        })
+    }
+
+    ///
+    /// Returns the segment contents in hexadecimal, with the string escape sequences resolved.
+    ///
+    pub fn to_hexadecimal(&self) -> anyhow::Result<String> {
+        self.value.to_hexadecimal()
+    }
+}
+
+impl std::fmt::Display for Data {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.value.is_hexadecimal {
+            write!(
+                f,
+                "data \"{}\" hex\"{}\"",
+                self.identifier, self.value.inner
+            )
+        } else {
+            write!(f, "data \"{}\" \"{}\"", self.identifier, self.value.inner)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::token::lexeme::literal::string::String as StringLiteral;
+    use crate::yul::lexer::token::location::Location;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::statement::data::Data;
+
+    fn to_hexadecimal(input: &str) -> anyhow::Result<String> {
+        let mut lexer = Lexer::new(input.to_owned());
+        Data::parse(&mut lexer, None)
+            .expect("Always valid")
+            .to_hexadecimal()
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            to_hexadecimal(r#"data "table" "a\x41\t\n\r\"""#).expect("Always valid"),
+            "6141090a0d22",
+        );
+    }
+
+    #[test]
+    fn unicode_escapes() {
+        assert_eq!(
+            to_hexadecimal(r#"data "table" "\u0041\u00e9\u20ac""#).expect("Always valid"),
+            "41c3a9e282ac",
+        );
+    }
+
+    #[test]
+    fn non_ascii() {
+        let data = Data {
+            location: Location::new(1, 1),
+            identifier: "table".to_owned(),
+            value: StringLiteral::new("é\\x41".to_owned(), false),
+        };
+        assert_eq!(data.to_hexadecimal().expect("Always valid"), "c3a941");
+    }
+
+    #[test]
+    fn error_truncated_byte_escape() {
+        let error = to_hexadecimal(r#"data "table" "\x4""#).expect_err("Must fail");
+        assert!(error.to_string().contains("Truncated escape sequence"));
+    }
+
+    #[test]
+    fn error_truncated_unicode_escape() {
+        let error = to_hexadecimal(r#"data "table" "\u00""#).expect_err("Must fail");
+        assert!(error.to_string().contains("Truncated escape sequence"));
+    }
+
+    #[test]
+    fn error_invalid_codepoint() {
+        let error = to_hexadecimal(r#"data "table" "\ud800""#).expect_err("Must fail");
+        assert!(error.to_string().contains("Invalid codepoint"));
+    }
+}
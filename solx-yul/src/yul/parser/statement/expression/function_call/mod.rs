@@ -12,6 +12,7 @@ use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
@@ -27,6 +28,8 @@ use self::name::Name;
 pub struct FunctionCall {
     /// The location.
     pub location: Location,
+    /// The Solidity source location.
+    pub source_location: Option<SourceLocation>,
     /// The function name.
     pub name: Name,
     /// The function arguments expression list.
@@ -39,6 +42,7 @@ impl FunctionCall {
     ///
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
+        let source_location = token.source_location;
 
         let (location, name) = match token {
             Token {
@@ -89,6 +93,7 @@ impl FunctionCall {
 
         Ok(Self {
             location,
+            source_location,
             name,
             arguments,
         })
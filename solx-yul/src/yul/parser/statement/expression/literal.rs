@@ -7,6 +7,7 @@ use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
@@ -19,6 +20,8 @@ use crate::yul::parser::r#type::Type;
 pub struct Literal {
     /// The location.
     pub location: Location,
+    /// The Solidity source location.
+    pub source_location: Option<SourceLocation>,
     /// The lexical literal.
     pub inner: LexicalLiteral,
     /// The type, if it has been explicitly specified.
@@ -31,6 +34,7 @@ impl Literal {
     ///
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
+        let source_location = token.source_location;
 
         let (location, literal) = match token {
             Token {
@@ -61,6 +65,7 @@ impl Literal {
 
         Ok(Self {
             location,
+            source_location,
             inner: literal,
             yul_type,
         })
@@ -12,6 +12,7 @@ use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
@@ -39,6 +40,7 @@ impl Expression {
     ///
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
+        let source_location = token.source_location;
 
         let (location, identifier) = match token {
             Token {
@@ -69,13 +71,20 @@ impl Expression {
                 lexer.next()?;
                 Ok(Self::FunctionCall(FunctionCall::parse(
                     lexer,
-                    Some(Token::new(location, Lexeme::Identifier(identifier), length)),
+                    Some(Token {
+                        location,
+                        lexeme: Lexeme::Identifier(identifier),
+                        length,
+                        source_location,
+                    }),
                 )?))
             }
-            _ => Ok(Self::Identifier(Identifier::new(
+            _ => Ok(Self::Identifier(Identifier {
                 location,
-                identifier.inner,
-            ))),
+                inner: identifier.inner,
+                r#type: None,
+                source_location,
+            })),
         }
     }
 
@@ -111,4 +120,15 @@ impl Expression {
             Self::Literal(inner) => inner.location,
         }
     }
+
+    ///
+    /// Returns the Solidity source location.
+    ///
+    pub fn source_location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::FunctionCall(inner) => inner.source_location,
+            Self::Identifier(inner) => inner.source_location,
+            Self::Literal(inner) => inner.source_location,
+        }
+    }
 }
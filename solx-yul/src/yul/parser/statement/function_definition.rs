@@ -12,6 +12,7 @@ use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::dialect::Dialect;
@@ -35,6 +36,8 @@ where
 {
     /// The location.
     pub location: Location,
+    /// The Solidity source location.
+    pub source_location: Option<SourceLocation>,
     /// The function identifier.
     pub identifier: String,
     /// The function formal arguments.
@@ -56,6 +59,7 @@ where
     ///
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
+        let source_location = token.source_location;
 
         let (location, identifier) = match token {
             Token {
@@ -145,6 +149,7 @@ where
 
         Ok(Self {
             location,
+            source_location,
             identifier: identifier.inner,
             arguments,
             result,
@@ -7,6 +7,7 @@ use std::collections::BTreeSet;
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::dialect::Dialect;
@@ -24,6 +25,8 @@ where
 {
     /// The location.
     pub location: Location,
+    /// The Solidity source location.
+    pub source_location: Option<SourceLocation>,
     /// The condition expression.
     pub condition: Expression,
     /// The conditional block.
@@ -40,6 +43,7 @@ where
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
         let location = token.location;
+        let source_location = token.source_location;
 
         let condition = Expression::parse(lexer, Some(token))?;
 
@@ -47,6 +51,7 @@ where
 
         Ok(Self {
             location,
+            source_location,
             condition,
             block,
         })
@@ -5,6 +5,7 @@
 pub mod assignment;
 pub mod block;
 pub mod code;
+pub mod data;
 pub mod expression;
 pub mod for_loop;
 pub mod function_definition;
@@ -20,6 +21,7 @@ use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
@@ -214,4 +216,25 @@ where
             Self::Leave(location) => *location,
         }
     }
+
+    ///
+    /// Returns the Solidity source location.
+    ///
+    pub fn source_location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::Object(_) => None,
+            Self::Code(_) => None,
+            Self::Block(inner) => inner.source_location,
+            Self::Expression(inner) => inner.source_location(),
+            Self::FunctionDefinition(inner) => inner.source_location,
+            Self::VariableDeclaration(inner) => inner.source_location,
+            Self::Assignment(inner) => inner.source_location,
+            Self::IfConditional(inner) => inner.source_location,
+            Self::Switch(inner) => inner.source_location,
+            Self::ForLoop(inner) => inner.source_location,
+            Self::Continue(_) => None,
+            Self::Break(_) => None,
+            Self::Leave(_) => None,
+        }
+    }
 }
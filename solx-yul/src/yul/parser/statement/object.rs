@@ -2,6 +2,7 @@
 //! The Yul object.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashSet;
 
@@ -17,6 +18,7 @@ use crate::yul::lexer::Lexer;
 use crate::yul::parser::dialect::Dialect;
 use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::data::Data;
 
 ///
 /// The upper-level Yul object, representing the deploy code.
@@ -31,10 +33,14 @@ where
     pub location: Location,
     /// The identifier.
     pub identifier: String,
+    /// The source file table from the `@use-src` annotation, mapping `@src` file indexes to paths.
+    pub source_files: BTreeMap<usize, String>,
     /// The code.
     pub code: Code<P>,
     /// The optional inner object, representing the runtime code.
     pub inner_object: Option<Box<Self>>,
+    /// The data segments, which can be referenced by `dataoffset` and `datasize`.
+    pub data: BTreeMap<String, Data>,
     /// The factory dependency objects, which are represented by nested Yul object. The nested
     /// objects are duplicates of the upper-level objects describing the dependencies, so only
     /// their identifiers are preserved. The identifiers are used to address upper-level objects.
@@ -67,6 +73,8 @@ where
             }
         };
 
+        let source_files = lexer.take_source_files().unwrap_or_default();
+
         let identifier = match lexer.next()? {
             Token {
                 lexeme: Lexeme::Literal(Literal::String(literal)),
@@ -100,6 +108,7 @@ where
 
         let code = Code::parse(lexer, None)?;
         let mut inner_object = None;
+        let mut data = BTreeMap::new();
         let mut factory_dependencies = HashSet::new();
 
         if !is_runtime_code {
@@ -124,18 +133,6 @@ where
                 }
                 _ => None,
             };
-
-            if let Token {
-                lexeme: Lexeme::Identifier(identifier),
-                ..
-            } = lexer.peek()?
-            {
-                if identifier.inner.as_str() == "data" {
-                    let _data = lexer.next()?;
-                    let _identifier = lexer.next()?;
-                    let _metadata = lexer.next()?;
-                }
-            };
         }
 
         loop {
@@ -151,17 +148,25 @@ where
                     let dependency = Self::parse(lexer, Some(token))?;
                     factory_dependencies.insert(dependency.identifier);
                 }
-                Token {
-                    lexeme: Lexeme::Identifier(identifier),
+                token @ Token {
+                    lexeme: Lexeme::Identifier(_),
                     ..
-                } if identifier.inner.as_str() == "data" => {
-                    let _identifier = lexer.next()?;
-                    let _metadata = lexer.next()?;
+                } if matches!(&token.lexeme, Lexeme::Identifier(identifier) if identifier.inner.as_str() == Data::KEYWORD) =>
+                {
+                    let segment = Data::parse(lexer, Some(token))?;
+                    if data.contains_key(segment.identifier.as_str()) {
+                        return Err(ParserError::DuplicateDataSegment {
+                            location: segment.location,
+                            identifier: segment.identifier,
+                        }
+                        .into());
+                    }
+                    data.insert(segment.identifier.clone(), segment);
                 }
                 token => {
                     return Err(ParserError::InvalidToken {
                         location: token.location,
-                        expected: vec!["object", "}"],
+                        expected: vec!["object", "data", "}"],
                         found: token.lexeme.to_string(),
                     }
                     .into());
@@ -172,8 +177,10 @@ where
         Ok(Self {
             location,
             identifier,
+            source_files,
             code,
             inner_object,
+            data,
             factory_dependencies,
         })
     }
@@ -337,7 +344,7 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(8, 5),
-                expected: vec!["object", "}"],
+                expected: vec!["object", "data", "}"],
                 found: "class".to_owned(),
             }
             .into())
@@ -375,4 +382,38 @@ object "Test" {
             .into())
         );
     }
+
+    #[test]
+    fn source_files() {
+        let input = r#"
+/// @use-src 0:"Test.sol"
+object "Test" {
+    code {
+        /// @src 0:0:10
+        return(0, 0)
+    }
+    /// @use-src 0:"Test.sol", 1:"Library.sol"
+    object "Test_deployed" {
+        code {
+            /// @src 1:5:15
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::<DefaultDialect>::parse(&mut lexer, None).expect("Always valid");
+        assert_eq!(object.source_files.len(), 1);
+        assert_eq!(
+            object
+                .inner_object
+                .as_ref()
+                .expect("Always exists")
+                .source_files
+                .get(&1)
+                .map(String::as_str),
+            Some("Library.sol")
+        );
+    }
 }
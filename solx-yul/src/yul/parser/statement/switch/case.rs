@@ -8,6 +8,7 @@ use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::dialect::Dialect;
@@ -26,6 +27,8 @@ where
 {
     /// The location.
     pub location: Location,
+    /// The Solidity source location.
+    pub source_location: Option<SourceLocation>,
     /// The matched constant.
     pub literal: Literal,
     /// The case block.
@@ -41,6 +44,7 @@ where
     ///
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
+        let source_location = token.source_location;
 
         let (location, literal) = match token {
             token @ Token {
@@ -62,6 +66,7 @@ where
 
         Ok(Self {
             location,
+            source_location,
             literal,
             block,
         })
@@ -11,6 +11,7 @@ use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::dialect::Dialect;
@@ -31,6 +32,8 @@ where
 {
     /// The location.
     pub location: Location,
+    /// The Solidity source location.
+    pub source_location: Option<SourceLocation>,
     /// The expression being matched.
     pub expression: Expression,
     /// The non-default cases.
@@ -61,6 +64,7 @@ where
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
         let mut token = crate::yul::parser::take_or_next(initial, lexer)?;
         let location = token.location;
+        let source_location = token.source_location;
         let mut state = State::CaseOrDefaultKeyword;
 
         let expression = Expression::parse(lexer, Some(token.clone()))?;
@@ -113,6 +117,7 @@ where
 
         Ok(Self {
             location,
+            source_location,
             expression,
             cases,
             default,
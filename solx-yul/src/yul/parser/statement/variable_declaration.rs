@@ -9,6 +9,7 @@ use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::source_location::SourceLocation;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
@@ -23,6 +24,8 @@ use crate::yul::parser::statement::expression::Expression;
 pub struct VariableDeclaration {
     /// The location.
     pub location: Location,
+    /// The Solidity source location.
+    pub source_location: Option<SourceLocation>,
     /// The variable bindings list.
     pub bindings: Vec<Identifier>,
     /// The variable initializing expression.
@@ -39,6 +42,7 @@ impl VariableDeclaration {
     ) -> Result<(Self, Option<Token>), Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
         let location = token.location;
+        let source_location = token.source_location;
 
         let (bindings, next) = Identifier::parse_typed_list(lexer, Some(token))?;
         for binding in bindings.iter() {
@@ -63,6 +67,7 @@ impl VariableDeclaration {
                 return Ok((
                     Self {
                         location,
+                        source_location,
                         bindings,
                         expression: None,
                     },
@@ -76,6 +81,7 @@ impl VariableDeclaration {
         Ok((
             Self {
                 location,
+                source_location,
                 bindings,
                 expression: Some(expression),
             },
@@ -37,6 +37,9 @@ where
         if let Some(inner) = &obj.inner_object {
             self.visit_object(inner)
         }
+        for data in obj.data.values() {
+            self.println(data.to_string().as_str()).unwrap();
+        }
         self.println("}").unwrap();
         self.decrease_indent().unwrap();
     }
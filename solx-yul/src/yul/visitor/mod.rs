@@ -37,6 +37,7 @@ where
 {
     FunctionDefinition {
         location: code.location,
+        source_location: code.block.source_location,
         identifier: IMPLICIT_CODE_FUNCTION_NAME.to_string(),
         arguments: Vec::new(),
         result: Vec::new(),
@@ -188,6 +188,26 @@ object "ecadd" {
     }
 
 }
+"#
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_data() {
+        let expected = "object \"ecadd\" {\n  code  { }\n  object \"ecadd_deployed\" {\n    code  { }\n    data \"table\" hex\"0102\"\n    }\n  data \"name\" \"ecadd\"\n  }\n";
+        assert_eq!(
+            print_statement(
+                r#"
+object "ecadd" {
+    code { }
+    object "ecadd_deployed" {
+        code { }
+        data "table" hex"0102"
+    }
+    data "name" "ecadd"
+}
 "#
             ),
             expected
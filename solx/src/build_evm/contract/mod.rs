@@ -3,12 +3,20 @@
 //!
 
 pub mod object;
+pub mod size;
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 
+use crate::disassembly::Disassembly;
+use crate::gas_estimator::GasEstimator;
+use crate::statistics::contract::Contract as ContractStatistics;
+
 use self::object::Object;
+use self::size::Size;
 
 ///
 /// The Solidity contract build.
@@ -25,9 +33,26 @@ pub struct Contract {
     pub metadata_hash: Option<era_compiler_common::Hash>,
     /// The metadata string.
     pub metadata_string: String,
+    /// The ABI specification received from `solc`.
+    pub abi: Option<serde_json::Value>,
+    /// The method identifiers received from `solc`.
+    pub method_identifiers: Option<BTreeMap<String, String>>,
+    /// The storage layout received from `solc`.
+    pub storage_layout: Option<serde_json::Value>,
+    /// The user documentation received from `solc`.
+    pub userdoc: Option<serde_json::Value>,
+    /// The developer documentation received from `solc`.
+    pub devdoc: Option<serde_json::Value>,
+    /// The compilation statistics.
+    #[serde(default)]
+    pub statistics: ContractStatistics,
 }
 
 impl Contract {
+    /// The assembly text printed for the contracts that are not linked.
+    pub const UNLINKED_ASSEMBLY_TEXT: &'static str =
+        "/* The bytecode is not linked, so it cannot be disassembled. */\n";
+
     ///
     /// A shortcut constructor.
     ///
@@ -37,6 +62,7 @@ impl Contract {
         runtime_object: Object,
         metadata_hash: Option<era_compiler_common::Hash>,
         metadata_string: String,
+        statistics: ContractStatistics,
     ) -> Self {
         Self {
             name,
@@ -44,6 +70,12 @@ impl Contract {
             runtime_object,
             metadata_hash,
             metadata_string,
+            abi: None,
+            method_identifiers: None,
+            storage_layout: None,
+            userdoc: None,
+            devdoc: None,
+            statistics,
         }
     }
 
@@ -53,19 +85,67 @@ impl Contract {
     pub fn write_to_terminal(
         self,
         path: String,
-        output_metadata: bool,
-        output_binary: bool,
+        output_selection: &solx_solc::StandardJsonInputSelection,
     ) -> anyhow::Result<()> {
         writeln!(std::io::stdout(), "\n======= {path} =======")?;
-        if output_metadata {
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::Metadata) {
             writeln!(std::io::stdout(), "Metadata:\n{}", self.metadata_string)?;
         }
-        if output_binary {
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::Assembly) {
+            writeln!(
+                std::io::stdout(),
+                "EVM assembly:\n{}",
+                self.assembly_text()
+                    .unwrap_or_else(|| Self::UNLINKED_ASSEMBLY_TEXT.to_owned())
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::BytecodeObject) {
+            writeln!(
+                std::io::stdout(),
+                "Binary:\n{}",
+                hex::encode(self.deploy_object.bytecode.as_slice()),
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::RuntimeBytecodeObject) {
+            writeln!(
+                std::io::stdout(),
+                "Binary of the runtime part:\n{}",
+                hex::encode(self.runtime_object.bytecode.as_slice()),
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::ABI) {
+            writeln!(
+                std::io::stdout(),
+                "Contract JSON ABI:\n{}",
+                Self::json_to_string(self.abi.as_ref())
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::MethodIdentifiers) {
+            writeln!(
+                std::io::stdout(),
+                "Function signatures:\n{}",
+                self.method_identifiers_text()
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::StorageLayout) {
+            writeln!(
+                std::io::stdout(),
+                "Contract Storage Layout:\n{}",
+                Self::json_to_string(self.storage_layout.as_ref())
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::Userdoc) {
             writeln!(
                 std::io::stdout(),
-                "Binary:\n{}{}",
-                hex::encode(self.deploy_object.bytecode),
-                hex::encode(self.runtime_object.bytecode),
+                "User Documentation:\n{}",
+                Self::json_to_string(self.userdoc.as_ref())
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::Devdoc) {
+            writeln!(
+                std::io::stdout(),
+                "Developer Documentation:\n{}",
+                Self::json_to_string(self.devdoc.as_ref())
             )?;
         }
 
@@ -75,68 +155,171 @@ impl Contract {
     ///
     /// Writes the contract text assembly and bytecode to files.
     ///
+    /// The file names follow the `solc` conventions.
+    ///
     pub fn write_to_directory(
         self,
         output_path: &Path,
-        output_metadata: bool,
-        output_binary: bool,
+        output_selection: &solx_solc::StandardJsonInputSelection,
         overwrite: bool,
     ) -> anyhow::Result<()> {
-        let file_path = PathBuf::from(self.name.path);
+        let file_path = PathBuf::from(self.name.path.as_str());
         let file_name = file_path
             .file_name()
             .expect("Always exists")
             .to_str()
             .expect("Always valid");
+        let contract_name = self.name.name.as_deref().unwrap_or(file_name);
 
         let mut output_path = output_path.to_owned();
         output_path.push(file_name);
         std::fs::create_dir_all(output_path.as_path())?;
 
-        if output_metadata {
-            let output_name = format!(
-                "{}_meta.{}",
-                self.name.name.as_deref().unwrap_or(file_name),
-                era_compiler_common::EXTENSION_JSON,
-            );
-            let mut output_path = output_path.clone();
-            output_path.push(output_name.as_str());
-
-            if output_path.exists() && !overwrite {
-                anyhow::bail!(
-                    "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
-                );
-            } else {
-                std::fs::write(
-                    output_path.as_path(),
-                    self.metadata_string.to_string().as_bytes(),
-                )
-                .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
-            }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::Metadata) {
+            Self::write_file(
+                output_path.as_path(),
+                format!(
+                    "{contract_name}_meta.{}",
+                    era_compiler_common::EXTENSION_JSON
+                ),
+                self.metadata_string.as_str(),
+                overwrite,
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::Assembly) {
+            Self::write_file(
+                output_path.as_path(),
+                format!("{contract_name}.{}", crate::r#const::EXTENSION_EVM_ASSEMBLY),
+                self.assembly_text()
+                    .unwrap_or_else(|| Self::UNLINKED_ASSEMBLY_TEXT.to_owned())
+                    .as_str(),
+                overwrite,
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::BytecodeObject) {
+            Self::write_file(
+                output_path.as_path(),
+                format!(
+                    "{contract_name}.{}",
+                    era_compiler_common::EXTENSION_EVM_BINARY
+                ),
+                hex::encode(self.deploy_object.bytecode.as_slice()).as_str(),
+                overwrite,
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::RuntimeBytecodeObject) {
+            Self::write_file(
+                output_path.as_path(),
+                format!(
+                    "{contract_name}.{}",
+                    crate::r#const::EXTENSION_EVM_BINARY_RUNTIME
+                ),
+                hex::encode(self.runtime_object.bytecode.as_slice()).as_str(),
+                overwrite,
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::ABI) {
+            Self::write_file(
+                output_path.as_path(),
+                format!("{contract_name}.{}", crate::r#const::EXTENSION_ABI),
+                Self::json_to_string(self.abi.as_ref()).as_str(),
+                overwrite,
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::MethodIdentifiers) {
+            Self::write_file(
+                output_path.as_path(),
+                format!(
+                    "{contract_name}.{}",
+                    crate::r#const::EXTENSION_METHOD_IDENTIFIERS
+                ),
+                self.method_identifiers_text().as_str(),
+                overwrite,
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::StorageLayout) {
+            Self::write_file(
+                output_path.as_path(),
+                format!(
+                    "{contract_name}_storage.{}",
+                    era_compiler_common::EXTENSION_JSON
+                ),
+                Self::json_to_string(self.storage_layout.as_ref()).as_str(),
+                overwrite,
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::Userdoc) {
+            Self::write_file(
+                output_path.as_path(),
+                format!("{contract_name}.{}", crate::r#const::EXTENSION_USERDOC),
+                Self::json_to_string(self.userdoc.as_ref()).as_str(),
+                overwrite,
+            )?;
+        }
+        if output_selection.contains(&solx_solc::StandardJsonInputSelector::Devdoc) {
+            Self::write_file(
+                output_path.as_path(),
+                format!("{contract_name}.{}", crate::r#const::EXTENSION_DEVDOC),
+                Self::json_to_string(self.devdoc.as_ref()).as_str(),
+                overwrite,
+            )?;
         }
 
-        if output_binary {
-            let output_name = format!(
-                "{}.{}",
-                self.name.name.as_deref().unwrap_or(file_name),
-                era_compiler_common::EXTENSION_EVM_BINARY
-            );
-            let mut output_path = output_path.clone();
-            output_path.push(output_name.as_str());
-
-            if output_path.exists() && !overwrite {
-                anyhow::bail!(
-                    "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
-                );
-            } else {
-                let mut bytecode_hexadecimal = hex::encode(self.deploy_object.bytecode);
-                bytecode_hexadecimal.push_str(hex::encode(self.runtime_object.bytecode).as_str());
-                std::fs::write(output_path.as_path(), bytecode_hexadecimal.as_bytes())
-                    .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+        Ok(())
+    }
+
+    ///
+    /// Writes the contract artifacts requested by the `selectors` to the combined JSON.
+    ///
+    pub fn write_to_combined_json(
+        self,
+        selectors: &BTreeSet<solx_solc::CombinedJsonSelector>,
+        combined_json_contract: &mut solx_solc::CombinedJsonContract,
+    ) {
+        for selector in selectors.iter() {
+            match selector {
+                solx_solc::CombinedJsonSelector::ABI => {
+                    combined_json_contract.abi = self.abi.clone();
+                }
+                solx_solc::CombinedJsonSelector::Bytecode => {
+                    combined_json_contract.bin =
+                        Some(hex::encode(self.deploy_object.bytecode.as_slice()));
+                }
+                solx_solc::CombinedJsonSelector::BytecodeRuntime => {
+                    combined_json_contract.bin_runtime =
+                        Some(hex::encode(self.runtime_object.bytecode.as_slice()));
+                }
+                solx_solc::CombinedJsonSelector::Metadata => {
+                    combined_json_contract.metadata = Some(self.metadata_string.clone());
+                }
+                solx_solc::CombinedJsonSelector::Hashes => {
+                    combined_json_contract.hashes = self.method_identifiers.clone();
+                }
+                solx_solc::CombinedJsonSelector::StorageLayout => {
+                    combined_json_contract.storage_layout = self.storage_layout.clone();
+                }
+                solx_solc::CombinedJsonSelector::Userdoc => {
+                    combined_json_contract.userdoc = self.userdoc.clone();
+                }
+                solx_solc::CombinedJsonSelector::Devdoc => {
+                    combined_json_contract.devdoc = self.devdoc.clone();
+                }
             }
         }
+    }
 
-        Ok(())
+    ///
+    /// Moves the ABI, method identifiers, storage layout, and documentation from the `solc` output.
+    ///
+    pub fn take_solc_data(&mut self, solc_contract: &mut solx_solc::StandardJsonOutputContract) {
+        self.abi = Some(solc_contract.abi.take());
+        self.storage_layout = Some(solc_contract.storage_layout.take());
+        self.userdoc = Some(solc_contract.userdoc.take());
+        self.devdoc = Some(solc_contract.devdoc.take());
+        self.method_identifiers = solc_contract
+            .evm
+            .as_mut()
+            .map(|evm| std::mem::take(&mut evm.method_identifiers));
     }
 
     ///
@@ -146,18 +329,146 @@ impl Contract {
         self,
         standard_json_contract: &mut solx_solc::StandardJsonOutputContract,
     ) -> anyhow::Result<()> {
+        let deploy_source_map = self.deploy_object.source_map().to_string();
+        let runtime_source_map = self.runtime_object.source_map().to_string();
+        let assembly = self.assembly_text();
+        let (deploy_opcodes, runtime_opcodes) = match self.disassemble() {
+            Some((deploy_disassembly, runtime_disassembly)) => (
+                Some(deploy_disassembly.opcodes()),
+                Some(runtime_disassembly.opcodes()),
+            ),
+            None => (None, None),
+        };
+        let is_linked = self.is_linked();
+
         standard_json_contract.metadata = self.metadata_string;
-        standard_json_contract
+        let evm = standard_json_contract
             .evm
-            .get_or_insert_with(solx_solc::StandardJsonOutputContractEVM::default)
-            .modify(
-                hex::encode(self.deploy_object.bytecode),
-                self.deploy_object.format,
-                self.deploy_object.unlinked_libraries,
-                hex::encode(self.runtime_object.bytecode),
-                self.runtime_object.format,
-                self.runtime_object.unlinked_libraries,
-            );
+            .get_or_insert_with(solx_solc::StandardJsonOutputContractEVM::default);
+        if is_linked {
+            evm.gas_estimates = Some(GasEstimator::estimate(
+                self.deploy_object.bytecode.as_slice(),
+                self.runtime_object.bytecode.as_slice(),
+                &evm.method_identifiers,
+            ));
+        }
+        evm.assembly = assembly;
+        evm.modify(
+            self.deploy_object
+                .placeholder_bytecode
+                .unwrap_or_else(|| hex::encode(self.deploy_object.bytecode)),
+            self.deploy_object.format,
+            self.deploy_object.unlinked_libraries,
+            self.deploy_object.link_references,
+            Some(deploy_source_map),
+            deploy_opcodes,
+            self.runtime_object
+                .placeholder_bytecode
+                .unwrap_or_else(|| hex::encode(self.runtime_object.bytecode)),
+            self.runtime_object.format,
+            self.runtime_object.unlinked_libraries,
+            self.runtime_object.link_references,
+            self.runtime_object.immutables,
+            Some(runtime_source_map),
+            runtime_opcodes,
+        );
         Ok(())
     }
+
+    ///
+    /// Whether both objects are linked, that is, their bytecode is final.
+    ///
+    pub fn is_linked(&self) -> bool {
+        matches!(
+            self.deploy_object.format,
+            era_compiler_common::ObjectFormat::Raw
+        ) && matches!(
+            self.runtime_object.format,
+            era_compiler_common::ObjectFormat::Raw
+        )
+    }
+
+    ///
+    /// Returns the bytecode size, if both objects are linked.
+    ///
+    pub fn size(&self) -> Option<Size> {
+        if !self.is_linked() {
+            return None;
+        }
+
+        Some(Size::new(
+            self.deploy_object.bytecode.as_slice(),
+            self.runtime_object.bytecode.as_slice(),
+        ))
+    }
+
+    ///
+    /// Disassembles the deploy and runtime code, if they are linked.
+    ///
+    /// The runtime code and data segments appended to the objects are split off as data.
+    ///
+    pub fn disassemble(&self) -> Option<(Disassembly, Disassembly)> {
+        if !self.is_linked() {
+            return None;
+        }
+
+        Some((
+            Disassembly::new(
+                self.deploy_object.bytecode.as_slice(),
+                self.deploy_object.data_layout.as_slice(),
+            ),
+            Disassembly::new(
+                self.runtime_object.bytecode.as_slice(),
+                self.runtime_object.data_layout.as_slice(),
+            ),
+        ))
+    }
+
+    ///
+    /// Returns the method identifiers text, one `<selector>: <signature>` pair per line.
+    ///
+    pub fn method_identifiers_text(&self) -> String {
+        self.method_identifiers
+            .iter()
+            .flatten()
+            .map(|(signature, selector)| format!("{selector}: {signature}\n"))
+            .collect()
+    }
+
+    ///
+    /// Returns the assembly text of the deploy and runtime code, if they are linked.
+    ///
+    pub fn assembly_text(&self) -> Option<String> {
+        let (deploy_disassembly, runtime_disassembly) = self.disassemble()?;
+        Some(format!(
+            ".code {}\n{deploy_disassembly}\n.code {}\n{runtime_disassembly}",
+            self.deploy_object.identifier, self.runtime_object.identifier,
+        ))
+    }
+
+    ///
+    /// Serializes the `solc` JSON artifact, or returns `null` if it is missing.
+    ///
+    fn json_to_string(value: Option<&serde_json::Value>) -> String {
+        serde_json::to_string(value.unwrap_or(&serde_json::Value::Null)).expect("Always valid")
+    }
+
+    ///
+    /// Writes the artifact `data` to the file `output_name` in the `output_path` directory.
+    ///
+    fn write_file(
+        output_path: &Path,
+        output_name: String,
+        data: &str,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        let output_path = output_path.join(output_name);
+        if output_path.exists() && !overwrite {
+            anyhow::bail!(
+                "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
+            );
+        }
+        std::fs::write(output_path.as_path(), data.as_bytes())
+            .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))
+    }
 }
@@ -2,8 +2,14 @@
 //! Bytecode object.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
+use crate::build_evm::contract::size::Size as ContractSize;
+use crate::linker::Linker;
+use crate::source_map::entry::Entry as SourceMapEntry;
+use crate::source_map::SourceMap;
+
 ///
 /// Bytecode object.
 ///
@@ -25,10 +31,22 @@ pub struct Object {
     pub dependencies: solx_yul::Dependencies,
     /// The unlinked unlinked libraries.
     pub unlinked_libraries: BTreeSet<String>,
+    /// The data segments, which are appended to the bytecode if they are among the dependencies.
+    pub data: BTreeMap<String, Vec<u8>>,
+    /// The immutable offsets in the runtime code, keyed by the immutable identifiers.
+    pub immutables: BTreeMap<String, BTreeSet<u64>>,
+    /// The library reference offsets in the linked bytecode, keyed by the library full paths.
+    pub link_references: BTreeMap<String, BTreeSet<usize>>,
+    /// The hexadecimal bytecode with library placeholders at the link reference offsets, if the object is not linked.
+    pub placeholder_bytecode: Option<String>,
     /// Whether the object is already assembled.
     pub is_assembled: bool,
+    /// The identifiers and sizes of the dependencies appended to the code by the assembler, in order.
+    pub data_layout: Vec<(String, usize)>,
     /// Binary object format.
     pub format: era_compiler_common::ObjectFormat,
+    /// Source locations emitted by LLVM, as pairs of bytecode offsets and source map entries.
+    pub source_locations: Vec<(usize, SourceMapEntry)>,
     /// Compilation errors.
     pub errors: Vec<era_compiler_llvm_context::EVMWarning>,
 }
@@ -45,6 +63,9 @@ impl Object {
         code_segment: era_compiler_common::CodeSegment,
         dependencies: solx_yul::Dependencies,
         unlinked_libraries: BTreeSet<String>,
+        data: BTreeMap<String, Vec<u8>>,
+        immutables: BTreeMap<String, BTreeSet<u64>>,
+        source_locations: Vec<(usize, SourceMapEntry)>,
         errors: Vec<era_compiler_llvm_context::EVMWarning>,
     ) -> Self {
         Self {
@@ -55,8 +76,14 @@ impl Object {
             code_segment,
             dependencies,
             unlinked_libraries,
+            data,
+            immutables,
+            link_references: BTreeMap::new(),
+            placeholder_bytecode: None,
             is_assembled: false,
+            data_layout: Vec::new(),
             format: era_compiler_common::ObjectFormat::ELF,
+            source_locations,
             errors,
         }
     }
@@ -68,6 +95,95 @@ impl Object {
         !self.is_assembled && !self.dependencies.inner.is_empty()
     }
 
+    ///
+    /// Returns the source map of the final bytecode.
+    ///
+    /// The data segments appended by the assembler and the runtime code metadata are not mapped.
+    ///
+    pub fn source_map(&self) -> SourceMap {
+        let data_size = self.data_layout.iter().map(|(_, size)| size).sum::<usize>();
+        let mut code_size = self.bytecode.len().saturating_sub(data_size);
+        if let era_compiler_common::CodeSegment::Runtime = self.code_segment {
+            code_size -= ContractSize::metadata_size(&self.bytecode[..code_size]);
+        }
+        SourceMap::from_locations(
+            self.bytecode.as_slice(),
+            code_size,
+            self.source_locations.as_slice(),
+        )
+    }
+
+    ///
+    /// Returns the offsets of the unresolved library references in the bytecode, as it will be
+    /// laid out after linking, and the hexadecimal bytecode with `solc`-compatible placeholders
+    /// written at these offsets.
+    ///
+    /// ELF objects do not expose their relocations, so the offsets are found by linking each library
+    /// with two different placeholder addresses and comparing the results. References to a library
+    /// may be adjacent, so each range of differing bytes is split into address-sized references.
+    ///
+    pub fn get_link_references(
+        &self,
+        linker_symbols: &BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
+    ) -> anyhow::Result<(BTreeMap<String, BTreeSet<usize>>, String)> {
+        let memory_buffer = || {
+            inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
+                self.bytecode.as_slice(),
+                self.identifier.as_str(),
+                false,
+            )
+        };
+        let libraries = memory_buffer().get_undefined_references_evm();
+
+        let mut placeholder_symbols = linker_symbols.to_owned();
+        placeholder_symbols.extend(libraries.iter().map(|library| {
+            (
+                library.to_owned(),
+                [u8::MIN; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS],
+            )
+        }));
+        let (base_bytecode, _) =
+            era_compiler_llvm_context::evm_link(memory_buffer(), &placeholder_symbols)?;
+        let mut placeholder_bytecode = hex::encode(base_bytecode.as_slice());
+
+        let mut link_references = BTreeMap::new();
+        for library in libraries.into_iter() {
+            let mut marked_symbols = placeholder_symbols.clone();
+            marked_symbols.insert(
+                library.clone(),
+                [u8::MAX; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS],
+            );
+            let (marked_bytecode, _) =
+                era_compiler_llvm_context::evm_link(memory_buffer(), &marked_symbols)?;
+
+            let differences = base_bytecode
+                .as_slice()
+                .iter()
+                .zip(marked_bytecode.as_slice().iter())
+                .map(|(base, marked)| base != marked)
+                .collect::<Vec<bool>>();
+            let mut offsets = BTreeSet::new();
+            let mut offset = 0;
+            while offset < differences.len() {
+                if differences[offset] {
+                    offsets.insert(offset);
+                    offset += era_compiler_common::BYTE_LENGTH_ETH_ADDRESS;
+                } else {
+                    offset += 1;
+                }
+            }
+
+            let placeholder = Linker::placeholder(library.as_str());
+            for offset in offsets.iter() {
+                let start = offset * 2;
+                placeholder_bytecode
+                    .replace_range(start..start + placeholder.len(), placeholder.as_str());
+            }
+            link_references.insert(library, offsets);
+        }
+        Ok((link_references, placeholder_bytecode))
+    }
+
     ///
     /// Checks whether the object name matches a dot-separated dependency name.
     ///
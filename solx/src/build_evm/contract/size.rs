@@ -0,0 +1,93 @@
+//!
+//! The contract bytecode size.
+//!
+
+///
+/// The contract bytecode size.
+///
+/// Only available for linked contracts, as the size of unlinked objects is not final.
+///
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Size {
+    /// The deploy code size in bytes, which is the initcode without constructor arguments.
+    pub deploy: usize,
+    /// The runtime code size in bytes.
+    pub runtime: usize,
+    /// The size of the CBOR metadata at the end of the runtime code in bytes.
+    pub metadata: usize,
+}
+
+impl Size {
+    /// The runtime code size limit introduced in EIP-170.
+    pub const RUNTIME_LIMIT: usize = 24576;
+
+    /// The initcode size limit introduced in EIP-3860.
+    pub const DEPLOY_LIMIT: usize = 2 * Self::RUNTIME_LIMIT;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(deploy_bytecode: &[u8], runtime_bytecode: &[u8]) -> Self {
+        Self {
+            deploy: deploy_bytecode.len(),
+            runtime: runtime_bytecode.len(),
+            metadata: Self::metadata_size(runtime_bytecode),
+        }
+    }
+
+    ///
+    /// Returns the number of bytes left until the initcode size limit, negative if exceeded.
+    ///
+    pub fn deploy_headroom(&self) -> isize {
+        Self::DEPLOY_LIMIT as isize - self.deploy as isize
+    }
+
+    ///
+    /// Returns the number of bytes left until the runtime code size limit, negative if exceeded.
+    ///
+    pub fn runtime_headroom(&self) -> isize {
+        Self::RUNTIME_LIMIT as isize - self.runtime as isize
+    }
+
+    ///
+    /// Returns the messages about the exceeded size limits.
+    ///
+    pub fn exceeded_limits(&self) -> Vec<String> {
+        let mut messages = Vec::with_capacity(2);
+        if self.runtime_headroom() < 0 {
+            messages.push(format!(
+                "Contract runtime code size is {} bytes and exceeds the EIP-170 limit of {} bytes. The contract may not be deployable on Mainnet. Consider using `--optimization-size-fallback` or `-Oz`.",
+                self.runtime,
+                Self::RUNTIME_LIMIT,
+            ));
+        }
+        if self.deploy_headroom() < 0 {
+            messages.push(format!(
+                "Contract initcode size is {} bytes and exceeds the EIP-3860 limit of {} bytes. The contract may not be deployable on Mainnet. Consider using `--optimization-size-fallback` or `-Oz`.",
+                self.deploy,
+                Self::DEPLOY_LIMIT,
+            ));
+        }
+        messages
+    }
+
+    ///
+    /// Returns the size of the CBOR metadata at the end of the `bytecode`, or zero if there is none.
+    ///
+    /// The metadata is encoded as a CBOR map followed by its length as a big-endian 16-bit integer.
+    ///
+    pub fn metadata_size(bytecode: &[u8]) -> usize {
+        let size = match bytecode {
+            [.., high, low] => u16::from_be_bytes([*high, *low]) as usize + 2,
+            _ => return 0,
+        };
+        if size > bytecode.len() {
+            return 0;
+        }
+        match bytecode[bytecode.len() - size] {
+            0xa0..=0xb7 => size,
+            _ => 0,
+        }
+    }
+}
@@ -5,12 +5,17 @@
 pub mod contract;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::io::Write;
 use std::path::Path;
+use std::time::Instant;
 
 use solx_solc::CollectableError;
 
+use crate::statistics::Statistics;
+
 use self::contract::object::Object as ContractObject;
+use self::contract::size::Size as ContractSize;
 use self::contract::Contract;
 
 ///
@@ -22,6 +27,10 @@ pub struct Build {
     pub results: BTreeMap<String, Result<Contract, solx_solc::StandardJsonOutputError>>,
     /// The additional message to output.
     pub messages: Vec<solx_solc::StandardJsonOutputError>,
+    /// The compilation timing and statistics.
+    pub statistics: Statistics,
+    /// The warning suppression and promotion settings.
+    pub warning_settings: solx_solc::StandardJsonInputWarnings,
 }
 
 impl Build {
@@ -35,6 +44,8 @@ impl Build {
         Self {
             results,
             messages: std::mem::take(messages),
+            statistics: Statistics::default(),
+            warning_settings: solx_solc::StandardJsonInputWarnings::default(),
         }
     }
 
@@ -45,6 +56,7 @@ impl Build {
         mut self,
         linker_symbols: BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
     ) -> Self {
+        let linking_start = Instant::now();
         let mut contracts: BTreeMap<String, Contract> = self
             .results
             .into_iter()
@@ -64,11 +76,14 @@ impl Build {
                     .filter(|object| {
                         object.requires_assembling()
                             && object.dependencies.inner.iter().all(|dependency| {
-                                all_objects
-                                    .iter()
-                                    .find(|object| object.matches_dependency(dependency.as_str()))
-                                    .map(|object| !object.requires_assembling())
-                                    .unwrap_or_default()
+                                object.data.contains_key(dependency.as_str())
+                                    || all_objects
+                                        .iter()
+                                        .find(|object| {
+                                            object.matches_dependency(dependency.as_str())
+                                        })
+                                        .map(|object| !object.requires_assembling())
+                                        .unwrap_or_default()
                             })
                     })
                     .copied()
@@ -91,6 +106,16 @@ impl Build {
 
                     memory_buffers.extend(object.dependencies.inner.iter().map(|dependency| {
                         let original_dependency_identifier = dependency.to_owned();
+                        if let Some(data) = object.data.get(dependency.as_str()) {
+                            let memory_buffer =
+                                inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
+                                    data.as_slice(),
+                                    dependency.as_str(),
+                                    false,
+                                );
+                            return (original_dependency_identifier, memory_buffer);
+                        }
+
                         let dependency = all_objects
                             .iter()
                             .find(|object| object.matches_dependency(dependency.as_str()))
@@ -168,16 +193,216 @@ impl Build {
                     };
                 object.bytecode = linked_object.as_slice().to_owned();
                 object.format = object_format;
+
+                if let era_compiler_common::ObjectFormat::ELF = object.format {
+                    match object.get_link_references(&linker_symbols) {
+                        Ok((link_references, placeholder_bytecode)) => {
+                            object.link_references = link_references;
+                            object.placeholder_bytecode = Some(placeholder_bytecode);
+                        }
+                        Err(error) => {
+                            self.messages
+                                .push(solx_solc::StandardJsonOutputError::new_error(
+                                    None, error, None, None,
+                                ));
+                        }
+                    }
+                }
+            }
+        }
+
+        let data_layouts = contracts
+            .iter()
+            .flat_map(|(_path, contract)| [&contract.deploy_object, &contract.runtime_object])
+            .map(|object| {
+                let data_layout = if object.is_assembled {
+                    Self::get_data_layout(object, &contracts)
+                } else {
+                    Vec::new()
+                };
+                (
+                    object.contract_name.full_path.to_owned(),
+                    object.code_segment,
+                    data_layout,
+                )
+            })
+            .collect::<Vec<_>>();
+        for (full_path, code_segment, data_layout) in data_layouts.into_iter() {
+            let contract = contracts
+                .get_mut(full_path.as_str())
+                .expect("Always exists");
+            match code_segment {
+                era_compiler_common::CodeSegment::Deploy => {
+                    contract.deploy_object.data_layout = data_layout
+                }
+                era_compiler_common::CodeSegment::Runtime => {
+                    contract.runtime_object.data_layout = data_layout
+                }
             }
         }
 
-        Self::new(
+        let mut build = Self::new(
             contracts
                 .into_iter()
                 .map(|(path, contract)| (path, Ok(contract)))
                 .collect(),
             &mut self.messages,
-        )
+        );
+        build.statistics = self.statistics;
+        build.warning_settings = self.warning_settings;
+        Statistics::add_time(&mut build.statistics.linking_time, linking_start);
+        build
+    }
+
+    ///
+    /// Returns the identifiers and final sizes of the dependencies appended to the `object` code
+    /// by the assembler, in the order they were passed to it.
+    ///
+    fn get_data_layout(
+        object: &ContractObject,
+        contracts: &BTreeMap<String, Contract>,
+    ) -> Vec<(String, usize)> {
+        object
+            .dependencies
+            .inner
+            .iter()
+            .filter_map(|dependency| {
+                let size = match object.data.get(dependency.as_str()) {
+                    Some(data) => data.len(),
+                    None => contracts
+                        .values()
+                        .flat_map(|contract| [&contract.deploy_object, &contract.runtime_object])
+                        .find(|candidate| candidate.matches_dependency(dependency.as_str()))?
+                        .bytecode
+                        .len(),
+                };
+                Some((dependency.to_owned(), size))
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the timing and statistics report, including the bytecode sizes of the contracts.
+    ///
+    /// The metadata size and the headroom until the size limits are only reported for
+    /// the linked contracts.
+    ///
+    pub fn statistics(&self) -> Statistics {
+        let mut statistics = self.statistics.clone();
+        statistics.contracts = self
+            .results
+            .iter()
+            .filter_map(|(path, result)| {
+                let contract = result.as_ref().ok()?;
+                let mut contract_statistics = contract.statistics.clone();
+                contract_statistics.deploy_bytecode_size =
+                    Some(contract.deploy_object.bytecode.len());
+                contract_statistics.runtime_bytecode_size =
+                    Some(contract.runtime_object.bytecode.len());
+                if let Some(size) = contract.size() {
+                    contract_statistics.metadata_size = Some(size.metadata);
+                    contract_statistics.deploy_headroom = Some(size.deploy_headroom());
+                    contract_statistics.runtime_headroom = Some(size.runtime_headroom());
+                }
+                Some((path.to_owned(), contract_statistics))
+            })
+            .collect();
+        statistics
+    }
+
+    ///
+    /// Renders the contract errors again with the input `sources`.
+    ///
+    /// The compilation processes only report the offsets of the errors, as the source code
+    /// is not always available from the file system, e.g. in standard JSON mode.
+    ///
+    pub fn render_errors_with_sources(
+        &mut self,
+        sources: &BTreeMap<String, solx_solc::StandardJsonInputSource>,
+    ) {
+        for error in self
+            .results
+            .values_mut()
+            .filter_map(|result| result.as_mut().err())
+        {
+            error.render_with_sources(sources);
+        }
+    }
+
+    ///
+    /// Checks the linked contracts against the EIP-170 and EIP-3860 bytecode size limits.
+    ///
+    /// The exceeded limits are reported as warnings, or as errors if `is_error` is set.
+    ///
+    pub fn check_bytecode_sizes(&mut self, is_error: bool) {
+        for contract in self
+            .results
+            .values()
+            .filter_map(|result| result.as_ref().ok())
+        {
+            let size = match contract.size() {
+                Some(size) => size,
+                None => continue,
+            };
+            for message in size.exceeded_limits().into_iter() {
+                let source_location = Some(solx_solc::StandardJsonOutputErrorSourceLocation::new(
+                    contract.name.path.clone(),
+                ));
+                self.messages.push(if is_error {
+                    solx_solc::StandardJsonOutputError::new_error(
+                        None,
+                        message,
+                        source_location,
+                        None,
+                    )
+                } else {
+                    solx_solc::StandardJsonOutputError::new_warning(
+                        None,
+                        message,
+                        source_location,
+                        None,
+                    )
+                });
+            }
+        }
+    }
+
+    ///
+    /// Writes the bytecode size report of the linked contracts to stderr.
+    ///
+    /// The headroom is the number of bytes left until the size limit, negative if exceeded.
+    ///
+    pub fn write_size_report(&self) -> anyhow::Result<()> {
+        let sizes = self
+            .results
+            .iter()
+            .filter_map(|(path, result)| Some((path.as_str(), result.as_ref().ok()?.size()?)))
+            .collect::<Vec<(&str, ContractSize)>>();
+        let width = sizes
+            .iter()
+            .map(|(path, _)| path.len())
+            .max()
+            .unwrap_or_default()
+            .max("Contract".len());
+
+        let mut stderr = std::io::stderr().lock();
+        writeln!(
+            stderr,
+            "{:<width$}  {:>8}  {:>8}  {:>8}  {:>8}  {:>8}",
+            "Contract", "Initcode", "Headroom", "Runtime", "Headroom", "Metadata",
+        )?;
+        for (path, size) in sizes.into_iter() {
+            writeln!(
+                stderr,
+                "{path:<width$}  {:>8}  {:>8}  {:>8}  {:>8}  {:>8}",
+                size.deploy,
+                size.deploy_headroom(),
+                size.runtime,
+                size.runtime_headroom(),
+                size.metadata,
+            )?;
+        }
+        Ok(())
     }
 
     ///
@@ -185,16 +410,19 @@ impl Build {
     ///
     pub fn write_to_terminal(
         mut self,
-        output_metadata: bool,
-        output_binary: bool,
+        output_selection: &solx_solc::StandardJsonInputSelection,
+        collected: Option<&mut solx_solc::CollectedDiagnostics>,
     ) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
+        let is_collected = collected.is_some();
+        self.take_and_write_messages(collected)?;
 
-        if !output_metadata && !output_binary {
+        if !output_selection.has_selectors() {
+            if is_collected {
+                return Ok(());
+            }
             writeln!(
                 std::io::stderr(),
-                "Compiler run successful. No output requested. Use flags `--bin` and `--metadata`."
+                "Compiler run successful. No output requested. Use flags `--metadata`, `--asm`, `--bin`, `--bin-runtime`, `--abi`, `--hashes`, `--storage-layout`, `--userdoc`, and `--devdoc`."
             )?;
             return Ok(());
         }
@@ -202,7 +430,7 @@ impl Build {
         for (path, build) in self.results.into_iter() {
             build
                 .expect("Always valid")
-                .write_to_terminal(path, output_metadata, output_binary)?;
+                .write_to_terminal(path, output_selection)?;
         }
 
         Ok(())
@@ -214,31 +442,81 @@ impl Build {
     pub fn write_to_directory(
         mut self,
         output_directory: &Path,
-        output_metadata: bool,
-        output_binary: bool,
+        output_selection: &solx_solc::StandardJsonInputSelection,
         overwrite: bool,
+        collected: Option<&mut solx_solc::CollectedDiagnostics>,
     ) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
+        let is_collected = collected.is_some();
+        self.take_and_write_messages(collected)?;
 
         std::fs::create_dir_all(output_directory)?;
 
         for build in self.results.into_values() {
             build.expect("Always valid").write_to_directory(
                 output_directory,
-                output_metadata,
-                output_binary,
+                output_selection,
                 overwrite,
             )?;
         }
 
-        writeln!(
-            std::io::stderr(),
-            "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
-        )?;
+        if !is_collected {
+            writeln!(
+                std::io::stderr(),
+                "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
+            )?;
+        }
         Ok(())
     }
 
+    ///
+    /// Writes all contracts to the combined JSON.
+    ///
+    pub fn write_to_combined_json(
+        mut self,
+        selectors: &BTreeSet<solx_solc::CombinedJsonSelector>,
+        combined_json: &mut solx_solc::CombinedJson,
+        collected: Option<&mut solx_solc::CollectedDiagnostics>,
+    ) -> anyhow::Result<()> {
+        self.take_and_write_messages(collected)?;
+
+        for (path, build) in self.results.into_iter() {
+            let combined_json_contract = combined_json.contracts.entry(path).or_default();
+            build
+                .expect("Always valid")
+                .write_to_combined_json(selectors, combined_json_contract);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Moves the ABI, method identifiers, storage layout, and documentation from the `solc` output
+    /// to the contract builds.
+    ///
+    pub fn take_solc_data(&mut self, solc_output: &mut solx_solc::StandardJsonOutput) {
+        for build in self
+            .results
+            .values_mut()
+            .filter_map(|result| result.as_mut().ok())
+        {
+            let solc_contract = solc_output
+                .contracts
+                .get_mut(build.name.path.as_str())
+                .and_then(|contracts| {
+                    contracts.get_mut(
+                        build
+                            .name
+                            .name
+                            .as_deref()
+                            .unwrap_or(build.name.path.as_str()),
+                    )
+                });
+            if let Some(solc_contract) = solc_contract {
+                build.take_solc_data(solc_contract);
+            }
+        }
+    }
+
     ///
     /// Writes all contracts assembly and bytecode to the standard JSON.
     ///
@@ -255,14 +533,14 @@ impl Build {
                             .deploy_object
                             .errors
                             .iter()
-                            .map(|error| (build.name.full_path.as_str(), error).into()),
+                            .map(|error| (build.name.path.as_str(), error).into()),
                     );
                     errors.extend(
                         build
                             .runtime_object
                             .errors
                             .iter()
-                            .map(|error| (build.name.full_path.as_str(), error).into()),
+                            .map(|error| (build.name.path.as_str(), error).into()),
                     );
                     build
                 }
@@ -315,6 +593,20 @@ impl solx_solc::CollectableError for Build {
     }
 
     fn take_warnings(&mut self) -> Vec<solx_solc::StandardJsonOutputError> {
+        for build in self
+            .results
+            .values_mut()
+            .filter_map(|result| result.as_mut().ok())
+        {
+            for object in [&mut build.deploy_object, &mut build.runtime_object] {
+                for warning in std::mem::take(&mut object.errors).iter() {
+                    self.messages
+                        .push((build.name.path.as_str(), warning).into());
+                }
+            }
+        }
+        self.warning_settings.apply(&mut self.messages);
+
         let warnings = self
             .messages
             .iter()
@@ -0,0 +1,181 @@
+//!
+//! The persistent on-disk compilation cache.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use crate::build_evm::contract::Contract as EVMContractBuild;
+use crate::process::input_evm::Input as EVMProcessInput;
+use crate::project::contract::Contract;
+
+///
+/// The counter of temporary entry files written by this process.
+///
+static TEMPORARY_FILES: AtomicUsize = AtomicUsize::new(0);
+
+///
+/// The persistent on-disk compilation cache.
+///
+/// Each entry is a contract build stored in a separate file named after the `keccak256` hash
+/// of the serialized contract, the settings, and the project data the contract depends on,
+/// together with the compiler, `solc`, and LLVM versions.
+/// Thus, any change to the contract IR or settings results in a new entry, and stale entries
+/// are never reused.
+///
+#[derive(Debug)]
+pub struct Cache {
+    /// The cache directory.
+    pub path: PathBuf,
+    /// The number of cache hits.
+    hits: AtomicUsize,
+    /// The number of cache misses.
+    misses: AtomicUsize,
+}
+
+///
+/// The cache key data.
+///
+/// Only the project-wide data the contract actually depends on is included, so changes to
+/// unrelated contracts do not invalidate its entry.
+///
+#[derive(serde::Serialize)]
+struct Key<'a> {
+    /// The `solx` version.
+    solx_version: &'static str,
+    /// The `solc` version.
+    solc_version: &'a str,
+    /// The LLVM version.
+    llvm_version: String,
+    /// The input contract.
+    contract: &'a Contract,
+    /// The identifier paths of the contract dependencies.
+    identifier_paths: BTreeMap<&'a str, &'a str>,
+    /// The already deployed libraries used by the contract.
+    deployed_libraries: BTreeSet<&'a str>,
+    /// The EVM version to produce bytecode for.
+    evm_version: Option<&'a era_compiler_common::EVMVersion>,
+    /// The metadata hash type.
+    metadata_hash_type: &'a era_compiler_common::HashType,
+    /// The optimizer settings.
+    optimizer_settings: &'a era_compiler_llvm_context::OptimizerSettings,
+    /// The extra LLVM arguments.
+    llvm_options: &'a [String],
+}
+
+impl Cache {
+    /// The cache entry file extension.
+    pub const EXTENSION: &'static str = "json";
+
+    ///
+    /// Opens the cache at `path`, creating the directory if it does not exist.
+    ///
+    pub fn try_new(path: PathBuf) -> anyhow::Result<Self> {
+        std::fs::create_dir_all(path.as_path())
+            .map_err(|error| anyhow::anyhow!("Cache directory {path:?} creating: {error}"))?;
+        Ok(Self {
+            path,
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        })
+    }
+
+    ///
+    /// Returns the cache key of the contract process input.
+    ///
+    pub fn key(input: &EVMProcessInput, solc_version: &solx_solc::Version) -> String {
+        let dependency_identifiers = input.contract.ir.get_dependency_identifiers();
+        let unlinked_libraries = input.contract.ir.get_unlinked_libraries();
+        let key = Key {
+            solx_version: env!("CARGO_PKG_VERSION"),
+            solc_version: solc_version.long.as_str(),
+            llvm_version: inkwell::support::get_commit_id().to_string(),
+            contract: &input.contract,
+            identifier_paths: input
+                .identifier_paths
+                .iter()
+                .filter(|(identifier, _path)| dependency_identifiers.contains(identifier.as_str()))
+                .map(|(identifier, path)| (identifier.as_str(), path.as_str()))
+                .collect(),
+            deployed_libraries: input
+                .deployed_libraries
+                .iter()
+                .filter(|library| unlinked_libraries.contains(library.as_str()))
+                .map(String::as_str)
+                .collect(),
+            evm_version: input.evm_version.as_ref(),
+            metadata_hash_type: &input.metadata_hash_type,
+            optimizer_settings: &input.optimizer_settings,
+            llvm_options: input.llvm_options.as_slice(),
+        };
+        let json = serde_json::to_vec(&key).expect("Always valid");
+        era_compiler_common::Hash::keccak256(json.as_slice()).to_string()
+    }
+
+    ///
+    /// Returns the cached contract build, if any.
+    ///
+    /// Unreadable or corrupted entries are treated as misses.
+    ///
+    pub fn get(&self, key: &str) -> Option<EVMContractBuild> {
+        let build = std::fs::read(self.entry_path(key))
+            .ok()
+            .and_then(|data| era_compiler_common::deserialize_from_slice(data.as_slice()).ok());
+        match build {
+            Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+        build
+    }
+
+    ///
+    /// Stores the contract build.
+    ///
+    /// The entry is written to a uniquely named temporary file first and then renamed, so
+    /// concurrent compiler processes and threads sharing the cache never observe partially
+    /// written entries.
+    ///
+    pub fn put(&self, key: &str, build: &EVMContractBuild) -> anyhow::Result<()> {
+        let path = self.entry_path(key);
+        let mut temporary_path = path.clone();
+        temporary_path.set_extension(format!(
+            "{}.{}.{}",
+            Self::EXTENSION,
+            std::process::id(),
+            TEMPORARY_FILES.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let data = serde_json::to_vec(build).expect("Always valid");
+        std::fs::write(temporary_path.as_path(), data)
+            .map_err(|error| anyhow::anyhow!("Cache entry {temporary_path:?} writing: {error}"))?;
+        std::fs::rename(temporary_path.as_path(), path.as_path())
+            .map_err(|error| anyhow::anyhow!("Cache entry {path:?} renaming: {error}"))?;
+        Ok(())
+    }
+
+    ///
+    /// Returns the number of cache hits.
+    ///
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    ///
+    /// Returns the number of cache misses.
+    ///
+    pub fn misses(&self) -> usize {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    ///
+    /// Returns the path to the cache entry file.
+    ///
+    fn entry_path(&self, key: &str) -> PathBuf {
+        let mut path = self.path.join(key);
+        path.set_extension(Self::EXTENSION);
+        path
+    }
+}
@@ -14,6 +14,24 @@ pub const OFFSET_EMPTY_SLOT: usize = 3 * era_compiler_common::BYTE_LENGTH_FIELD;
 /// The non-reserved memory offset.
 pub const OFFSET_NON_RESERVED: usize = 4 * era_compiler_common::BYTE_LENGTH_FIELD;
 
+/// The EVM assembly file extension.
+pub const EXTENSION_EVM_ASSEMBLY: &str = "asm";
+
+/// The EVM runtime bytecode file extension.
+pub const EXTENSION_EVM_BINARY_RUNTIME: &str = "bin-runtime";
+
+/// The ABI specification file extension.
+pub const EXTENSION_ABI: &str = "abi";
+
+/// The method identifiers file extension.
+pub const EXTENSION_METHOD_IDENTIFIERS: &str = "signatures";
+
+/// The user documentation file extension.
+pub const EXTENSION_USERDOC: &str = "docuser";
+
+/// The developer documentation file extension.
+pub const EXTENSION_DEVDOC: &str = "docdev";
+
 ///
 /// The compiler version default function.
 ///
@@ -0,0 +1,162 @@
+//!
+//! The diagnostic with a resolved source location.
+//!
+
+use std::collections::BTreeMap;
+
+use super::related_location::RelatedLocation;
+
+///
+/// The diagnostic with a resolved source location.
+///
+/// The line and column numbers are one-based.
+///
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostic {
+    /// The compiler message.
+    #[serde(flatten)]
+    pub error: solx_solc::StandardJsonOutputError,
+    /// The line number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// The column number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    /// The last line number of the highlighted area.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+    /// The column number right after the highlighted area on the last line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
+    /// The length of the highlighted area.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<usize>,
+    /// The related locations resolved from the secondary source locations.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related_locations: Vec<RelatedLocation>,
+}
+
+impl Diagnostic {
+    ///
+    /// Resolves the message source location offsets in the `sources`.
+    ///
+    pub fn new(
+        error: solx_solc::StandardJsonOutputError,
+        sources: &BTreeMap<String, String>,
+    ) -> Self {
+        let source_code = |path: &str| sources.get(path).map(String::as_str);
+
+        let mapped_location = error.source_location.as_ref().map(|source_location| {
+            solx_solc::StandardJsonOutputErrorMappedLocation::try_from_source_location(
+                source_location,
+                source_code(source_location.file.as_str()),
+            )
+        });
+        let (line, column, end_line, end_column, length) = match mapped_location {
+            Some(mapped_location) => (
+                mapped_location.line,
+                mapped_location.column.map(|column| column + 1),
+                mapped_location.end_line,
+                mapped_location.end_column.map(|column| column + 1),
+                mapped_location.length,
+            ),
+            None => (None, None, None, None, None),
+        };
+        let related_locations = error
+            .secondary_source_locations
+            .iter()
+            .map(|secondary_source_location| {
+                RelatedLocation::new(
+                    secondary_source_location,
+                    source_code(secondary_source_location.file.as_str()),
+                )
+            })
+            .collect();
+
+        Self {
+            error,
+            line,
+            column,
+            end_line,
+            end_column,
+            length,
+            related_locations,
+        }
+    }
+
+    ///
+    /// Returns the SARIF result.
+    ///
+    pub fn to_sarif(&self) -> serde_json::Value {
+        let level = match self.error.severity.as_str() {
+            "error" => "error",
+            "warning" => "warning",
+            _ => "note",
+        };
+
+        let mut result = serde_json::json!({
+            "ruleId": self.rule_id(),
+            "level": level,
+            "message": { "text": self.error.message.trim() },
+        });
+        if let Some(source_location) = self.error.source_location.as_ref() {
+            let physical_location = Self::sarif_physical_location(
+                source_location.file.as_str(),
+                self.line,
+                self.column,
+                self.end_line,
+                self.end_column.or_else(|| {
+                    self.column
+                        .map(|column| column + self.length.unwrap_or_default())
+                }),
+            );
+            result["locations"] = serde_json::json!([{ "physicalLocation": physical_location }]);
+        }
+        if !self.related_locations.is_empty() {
+            result["relatedLocations"] = self
+                .related_locations
+                .iter()
+                .enumerate()
+                .map(|(index, related_location)| related_location.to_sarif(index + 1))
+                .collect();
+        }
+        result
+    }
+
+    ///
+    /// Returns the SARIF physical location of the `file`, with the region if the position is resolved.
+    ///
+    pub fn sarif_physical_location(
+        file: &str,
+        line: Option<usize>,
+        column: Option<usize>,
+        end_line: Option<usize>,
+        end_column: Option<usize>,
+    ) -> serde_json::Value {
+        let mut physical_location = serde_json::json!({
+            "artifactLocation": { "uri": file.replace('\\', "/") },
+        });
+        if let (Some(line), Some(column)) = (line, column) {
+            physical_location["region"] = serde_json::json!({
+                "startLine": line,
+                "startColumn": column,
+                "endLine": end_line.unwrap_or(line),
+                "endColumn": end_column.unwrap_or(column),
+            });
+        }
+        physical_location
+    }
+
+    ///
+    /// Returns the SARIF rule identifier.
+    ///
+    /// The messages without an error code are identified by their type, e.g. `TypeError`.
+    ///
+    pub fn rule_id(&self) -> String {
+        self.error
+            .error_code
+            .clone()
+            .unwrap_or_else(|| self.error.r#type.clone())
+    }
+}
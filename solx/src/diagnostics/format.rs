@@ -0,0 +1,44 @@
+//!
+//! The diagnostics output format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The diagnostics output format.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The human-readable messages written as soon as they are produced.
+    #[default]
+    Human,
+    /// The JSON array of messages with resolved lines and columns.
+    JSON,
+    /// The SARIF 2.1.0 log.
+    SARIF,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::JSON),
+            "sarif" => Ok(Self::SARIF),
+            string => anyhow::bail!(
+                "Invalid diagnostics format: `{string}`. Available formats: `human`, `json`, `sarif`."
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Human => write!(f, "human"),
+            Self::JSON => write!(f, "json"),
+            Self::SARIF => write!(f, "sarif"),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//!
+//! The compiler diagnostics.
+//!
+
+pub mod diagnostic;
+pub mod format;
+pub mod related_location;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io::Write;
+
+use self::diagnostic::Diagnostic;
+use self::format::Format;
+
+///
+/// The compiler diagnostics.
+///
+#[derive(Debug)]
+pub struct Diagnostics {
+    /// The diagnostics with resolved source locations.
+    pub inner: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    /// The SARIF schema URI.
+    pub const SARIF_SCHEMA: &'static str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+    /// The SARIF version.
+    pub const SARIF_VERSION: &'static str = "2.1.0";
+
+    ///
+    /// Resolves the source locations of `messages` in the `sources` they have been compiled from.
+    ///
+    pub fn new(
+        messages: Vec<solx_solc::StandardJsonOutputError>,
+        sources: &BTreeMap<String, String>,
+    ) -> Self {
+        let inner = messages
+            .into_iter()
+            .map(|error| Diagnostic::new(error, sources))
+            .collect();
+        Self { inner }
+    }
+
+    ///
+    /// Writes the messages to stderr in the specified format.
+    ///
+    /// The machine-readable formats resolve the source locations in the `sources`.
+    ///
+    pub fn write_to_terminal(
+        messages: Vec<solx_solc::StandardJsonOutputError>,
+        sources: &BTreeMap<String, String>,
+        format: Format,
+    ) -> anyhow::Result<()> {
+        let mut stderr = std::io::stderr();
+        match format {
+            Format::Human => {
+                stderr.write_all(
+                    messages
+                        .into_iter()
+                        .map(|error| error.to_terminal_string())
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                        .as_bytes(),
+                )?;
+            }
+            Format::JSON => {
+                serde_json::to_writer(&mut stderr, &Self::new(messages, sources).inner)?;
+                writeln!(stderr)?;
+            }
+            Format::SARIF => {
+                serde_json::to_writer(&mut stderr, &Self::new(messages, sources).to_sarif())?;
+                writeln!(stderr)?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the SARIF log with a single run.
+    ///
+    pub fn to_sarif(&self) -> serde_json::Value {
+        let rules: BTreeSet<String> = self.inner.iter().map(Diagnostic::rule_id).collect();
+        let rules: Vec<serde_json::Value> = rules
+            .into_iter()
+            .map(|rule_id| serde_json::json!({ "id": rule_id }))
+            .collect();
+        let results: Vec<serde_json::Value> = self.inner.iter().map(Diagnostic::to_sarif).collect();
+
+        serde_json::json!({
+            "$schema": Self::SARIF_SCHEMA,
+            "version": Self::SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "solx",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    },
+                },
+                "results": results,
+            }],
+        })
+    }
+}
@@ -0,0 +1,77 @@
+//!
+//! The diagnostic related location with a resolved position.
+//!
+
+///
+/// The diagnostic related location with a resolved position.
+///
+/// The line and column numbers are one-based.
+///
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelatedLocation {
+    /// The source file path.
+    pub file: String,
+    /// The note message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The line number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// The column number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    /// The last line number of the highlighted area.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+    /// The column number right after the highlighted area on the last line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
+}
+
+impl RelatedLocation {
+    ///
+    /// Resolves the secondary source location offsets in `source_code`.
+    ///
+    pub fn new(
+        secondary_source_location: &solx_solc::StandardJsonOutputErrorSecondarySourceLocation,
+        source_code: Option<&str>,
+    ) -> Self {
+        let source_location =
+            solx_solc::StandardJsonOutputErrorSourceLocation::from(secondary_source_location);
+        let mapped_location =
+            solx_solc::StandardJsonOutputErrorMappedLocation::try_from_source_location(
+                &source_location,
+                source_code,
+            );
+
+        Self {
+            file: secondary_source_location.file.to_owned(),
+            message: secondary_source_location.message.to_owned(),
+            line: mapped_location.line,
+            column: mapped_location.column.map(|column| column + 1),
+            end_line: mapped_location.end_line,
+            end_column: mapped_location.end_column.map(|column| column + 1),
+        }
+    }
+
+    ///
+    /// Returns the SARIF related location with the `id` unique within the result.
+    ///
+    pub fn to_sarif(&self, id: usize) -> serde_json::Value {
+        let mut related_location = serde_json::json!({
+            "id": id,
+            "physicalLocation": super::diagnostic::Diagnostic::sarif_physical_location(
+                self.file.as_str(),
+                self.line,
+                self.column,
+                self.end_line,
+                self.end_column,
+            ),
+        });
+        if let Some(message) = self.message.as_deref() {
+            related_location["message"] = serde_json::json!({ "text": message.trim() });
+        }
+        related_location
+    }
+}
@@ -0,0 +1,38 @@
+//!
+//! The EVM bytecode disassembly instruction.
+//!
+
+use crate::opcode::Opcode;
+
+///
+/// The EVM bytecode disassembly instruction.
+///
+#[derive(Debug)]
+pub struct Instruction {
+    /// The opcode byte.
+    pub opcode: u8,
+    /// The immediate data of `PUSH*` instructions.
+    pub data: Vec<u8>,
+}
+
+impl Instruction {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(opcode: u8, data: Vec<u8>) -> Self {
+        Self { opcode, data }
+    }
+}
+
+impl std::fmt::Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match Opcode::get(self.opcode) {
+            Some(opcode) => write!(f, "{}", opcode.name)?,
+            None => write!(f, "INVALID")?,
+        }
+        if (Opcode::PUSH1..=Opcode::PUSH32).contains(&self.opcode) {
+            write!(f, " 0x{}", hex::encode(self.data.as_slice()))?;
+        }
+        Ok(())
+    }
+}
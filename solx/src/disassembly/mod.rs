@@ -0,0 +1,98 @@
+//!
+//! The EVM bytecode disassembly.
+//!
+
+pub mod instruction;
+
+use crate::opcode::Opcode;
+
+use self::instruction::Instruction;
+
+///
+/// The EVM bytecode disassembly.
+///
+/// The bytecode is split into the code and the data segments appended by the assembler, such as
+/// the runtime code of the deploy code object, and Yul data. The data segments are laid out
+/// after the code in the order they were passed to the assembler, so the code ends where the
+/// total size of the segments begins.
+///
+#[derive(Debug)]
+pub struct Disassembly {
+    /// The code instructions, as pairs of offsets and instructions with immediate data.
+    pub instructions: Vec<(usize, Instruction)>,
+    /// The data segments, as pairs of offsets and optional names with the data.
+    pub data: Vec<(usize, Option<String>, Vec<u8>)>,
+}
+
+impl Disassembly {
+    /// The number of bytes in a data segment line.
+    pub const DATA_LINE_LENGTH: usize = era_compiler_common::BYTE_LENGTH_FIELD;
+
+    ///
+    /// Disassembles the `bytecode`, splitting off the data segments described by the `data_layout`
+    /// as pairs of names and sizes.
+    ///
+    pub fn new(bytecode: &[u8], data_layout: &[(String, usize)]) -> Self {
+        let data_size = data_layout.iter().map(|(_, size)| size).sum::<usize>();
+        let code_end = bytecode.len().saturating_sub(data_size);
+
+        let mut instructions = Vec::new();
+        let mut offset = 0;
+        while offset < code_end {
+            let opcode = bytecode[offset];
+            let size = Opcode::size(opcode);
+            let data = bytecode[offset + 1..(offset + size).min(code_end)].to_owned();
+            instructions.push((offset, Instruction::new(opcode, data)));
+            offset += size;
+        }
+
+        let mut data = Vec::new();
+        let mut cursor = code_end;
+        for (name, size) in data_layout.iter() {
+            let end = (cursor + size).min(bytecode.len());
+            data.push((
+                cursor,
+                Some(name.to_owned()),
+                bytecode[cursor..end].to_owned(),
+            ));
+            cursor = end;
+        }
+        if cursor < bytecode.len() {
+            data.push((cursor, None, bytecode[cursor..].to_owned()));
+        }
+
+        Self { instructions, data }
+    }
+
+    ///
+    /// Returns the `solc`-compatible space-separated list of opcodes.
+    ///
+    pub fn opcodes(&self) -> String {
+        self.instructions
+            .iter()
+            .map(|(_, instruction)| instruction.to_string())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+impl std::fmt::Display for Disassembly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (offset, instruction) in self.instructions.iter() {
+            if instruction.opcode == Opcode::JUMPDEST {
+                writeln!(f, "tag_{offset:04x}:")?;
+            }
+            writeln!(f, "    /* {offset:04x} */ {instruction}")?;
+        }
+        for (offset, name, data) in self.data.iter() {
+            match name {
+                Some(name) => writeln!(f, ".data {name} /* {offset:04x} */")?,
+                None => writeln!(f, ".data /* {offset:04x} */")?,
+            }
+            for line in data.chunks(Self::DATA_LINE_LENGTH) {
+                writeln!(f, "    {}", hex::encode(line))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//!
+//! The backend error located in the source code.
+//!
+
+use std::collections::BTreeMap;
+
+use solx_yul::yul::lexer::token::source_location::SourceLocation;
+
+///
+/// The backend error located in the source code.
+///
+/// Wraps an error of translating an EVM legacy assembly instruction or a Yul statement, so it
+/// can be reported at the offending Solidity code. The error message is left intact.
+///
+#[derive(Debug)]
+pub struct Located {
+    /// The source location of the instruction or statement being translated.
+    pub location: SourceLocation,
+    /// The original error.
+    pub error: anyhow::Error,
+}
+
+impl Located {
+    ///
+    /// Wraps the `error` with the `location`.
+    ///
+    /// The errors already located by a nested instruction or statement are left as is,
+    /// so the innermost known location is reported. The unknown locations are ignored.
+    ///
+    pub fn wrap(error: anyhow::Error, location: SourceLocation) -> anyhow::Error {
+        if error.is::<Self>() || location.is_unknown() {
+            return error;
+        }
+        anyhow::Error::new(Self { location, error })
+    }
+
+    ///
+    /// Maps the `error` with `map`, preserving its location if there is one.
+    ///
+    pub fn map<F>(error: anyhow::Error, map: F) -> anyhow::Error
+    where
+        F: FnOnce(anyhow::Error) -> anyhow::Error,
+    {
+        match error.downcast::<Self>() {
+            Ok(mut located) => {
+                located.error = map(located.error);
+                anyhow::Error::new(located)
+            }
+            Err(error) => map(error),
+        }
+    }
+
+    ///
+    /// Returns the source location of the `error` with its file index resolved with `source_paths`.
+    ///
+    pub fn source_location(
+        error: &anyhow::Error,
+        source_paths: &BTreeMap<usize, String>,
+    ) -> Option<solx_solc::StandardJsonOutputErrorSourceLocation> {
+        let located = error.downcast_ref::<Self>()?;
+        let path = source_paths.get(&usize::try_from(located.location.file).ok()?)?;
+        Some(
+            solx_solc::StandardJsonOutputErrorSourceLocation::new_with_offsets(
+                path.to_owned(),
+                located.location.start,
+                located.location.end,
+            ),
+        )
+    }
+}
+
+impl std::error::Error for Located {}
+
+impl std::fmt::Display for Located {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
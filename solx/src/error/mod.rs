@@ -0,0 +1,5 @@
+//!
+//! The compiler errors.
+//!
+
+pub mod located;
@@ -9,6 +9,8 @@ pub mod stack;
 
 use std::collections::BTreeMap;
 
+use solx_yul::yul::lexer::token::source_location::SourceLocation;
+
 use self::name::Name;
 
 ///
@@ -314,6 +316,13 @@ impl Instruction {
         Ok(())
     }
 
+    ///
+    /// Returns the Solidity source location of the instruction.
+    ///
+    pub fn source_location(&self) -> SourceLocation {
+        SourceLocation::new(self.source.unwrap_or(-1), self.begin, self.end)
+    }
+
     ///
     /// Initializes an `INVALID` instruction to terminate an invalid unreachable block part.
     ///
@@ -8,6 +8,7 @@ pub mod instruction;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashSet;
+use std::time::Instant;
 
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
@@ -16,6 +17,8 @@ use era_compiler_llvm_context::IContext;
 
 use crate::evmla::ethereal_ir::entry_link::EntryLink;
 use crate::evmla::ethereal_ir::EtherealIR;
+use crate::statistics::contract::Contract as ContractStatistics;
+use crate::statistics::Statistics;
 
 use self::data::Data;
 use self::instruction::name::Name as InstructionName;
@@ -369,17 +372,15 @@ impl Assembly {
 
         Ok(index_path_mapping)
     }
-}
 
-impl era_compiler_llvm_context::EVMWriteLLVM for Assembly {
-    fn declare(
-        &mut self,
-        _context: &mut era_compiler_llvm_context::EVMContext,
+    ///
+    /// Translates the assembly into LLVM IR, recording the EthIR statistics.
+    ///
+    pub fn into_llvm_with_statistics(
+        self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+        statistics: &mut ContractStatistics,
     ) -> anyhow::Result<()> {
-        Ok(())
-    }
-
-    fn into_llvm(self, context: &mut era_compiler_llvm_context::EVMContext) -> anyhow::Result<()> {
         let full_path = self.full_path().to_owned();
 
         let (code_segment, blocks) = if let Ok(runtime_code) = self.runtime_code() {
@@ -429,12 +430,15 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Assembly {
             (era_compiler_common::CodeSegment::Runtime, blocks)
         };
 
+        let ethir_traversal_start = Instant::now();
         let mut ethereal_ir = EtherealIR::new(
             context.evmla().expect("Always exists").version.to_owned(),
             self.extra_metadata.unwrap_or_default(),
             Some(code_segment),
             blocks,
         )?;
+        Statistics::add_time(&mut statistics.ethir_traversal_time, ethir_traversal_start);
+        statistics.add_ethir(ethereal_ir.blocks_count(), ethereal_ir.functions_count());
         if let Some(debug_config) = context.debug_config() {
             debug_config.dump_ethir(
                 format!("{full_path}.{code_segment}").as_str(),
@@ -453,6 +457,19 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Assembly {
     }
 }
 
+impl era_compiler_llvm_context::EVMWriteLLVM for Assembly {
+    fn declare(
+        &mut self,
+        _context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn into_llvm(self, context: &mut era_compiler_llvm_context::EVMContext) -> anyhow::Result<()> {
+        self.into_llvm_with_statistics(context, &mut ContractStatistics::default())
+    }
+}
+
 impl std::fmt::Display for Assembly {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(instructions) = self.code.as_ref() {
@@ -78,6 +78,15 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Element {
         mut self,
         context: &mut era_compiler_llvm_context::EVMContext,
     ) -> anyhow::Result<()> {
+        match self.instruction.source {
+            Some(source) => context.set_source_location(
+                source,
+                self.instruction.begin,
+                self.instruction.end - self.instruction.begin,
+            ),
+            None => context.set_source_location(-1, -1, -1),
+        }
+
         let mut original = self.instruction.value.clone();
 
         let result = match self.instruction.name.clone() {
@@ -1099,8 +1108,10 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Element {
                     .map(Some)
             }
             InstructionName::BLOBHASH => {
-                let _arguments = self.pop_arguments_llvm(context)?;
-                anyhow::bail!("The `BLOBHASH` instruction is not supported");
+                let arguments = self.pop_arguments_llvm(context)?;
+                let index = arguments[0].into_int_value();
+
+                era_compiler_llvm_context::evm_contract_context::blob_hash(context, index).map(Some)
             }
             InstructionName::DIFFICULTY | InstructionName::PREVRANDAO => {
                 era_compiler_llvm_context::evm_contract_context::difficulty(context).map(Some)
@@ -1112,7 +1123,7 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Element {
                 era_compiler_llvm_context::evm_contract_context::basefee(context).map(Some)
             }
             InstructionName::BLOBBASEFEE => {
-                anyhow::bail!("The `BLOBBASEFEE` instruction is not supported");
+                era_compiler_llvm_context::evm_contract_context::blob_base_fee(context).map(Some)
             }
             InstructionName::MSIZE => {
                 era_compiler_llvm_context::evm_contract_context::msize(context).map(Some)
@@ -8,6 +8,7 @@ use std::collections::HashSet;
 
 use num::Zero;
 
+use crate::error::located::Located as LocatedError;
 use crate::evmla::assembly::instruction::name::Name as InstructionName;
 use crate::evmla::assembly::instruction::Instruction;
 
@@ -121,7 +122,10 @@ impl Block {
 impl era_compiler_llvm_context::EVMWriteLLVM for Block {
     fn into_llvm(self, context: &mut era_compiler_llvm_context::EVMContext) -> anyhow::Result<()> {
         for element in self.elements.into_iter() {
-            element.into_llvm(context)?;
+            let source_location = element.instruction.source_location();
+            element
+                .into_llvm(context)
+                .map_err(|error| LocatedError::wrap(error, source_location))?;
         }
 
         Ok(())
@@ -25,6 +25,7 @@ use num::Zero;
 use era_compiler_llvm_context::IContext;
 use era_compiler_llvm_context::IEVMLAFunction;
 
+use crate::error::located::Located as LocatedError;
 use crate::evmla::assembly::instruction::name::Name as InstructionName;
 use crate::evmla::assembly::instruction::Instruction;
 use crate::evmla::ethereal_ir::function::block::element::stack::element::Element;
@@ -159,6 +160,8 @@ impl Function {
     ///
     /// Consumes the entry or a conditional block attached to another one.
     ///
+    /// The errors of consuming the destination blocks are located at the jumps that lead to them.
+    ///
     fn consume_block(
         &mut self,
         blocks: &HashMap<era_compiler_llvm_context::BlockKey, Block>,
@@ -219,6 +222,7 @@ impl Function {
         block.elements.truncate(block_size);
 
         for element in queue.into_iter() {
+            let source_location = element.source_location;
             self.consume_block(
                 blocks,
                 functions,
@@ -226,7 +230,8 @@ impl Function {
                 visited_functions,
                 visited_blocks,
                 element,
-            )?;
+            )
+            .map_err(|error| LocatedError::wrap(error, source_location))?;
         }
 
         Ok(())
@@ -995,6 +1000,7 @@ impl Function {
 
         if let Some(mut queue_element) = queue_element {
             block_element.stack.clone_into(&mut queue_element.stack);
+            queue_element.source_location = block_element.instruction.source_location();
             queue.push(queue_element);
         }
 
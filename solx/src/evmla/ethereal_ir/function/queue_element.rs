@@ -2,6 +2,8 @@
 //! The Ethereal IR block queue element.
 //!
 
+use solx_yul::yul::lexer::token::source_location::SourceLocation;
+
 use crate::evmla::ethereal_ir::function::block::element::stack::Stack;
 
 ///
@@ -15,6 +17,8 @@ pub struct QueueElement {
     pub predecessor: Option<(era_compiler_llvm_context::BlockKey, usize)>,
     /// The predecessor's last stack state.
     pub stack: Stack,
+    /// The source location of the instruction that has queued the block.
+    pub source_location: SourceLocation,
 }
 
 impl QueueElement {
@@ -30,6 +34,7 @@ impl QueueElement {
             block_key,
             predecessor,
             stack,
+            source_location: SourceLocation::new(-1, -1, -1),
         }
     }
 }
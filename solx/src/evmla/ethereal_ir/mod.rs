@@ -70,6 +70,23 @@ impl EtherealIR {
         })
     }
 
+    ///
+    /// Returns the number of blocks in all functions.
+    ///
+    pub fn blocks_count(&self) -> usize {
+        std::iter::once(&self.entry_function)
+            .chain(self.recursive_functions.values())
+            .map(|function| function.blocks.values().map(Vec::len).sum::<usize>())
+            .sum()
+    }
+
+    ///
+    /// Returns the number of functions, including the entry one.
+    ///
+    pub fn functions_count(&self) -> usize {
+        1 + self.recursive_functions.len()
+    }
+
     ///
     /// Gets blocks for the specified type of the contract code.
     ///
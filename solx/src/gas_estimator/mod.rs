@@ -0,0 +1,288 @@
+//!
+//! The static gas estimator.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use num::ToPrimitive;
+use num::Zero;
+
+use crate::opcode::Opcode;
+
+/// The abstract stack value, which is `None` if unknown at compile time.
+type Value = Option<num::BigUint>;
+
+///
+/// The static gas estimator.
+///
+/// Runs an abstract interpretation of the final bytecode, tracking the stack values known at
+/// compile time in order to resolve the jump destinations and the function dispatcher branches.
+/// The estimate is the most expensive path from the entry point to a halting instruction.
+/// If a path contains a loop or an unresolvable jump, the cost is considered infinite.
+///
+#[derive(Debug)]
+pub struct GasEstimator<'a> {
+    /// The bytecode to analyze.
+    bytecode: &'a [u8],
+    /// The valid jump destinations.
+    jump_destinations: BTreeSet<usize>,
+    /// The function selector passed via calldata, if any.
+    selector: Option<u32>,
+    /// The finite costs of the already analyzed blocks, keyed by their offset and input stack.
+    costs: HashMap<(usize, Vec<Value>), u64>,
+    /// The block offsets on the current path.
+    path: Vec<usize>,
+    /// The number of analyzed blocks.
+    steps: usize,
+}
+
+impl<'a> GasEstimator<'a> {
+    /// The maximum number of analyzed blocks, after which the cost is considered infinite.
+    pub const MAX_STEPS: usize = 100_000;
+
+    /// The maximum length of a path, after which the cost is considered infinite.
+    pub const MAX_PATH_LENGTH: usize = 1024;
+
+    /// The cost of storing a byte of the runtime code.
+    pub const CODE_DEPOSIT_COST_PER_BYTE: u64 = 200;
+
+    ///
+    /// Estimates the deploy code and external function costs of a contract.
+    ///
+    /// The external functions are taken from the `method_identifiers`, which map the function
+    /// signatures to their hexadecimal selectors.
+    ///
+    pub fn estimate(
+        deploy_bytecode: &[u8],
+        runtime_bytecode: &[u8],
+        method_identifiers: &BTreeMap<String, String>,
+    ) -> solx_solc::StandardJsonOutputContractEVMGasEstimates {
+        let code_deposit_cost = runtime_bytecode.len() as u64 * Self::CODE_DEPOSIT_COST_PER_BYTE;
+        let execution_cost = Self::new(deploy_bytecode, None).run();
+        let creation = solx_solc::StandardJsonOutputContractEVMGasEstimatesCreation::new(
+            code_deposit_cost,
+            execution_cost,
+        );
+
+        let external = method_identifiers
+            .iter()
+            .map(|(signature, selector)| {
+                let cost =
+                    u32::from_str_radix(selector.as_str(), era_compiler_common::BASE_HEXADECIMAL)
+                        .ok()
+                        .and_then(|selector| Self::new(runtime_bytecode, Some(selector)).run());
+                (
+                    signature.to_owned(),
+                    solx_solc::StandardJsonOutputContractEVMGasEstimates::cost_to_string(cost),
+                )
+            })
+            .collect();
+
+        solx_solc::StandardJsonOutputContractEVMGasEstimates::new(creation, external)
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    fn new(bytecode: &'a [u8], selector: Option<u32>) -> Self {
+        let mut jump_destinations = BTreeSet::new();
+        let mut offset = 0;
+        while let Some(opcode) = bytecode.get(offset).copied() {
+            if opcode == Opcode::JUMPDEST {
+                jump_destinations.insert(offset);
+            }
+            offset += Opcode::size(opcode);
+        }
+
+        Self {
+            bytecode,
+            jump_destinations,
+            selector,
+            costs: HashMap::new(),
+            path: Vec::new(),
+            steps: 0,
+        }
+    }
+
+    ///
+    /// Returns the cost of the most expensive path from the entry point.
+    ///
+    fn run(mut self) -> Option<u64> {
+        self.block(0, Vec::new())
+    }
+
+    ///
+    /// Returns the cost of the most expensive path from the block at `offset`.
+    ///
+    fn block(&mut self, offset: usize, stack: Vec<Value>) -> Option<u64> {
+        if self.path.contains(&offset) || self.path.len() >= Self::MAX_PATH_LENGTH {
+            return None;
+        }
+        self.steps += 1;
+        if self.steps > Self::MAX_STEPS {
+            return None;
+        }
+
+        let key = (offset, stack);
+        if let Some(cost) = self.costs.get(&key) {
+            return Some(*cost);
+        }
+
+        self.path.push(offset);
+        let cost = self.block_inner(offset, key.1.clone());
+        self.path.pop();
+
+        // Infinite costs may be caused by a cycle through the current path, so they are not
+        // valid when the block is reached via another path and must not be memoized.
+        if let Some(cost) = cost {
+            self.costs.insert(key, cost);
+        }
+        cost
+    }
+
+    ///
+    /// Interprets the instructions of the block at `offset` until the control flow diverges.
+    ///
+    fn block_inner(&mut self, mut offset: usize, mut stack: Vec<Value>) -> Option<u64> {
+        let mut cost = 0u64;
+        loop {
+            let byte = match self.bytecode.get(offset).copied() {
+                Some(byte) => byte,
+                None => return Some(cost),
+            };
+            let opcode = match Opcode::get(byte) {
+                Some(opcode) if !Opcode::is_halting(byte) => opcode,
+                Some(opcode) => return Some(cost + opcode.gas),
+                None => return Some(cost),
+            };
+            if stack.len() < opcode.inputs {
+                return Some(cost);
+            }
+            cost += opcode.gas;
+
+            match byte {
+                Opcode::JUMP => {
+                    let destination = stack.pop().expect("Always exists")?;
+                    return match self.jump_destination(&destination) {
+                        Some(destination) => self
+                            .block(destination, stack)
+                            .map(|destination_cost| cost + destination_cost),
+                        None => Some(cost),
+                    };
+                }
+                Opcode::JUMPI => {
+                    let destination = stack.pop().expect("Always exists");
+                    let condition = stack.pop().expect("Always exists");
+                    let is_taken = condition.map(|condition| !condition.is_zero());
+
+                    let jump_cost = match (is_taken, destination) {
+                        (Some(false), _) => 0,
+                        (_, Some(destination)) => match self.jump_destination(&destination) {
+                            Some(destination) => self.block(destination, stack.clone())?,
+                            None => 0,
+                        },
+                        (_, None) => return None,
+                    };
+                    if is_taken == Some(true) {
+                        return Some(cost + jump_cost);
+                    }
+
+                    let fallthrough_offset = offset + Opcode::size(byte);
+                    let fallthrough_cost = self.block(fallthrough_offset, stack)?;
+                    return Some(cost + jump_cost.max(fallthrough_cost));
+                }
+                Opcode::PUSH0..=Opcode::PUSH32 => {
+                    let size = Opcode::size(byte) - 1;
+                    let mut data = vec![0u8; size];
+                    let available = self
+                        .bytecode
+                        .get(offset + 1..)
+                        .unwrap_or_default()
+                        .iter()
+                        .take(size)
+                        .copied()
+                        .collect::<Vec<u8>>();
+                    data[..available.len()].copy_from_slice(available.as_slice());
+                    stack.push(Some(num::BigUint::from_bytes_be(data.as_slice())));
+                }
+                Opcode::DUP1..=Opcode::DUP16 => {
+                    let value = stack[stack.len() - opcode.inputs].clone();
+                    stack.push(value);
+                }
+                Opcode::SWAP1..=Opcode::SWAP16 => {
+                    let top = stack.len() - 1;
+                    stack.swap(top, top + 1 - opcode.inputs);
+                }
+                _ => {
+                    let inputs = stack.split_off(stack.len() - opcode.inputs);
+                    let output = self.evaluate(byte, inputs.into_iter().rev().collect());
+                    if opcode.outputs > 0 {
+                        stack.push(output);
+                    }
+                }
+            }
+
+            offset += Opcode::size(byte);
+        }
+    }
+
+    ///
+    /// Evaluates the instruction, if its output is known at compile time.
+    ///
+    /// The `inputs` are ordered from the stack top.
+    ///
+    fn evaluate(&self, opcode: u8, inputs: Vec<Value>) -> Value {
+        let modulus = num::BigUint::from(1u8) << era_compiler_common::BIT_LENGTH_FIELD;
+
+        if opcode == Opcode::CALLDATALOAD {
+            let selector = self.selector?;
+            let offset = inputs[0].as_ref()?;
+            return if offset.is_zero() {
+                Some(
+                    num::BigUint::from(selector)
+                        << (era_compiler_common::BIT_LENGTH_FIELD
+                            - era_compiler_common::BIT_LENGTH_X32),
+                )
+            } else {
+                None
+            };
+        }
+
+        let inputs = inputs.into_iter().collect::<Option<Vec<num::BigUint>>>()?;
+        let boolean = |value: bool| Some(num::BigUint::from(value as u8));
+        match opcode {
+            Opcode::ADD => Some((&inputs[0] + &inputs[1]) % modulus),
+            Opcode::MUL => Some((&inputs[0] * &inputs[1]) % modulus),
+            Opcode::SUB => Some((&inputs[0] + &modulus - &inputs[1]) % modulus),
+            Opcode::LT => boolean(inputs[0] < inputs[1]),
+            Opcode::GT => boolean(inputs[0] > inputs[1]),
+            Opcode::EQ => boolean(inputs[0] == inputs[1]),
+            Opcode::ISZERO => boolean(inputs[0].is_zero()),
+            Opcode::AND => Some(&inputs[0] & &inputs[1]),
+            Opcode::OR => Some(&inputs[0] | &inputs[1]),
+            Opcode::XOR => Some(&inputs[0] ^ &inputs[1]),
+            Opcode::SHL | Opcode::SHR => {
+                let shift = inputs[0]
+                    .to_usize()
+                    .filter(|shift| *shift < era_compiler_common::BIT_LENGTH_FIELD);
+                match shift {
+                    Some(shift) if opcode == Opcode::SHL => Some((&inputs[1] << shift) % modulus),
+                    Some(shift) => Some(&inputs[1] >> shift),
+                    None => Some(num::BigUint::from(0u8)),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the jump destination offset, or `None` if the destination is invalid.
+    ///
+    fn jump_destination(&self, destination: &num::BigUint) -> Option<usize> {
+        destination
+            .to_usize()
+            .filter(|destination| self.jump_destinations.contains(destination))
+    }
+}
@@ -10,28 +10,57 @@
 #![allow(clippy::result_large_err)]
 
 pub mod build_evm;
+pub mod cache;
 pub mod r#const;
+pub mod diagnostics;
+pub mod disassembly;
+pub mod error;
 pub mod evmla;
+pub mod gas_estimator;
 pub mod linker;
+pub mod lsp;
+pub mod opcode;
 pub mod process;
 pub mod project;
+pub mod settings;
+pub mod source_map;
+pub mod statistics;
+pub mod watcher;
 pub mod yul;
 
 pub use self::build_evm::contract::Contract as EVMContractBuild;
 pub use self::build_evm::Build as EVMBuild;
+pub use self::cache::Cache;
+pub use self::diagnostics::format::Format as DiagnosticsFormat;
+pub use self::diagnostics::Diagnostics;
+pub use self::disassembly::Disassembly;
+pub use self::error::located::Located as LocatedError;
+pub use self::gas_estimator::GasEstimator;
 pub use self::linker::input::Input as LinkerInput;
 pub use self::linker::output::Output as LinkerOutput;
 pub use self::linker::Linker;
+pub use self::lsp::Server as LspServer;
 pub use self::process::input_evm::Input as EVMProcessInput;
+pub use self::process::limits::Limits as ProcessLimits;
 pub use self::process::output_evm::Output as EVMProcessOutput;
 pub use self::process::run as run_recursive;
 pub use self::process::EXECUTABLE;
 pub use self::project::contract::Contract as ProjectContract;
 pub use self::project::Project;
 pub use self::r#const::*;
-
+pub use self::settings::Settings as CompilationSettings;
+pub use self::source_map::SourceMap;
+pub use self::statistics::contract::Contract as ContractStatistics;
+pub use self::statistics::Statistics;
+pub use self::watcher::settings::Settings as WatchSettings;
+pub use self::watcher::Watcher;
+
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
+use std::time::Instant;
 
 use solx_solc::CollectableError;
 
@@ -49,28 +78,59 @@ pub fn yul_to_evm(
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    mut settings: CompilationSettings,
 ) -> anyhow::Result<EVMBuild> {
     let libraries = solx_solc::StandardJsonInputLibraries::try_from(libraries)?;
     let linker_symbols = libraries.as_linker_symbols()?;
 
+    if let Some(collected) = settings.diagnostics.as_deref_mut() {
+        collected.read_sources(paths, &[Path::new("")]);
+    }
+
     let solc_compiler = solx_solc::Compiler::default();
     solc_compiler.validate_yul_paths(paths, libraries.clone(), messages)?;
 
+    let parsing_start = Instant::now();
     let project = Project::try_from_yul_paths(paths, libraries, None, debug_config.as_ref())?;
+    let parsing_time = Statistics::elapsed_time(parsing_start);
 
+    let cache = settings.cache_dir.map(Cache::try_new).transpose()?;
     let mut build = project.compile_to_evm(
         messages,
+        None,
         metadata_hash_type,
         optimizer_settings,
         llvm_options,
         debug_config,
+        cache.as_ref(),
+        settings.in_process,
+        settings.process_limits,
     )?;
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.warning_settings = settings.warning_settings;
+    build.statistics.parsing_time = Some(parsing_time);
+    if let Some(cache) = cache.as_ref() {
+        build.statistics.set_cache(cache);
+    }
+    // The Yul locations refer to the Solidity files the Yul code has been generated from.
+    let sources = build
+        .results
+        .values()
+        .filter_map(|result| result.as_ref().err())
+        .filter_map(|error| error.source_location.as_ref())
+        .map(|source_location| source_location.file.as_str())
+        .collect::<BTreeSet<&str>>()
+        .into_iter()
+        .filter_map(|path| {
+            let source = solx_solc::StandardJsonInputSource::try_read(Path::new(path)).ok()?;
+            Some((path.to_owned(), source))
+        })
+        .collect();
+    build.render_errors_with_sources(&sources);
+    build.take_and_write_messages(settings.diagnostics.as_deref_mut())?;
 
     let mut build = build.link(linker_symbols);
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.check_bytecode_sizes(settings.deny_size_limits);
+    build.take_and_write_messages(settings.diagnostics.as_deref_mut())?;
     Ok(build)
 }
 
@@ -85,25 +145,41 @@ pub fn llvm_ir_to_evm(
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    mut settings: CompilationSettings,
 ) -> anyhow::Result<EVMBuild> {
     let libraries = solx_solc::StandardJsonInputLibraries::try_from(libraries)?;
     let linker_symbols = libraries.as_linker_symbols()?;
 
+    if let Some(collected) = settings.diagnostics.as_deref_mut() {
+        collected.read_sources(paths, &[Path::new("")]);
+    }
+
+    let parsing_start = Instant::now();
     let project = Project::try_from_llvm_ir_paths(paths, libraries, None)?;
+    let parsing_time = Statistics::elapsed_time(parsing_start);
 
+    let cache = settings.cache_dir.map(Cache::try_new).transpose()?;
     let mut build = project.compile_to_evm(
         messages,
+        None,
         metadata_hash_type,
         optimizer_settings,
         llvm_options,
         debug_config,
+        cache.as_ref(),
+        settings.in_process,
+        settings.process_limits,
     )?;
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.warning_settings = settings.warning_settings;
+    build.statistics.parsing_time = Some(parsing_time);
+    if let Some(cache) = cache.as_ref() {
+        build.statistics.set_cache(cache);
+    }
+    build.take_and_write_messages(settings.diagnostics.as_deref_mut())?;
 
     let mut build = build.link(linker_symbols);
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.check_bytecode_sizes(settings.deny_size_limits);
+    build.take_and_write_messages(settings.diagnostics.as_deref_mut())?;
     Ok(build)
 }
 
@@ -116,15 +192,18 @@ pub fn standard_output_evm(
     messages: &mut Vec<solx_solc::StandardJsonOutputError>,
     evm_version: Option<era_compiler_common::EVMVersion>,
     via_ir: bool,
+    output_selection: solx_solc::StandardJsonInputSelection,
     metadata_hash_type: era_compiler_common::HashType,
     use_literal_content: bool,
     base_path: Option<String>,
     include_paths: Vec<String>,
     allow_paths: Option<String>,
     remappings: BTreeSet<String>,
+    disabled_lints: BTreeSet<solx_solc::StandardJsonOutputSourceLint>,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    mut settings: CompilationSettings,
 ) -> anyhow::Result<EVMBuild> {
     let mut solc_input = solx_solc::StandardJsonInput::try_from_solidity_paths(
         paths,
@@ -133,51 +212,287 @@ pub fn standard_output_evm(
         solx_solc::StandardJsonInputOptimizer::default(),
         evm_version,
         via_ir,
-        solx_solc::StandardJsonInputSelection::new(via_ir),
+        output_selection,
         solx_solc::StandardJsonInputMetadata::new(use_literal_content, metadata_hash_type),
         llvm_options.clone(),
     )?;
+    solc_input.settings.solx_disabled_lints = disabled_lints;
+    solc_input.settings.solx_warnings = settings.warning_settings.clone();
 
+    let solc_start = Instant::now();
     let mut solc_output = solx_solc::Compiler::default().standard_json(
         &mut solc_input,
         messages,
-        base_path,
-        include_paths,
+        base_path.clone(),
+        include_paths.clone(),
         allow_paths,
     )?;
-    solc_output.take_and_write_warnings();
-    solc_output.check_errors()?;
+    let solc_time = Statistics::elapsed_time(solc_start);
+    for error in solc_output.errors.iter_mut() {
+        error.render_with_sources(&solc_input.sources);
+    }
+    if let Some(collected) = settings.diagnostics.as_deref_mut() {
+        collected.add_sources(&solc_input.sources);
+        let mut directories = vec![Path::new(base_path.as_deref().unwrap_or_default())];
+        directories.extend(include_paths.iter().map(Path::new));
+        collected.read_sources(solc_output.sources.keys(), directories.as_slice());
+    }
+    solc_output.take_and_write_messages(settings.diagnostics.as_deref_mut())?;
 
     let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
 
+    let parsing_start = Instant::now();
     let project = Project::try_from_solc_output(
         solc_input.settings.libraries,
         via_ir,
         &mut solc_output,
         debug_config.as_ref(),
     )?;
-    solc_output.take_and_write_warnings();
-    solc_output.check_errors()?;
+    let parsing_time = Statistics::elapsed_time(parsing_start);
+    solc_output.take_and_write_messages(settings.diagnostics.as_deref_mut())?;
 
+    let cache = settings.cache_dir.map(Cache::try_new).transpose()?;
     let mut build = project.compile_to_evm(
         messages,
+        evm_version,
         metadata_hash_type,
         optimizer_settings,
         llvm_options,
         debug_config,
+        cache.as_ref(),
+        settings.in_process,
+        settings.process_limits,
     )?;
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.warning_settings = settings.warning_settings;
+    build.statistics.solc_time = Some(solc_time);
+    build.statistics.parsing_time = Some(parsing_time);
+    if let Some(cache) = cache.as_ref() {
+        build.statistics.set_cache(cache);
+    }
+    build.render_errors_with_sources(&solc_input.sources);
+    build.take_and_write_messages(settings.diagnostics.as_deref_mut())?;
 
     let mut build = build.link(linker_symbols);
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.check_bytecode_sizes(settings.deny_size_limits);
+    build.take_and_write_messages(settings.diagnostics.as_deref_mut())?;
+    build.take_solc_data(&mut solc_output);
     Ok(build)
 }
 
+///
+/// Runs the watch mode for the EVM target.
+///
+/// The project is recompiled every time one of its source files changes. Only the contracts
+/// defined in the changed files or in the files importing them are compiled by the backend,
+/// whereas the builds of the other contracts are reused. If a recompilation fails, its changed
+/// files are kept and recompiled along with the next changes. The diagnostics of every
+/// recompilation are collected and written in the requested format, and the process runs until
+/// it is terminated.
+///
+pub fn watch_evm(
+    paths: &[PathBuf],
+    watch_settings: WatchSettings,
+    mut settings: CompilationSettings,
+) -> anyhow::Result<()> {
+    let WatchSettings {
+        libraries,
+        evm_version,
+        via_ir,
+        output_selection,
+        output_directory,
+        overwrite,
+        metadata_hash_type,
+        use_literal_content,
+        base_path,
+        include_paths,
+        allow_paths,
+        remappings,
+        disabled_lints,
+        optimizer_settings,
+        llvm_options,
+        debug_config,
+        diagnostics_format,
+    } = watch_settings;
+
+    let cache = settings.cache_dir.take().map(Cache::try_new).transpose()?;
+    let mut default_collected = solx_solc::CollectedDiagnostics::default();
+    let collected = settings
+        .diagnostics
+        .take()
+        .unwrap_or(&mut default_collected);
+    let mut watcher = Watcher::new(paths, base_path.as_deref(), include_paths.as_slice());
+    let mut builds: BTreeMap<String, EVMContractBuild> = BTreeMap::new();
+    let mut changed_files: Option<BTreeSet<String>> = None;
+
+    loop {
+        let mut compile = |collected: &mut solx_solc::CollectedDiagnostics| -> anyhow::Result<()> {
+            let mut solc_output_selection = output_selection.clone();
+            solc_output_selection.extend_with_ast();
+            let mut solc_input = solx_solc::StandardJsonInput::try_from_solidity_paths(
+                paths,
+                libraries.as_slice(),
+                remappings.clone(),
+                solx_solc::StandardJsonInputOptimizer::default(),
+                evm_version,
+                via_ir,
+                solc_output_selection,
+                solx_solc::StandardJsonInputMetadata::new(use_literal_content, metadata_hash_type),
+                llvm_options.clone(),
+            )?;
+            solc_input.settings.solx_disabled_lints = disabled_lints.clone();
+            solc_input.settings.solx_warnings = settings.warning_settings.clone();
+
+            let mut messages = Vec::new();
+            let mut solc_output = solx_solc::Compiler::default().standard_json(
+                &mut solc_input,
+                &mut messages,
+                base_path.clone(),
+                include_paths.clone(),
+                allow_paths.clone(),
+            )?;
+            watcher.update(&solc_output);
+            for error in solc_output.errors.iter_mut() {
+                error.render_with_sources(&solc_input.sources);
+            }
+            collected.add_sources(&solc_input.sources);
+            let mut directories = vec![Path::new(base_path.as_deref().unwrap_or_default())];
+            directories.extend(include_paths.iter().map(Path::new));
+            collected.read_sources(solc_output.sources.keys(), directories.as_slice());
+            solc_output.take_and_write_messages(Some(&mut *collected))?;
+
+            let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
+
+            let mut project = Project::try_from_solc_output(
+                solc_input.settings.libraries,
+                via_ir,
+                &mut solc_output,
+                debug_config.as_ref(),
+            )?;
+            solc_output.take_and_write_messages(Some(&mut *collected))?;
+
+            builds.retain(|path, _| project.contracts.contains_key(path.as_str()));
+            if let Some(changed_files) = changed_files.as_ref() {
+                let affected_files = watcher.affected(changed_files);
+                project.contracts.retain(|path, contract| {
+                    affected_files.contains(contract.name.path.as_str())
+                        || !builds.contains_key(path.as_str())
+                });
+            }
+            writeln!(
+                std::io::stderr(),
+                "Compiling {} contract(s)...",
+                project.contracts.len()
+            )?;
+
+            let mut build = project.compile_to_evm(
+                &mut messages,
+                evm_version,
+                metadata_hash_type,
+                optimizer_settings.clone(),
+                llvm_options.clone(),
+                debug_config.clone(),
+                cache.as_ref(),
+                settings.in_process,
+                settings.process_limits,
+            )?;
+            build.warning_settings = settings.warning_settings.clone();
+            build.render_errors_with_sources(&solc_input.sources);
+            build.take_and_write_messages(Some(&mut *collected))?;
+            for (path, result) in build.results.into_iter() {
+                builds.insert(path, result.expect("Always valid"));
+            }
+
+            let mut build = EVMBuild::new(
+                builds
+                    .iter()
+                    .map(|(path, build)| (path.to_owned(), Ok(build.to_owned())))
+                    .collect(),
+                &mut messages,
+            );
+            build.warning_settings = settings.warning_settings.clone();
+            let mut build = build.link(linker_symbols);
+            build.check_bytecode_sizes(settings.deny_size_limits);
+            build.take_and_write_messages(Some(&mut *collected))?;
+            build.take_solc_data(&mut solc_output);
+
+            match output_directory.as_ref() {
+                Some(output_directory) => {
+                    build.write_to_directory(output_directory, &output_selection, overwrite, None)
+                }
+                None => build.write_to_terminal(&output_selection, None),
+            }
+        };
+        match compile(collected) {
+            Ok(()) => changed_files = Some(BTreeSet::new()),
+            Err(error) if error.is::<solx_solc::CollectedErrors>() => {}
+            Err(error) => collected
+                .messages
+                .push(solx_solc::StandardJsonOutputError::new_error(
+                    None, error, None, None,
+                )),
+        }
+        settings.warning_settings.apply(&mut collected.messages);
+        Diagnostics::write_to_terminal(
+            std::mem::take(&mut collected.messages),
+            &collected.sources,
+            diagnostics_format,
+        )?;
+        collected.sources.clear();
+
+        writeln!(
+            std::io::stderr(),
+            "Watching {} file(s) for changes...",
+            watcher.len()
+        )?;
+        let new_changed_files = watcher.wait_for_changes();
+        if let Some(changed_files) = changed_files.as_mut() {
+            changed_files.extend(new_changed_files);
+        }
+    }
+}
+
+///
+/// Runs the linker mode for the EVM target.
+///
+/// The linked bytecode files are modified in place, including the partially linked ones.
+///
+pub fn link_evm(paths: &[PathBuf], libraries: Vec<String>) -> anyhow::Result<LinkerOutput> {
+    let input = LinkerInput::try_from_paths(paths, libraries)?;
+    let output = Linker::link(input)?;
+
+    for (path, linked) in output.linked.iter() {
+        std::fs::write(path.as_str(), linked.bytecode.as_bytes())
+            .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))?;
+    }
+    for (path, bytecode) in output
+        .unlinked
+        .iter()
+        .filter_map(|(path, unlinked)| Some((path, unlinked.bytecode.as_ref()?)))
+    {
+        std::fs::write(path.as_str(), bytecode.as_bytes())
+            .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))?;
+    }
+    Ok(output)
+}
+
+///
+/// Runs the linker in standard JSON mode for the EVM target.
+///
+pub fn standard_json_link_evm(json_path: Option<PathBuf>) -> anyhow::Result<()> {
+    let input = LinkerInput::try_from_standard_json(json_path.as_deref())?;
+    let output = Linker::link(input)?;
+
+    serde_json::to_writer(std::io::stdout(), &output)
+        .map_err(|error| anyhow::anyhow!("Stdout writing error: {error}"))?;
+    Ok(())
+}
+
 ///
 /// Runs the standard JSON mode for the EVM target.
 ///
+/// The cache directory and warning settings are taken from the standard JSON input,
+/// and the diagnostics are written to the standard JSON output.
+///
 pub fn standard_json_evm(
     via_ir: bool,
     json_path: Option<PathBuf>,
@@ -186,12 +501,45 @@ pub fn standard_json_evm(
     include_paths: Vec<String>,
     allow_paths: Option<String>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    settings: CompilationSettings,
 ) -> anyhow::Result<()> {
+    let mut solc_input = solx_solc::StandardJsonInput::try_from(json_path.as_deref())?;
+    let prune_output = solc_input.settings.output_selection.to_prune(via_ir);
+
+    let solc_output = standard_json_input_to_evm(
+        via_ir,
+        &mut solc_input,
+        messages,
+        base_path,
+        include_paths,
+        allow_paths,
+        debug_config,
+        settings,
+    )?;
+    solc_output.write_and_exit(prune_output);
+}
+
+///
+/// Compiles the standard JSON input for the EVM target, returning the standard JSON output.
+///
+/// The compilation stops at the first stage that reports errors, and the output contains
+/// the messages of all stages that have been run. The cache directory and warning settings
+/// are taken from the standard JSON input. The output is neither pruned nor filtered by
+/// the warning settings, which is done when it is written.
+///
+pub fn standard_json_input_to_evm(
+    via_ir: bool,
+    solc_input: &mut solx_solc::StandardJsonInput,
+    messages: &mut Vec<solx_solc::StandardJsonOutputError>,
+    base_path: Option<String>,
+    include_paths: Vec<String>,
+    allow_paths: Option<String>,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    mut settings: CompilationSettings,
+) -> anyhow::Result<solx_solc::StandardJsonOutput> {
     let solc_compiler = solx_solc::Compiler::default();
 
-    let mut solc_input = solx_solc::StandardJsonInput::try_from(json_path.as_deref())?;
     let language = solc_input.language;
-    let prune_output = solc_input.settings.output_selection.to_prune(via_ir);
     let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
 
     let mut optimizer_settings = era_compiler_llvm_context::OptimizerSettings::try_from_cli(
@@ -203,80 +551,114 @@ pub fn standard_json_evm(
     let llvm_options = solc_input.settings.llvm_options.clone();
 
     let metadata_hash_type = solc_input.settings.metadata.bytecode_hash;
+    let evm_version = solc_input.settings.evm_version;
+    let is_statistics_requested = solc_input.settings.solx_statistics;
+    settings.cache_dir = solc_input.settings.cache_dir.take();
+    settings.warning_settings = solc_input.settings.solx_warnings.clone();
 
+    let mut statistics = Statistics::default();
     let (mut solc_output, project) = match language {
         solx_solc::StandardJsonInputLanguage::Solidity => {
+            let solc_start = Instant::now();
             let mut solc_output = solc_compiler.standard_json(
-                &mut solc_input,
+                solc_input,
                 messages,
                 base_path,
                 include_paths,
                 allow_paths,
             )?;
+            Statistics::add_time(&mut statistics.solc_time, solc_start);
             if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
+                return Ok(solc_output);
             }
 
+            let parsing_start = Instant::now();
             let project = Project::try_from_solc_output(
-                solc_input.settings.libraries,
+                std::mem::take(&mut solc_input.settings.libraries),
                 via_ir,
                 &mut solc_output,
                 debug_config.as_ref(),
             )?;
+            Statistics::add_time(&mut statistics.parsing_time, parsing_start);
             if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
+                return Ok(solc_output);
             }
 
             (solc_output, project)
         }
         solx_solc::StandardJsonInputLanguage::Yul => {
-            let mut solc_output =
-                solc_compiler.validate_yul_standard_json(&mut solc_input, messages)?;
+            let solc_start = Instant::now();
+            let mut solc_output = solc_compiler.validate_yul_standard_json(solc_input, messages)?;
+            Statistics::add_time(&mut statistics.solc_time, solc_start);
             if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
+                return Ok(solc_output);
             }
 
+            let parsing_start = Instant::now();
             let project = Project::try_from_yul_sources(
-                solc_input.sources,
-                solc_input.settings.libraries,
+                std::mem::take(&mut solc_input.sources),
+                std::mem::take(&mut solc_input.settings.libraries),
                 Some(&mut solc_output),
                 debug_config.as_ref(),
             )?;
+            Statistics::add_time(&mut statistics.parsing_time, parsing_start);
             if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
+                return Ok(solc_output);
             }
 
             (solc_output, project)
         }
         solx_solc::StandardJsonInputLanguage::LLVMIR => {
             let mut solc_output = solx_solc::StandardJsonOutput::new(&solc_input.sources, messages);
+            solc_output.warning_settings = settings.warning_settings.clone();
 
+            let parsing_start = Instant::now();
             let project = Project::try_from_llvm_ir_sources(
-                solc_input.sources,
-                solc_input.settings.libraries,
+                std::mem::take(&mut solc_input.sources),
+                std::mem::take(&mut solc_input.settings.libraries),
                 Some(&mut solc_output),
             )?;
+            Statistics::add_time(&mut statistics.parsing_time, parsing_start);
             if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
+                return Ok(solc_output);
             }
 
             (solc_output, project)
         }
     };
 
-    let build = project.compile_to_evm(
+    let cache = settings.cache_dir.map(Cache::try_new).transpose()?;
+    let mut build = project.compile_to_evm(
         messages,
+        evm_version,
         metadata_hash_type,
         optimizer_settings,
         llvm_options,
         debug_config,
+        cache.as_ref(),
+        settings.in_process,
+        settings.process_limits,
     )?;
+    build.warning_settings = settings.warning_settings;
+    build.statistics.solc_time = statistics.solc_time;
+    build.statistics.parsing_time = statistics.parsing_time;
+    if let Some(cache) = cache.as_ref() {
+        build.statistics.set_cache(cache);
+    }
+    build.render_errors_with_sources(&solc_input.sources);
     if build.has_errors() {
+        if is_statistics_requested {
+            solc_output.statistics = Some(serde_json::to_value(build.statistics())?);
+        }
         build.write_to_standard_json(&mut solc_output)?;
-        solc_output.write_and_exit(prune_output);
+        return Ok(solc_output);
     }
 
-    let build = build.link(linker_symbols);
+    let mut build = build.link(linker_symbols);
+    build.check_bytecode_sizes(settings.deny_size_limits);
+    if is_statistics_requested {
+        solc_output.statistics = Some(serde_json::to_value(build.statistics())?);
+    }
     build.write_to_standard_json(&mut solc_output)?;
-    solc_output.write_and_exit(prune_output);
+    Ok(solc_output)
 }
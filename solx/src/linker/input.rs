@@ -3,6 +3,8 @@
 //!
 
 use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
 
 ///
 /// The linker input.
@@ -25,4 +27,35 @@ impl Input {
             libraries,
         }
     }
+
+    ///
+    /// Reads the bytecode files from the file system.
+    ///
+    pub fn try_from_paths(paths: &[PathBuf], libraries: Vec<String>) -> anyhow::Result<Self> {
+        let bytecodes = paths
+            .iter()
+            .map(|path| {
+                let bytecode = std::fs::read_to_string(path)
+                    .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+                Ok((path.to_string_lossy().to_string(), bytecode))
+            })
+            .collect::<anyhow::Result<BTreeMap<String, String>>>()?;
+        Ok(Self::new(bytecodes, libraries))
+    }
+
+    ///
+    /// Reads the linker standard JSON input.
+    ///
+    /// If the `path` is `None`, the input is read from the stdin.
+    ///
+    pub fn try_from_standard_json(path: Option<&Path>) -> anyhow::Result<Self> {
+        let input_json = match path {
+            Some(path) => std::fs::read_to_string(path)
+                .map_err(|error| anyhow::anyhow!("Standard JSON file {path:?} reading: {error}")),
+            None => std::io::read_to_string(std::io::stdin())
+                .map_err(|error| anyhow::anyhow!("Standard JSON reading from stdin: {error}")),
+        }?;
+        era_compiler_common::deserialize_from_str::<Self>(input_json.as_str())
+            .map_err(|error| anyhow::anyhow!("Standard JSON parsing: {error}"))
+    }
 }
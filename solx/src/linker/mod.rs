@@ -6,6 +6,7 @@ pub mod input;
 pub mod output;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 
 use self::input::Input;
 use self::output::ignored::Ignored as OutputIgnored;
@@ -20,105 +21,159 @@ use self::output::Output;
 pub struct Linker {}
 
 impl Linker {
+    /// The prefix of the `solc`-compatible library address placeholder.
+    pub const PLACEHOLDER_PREFIX: &'static str = "__$";
+
+    /// The suffix of the `solc`-compatible library address placeholder.
+    pub const PLACEHOLDER_SUFFIX: &'static str = "$__";
+
     ///
-    /// Links EraVM bytecode files.
+    /// Links EVM bytecode files.
     ///
-    /// TODO: rewrite for EVM.
+    /// EVM objects are assembled with their dependencies at compile time, so only library
+    /// references are resolved here. The references are either ELF relocations, or
+    /// `solc`-compatible placeholders found in the standard JSON output. Objects with neither
+    /// are considered already linked and are reported as ignored.
     ///
-    pub fn link_eravm(input: Input) -> anyhow::Result<Output> {
+    pub fn link(input: Input) -> anyhow::Result<Output> {
         let linker_symbols =
             solx_solc::StandardJsonInputLibraries::try_from(input.libraries.as_slice())?
                 .as_linker_symbols()?;
         let mut output = Output::default();
-        let mut unlinked_objects = Vec::new();
-        let mut factory_dependencies = BTreeMap::new();
-
-        let bytecode_binary = input
-            .bytecodes
-            .iter()
-            .map(|(path, string)| {
-                let string_stripped = string.strip_prefix("0x").unwrap_or(string.as_str());
-                let bytecode = hex::decode(string_stripped).map_err(|error| {
-                    anyhow::anyhow!("Object `{path}` hexadecimal string decoding: {error}")
-                })?;
-                Ok((path.to_owned(), bytecode))
-            })
-            .collect::<anyhow::Result<BTreeMap<String, Vec<u8>>>>()?;
 
         for (path, bytecode_string) in input.bytecodes.into_iter() {
-            let bytecode = bytecode_binary.get(path.as_str()).expect("Always exists");
+            let bytecode_string = bytecode_string.trim();
+            let bytecode_string_stripped = bytecode_string
+                .strip_prefix("0x")
+                .unwrap_or(bytecode_string);
+            if bytecode_string_stripped.contains(Self::PLACEHOLDER_PREFIX) {
+                Self::link_placeholders(
+                    &mut output,
+                    path,
+                    bytecode_string_stripped,
+                    &linker_symbols,
+                )?;
+                continue;
+            }
+
+            let bytecode = hex::decode(bytecode_string_stripped).map_err(|error| {
+                anyhow::anyhow!("Object `{path}` hexadecimal string decoding: {error}")
+            })?;
+
             let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
                 bytecode.as_slice(),
                 path.as_str(),
                 false,
             );
-
-            if memory_buffer.is_elf_eravm() {
-                unlinked_objects.push((path, memory_buffer));
+            if !memory_buffer.is_elf_evm() {
+                let hash = era_compiler_common::Hash::keccak256(bytecode.as_slice());
+                output.ignored.insert(
+                    path,
+                    OutputIgnored::new(bytecode_string_stripped.to_owned(), hash.to_string()),
+                );
                 continue;
             }
 
-            let hash = era_compiler_llvm_context::eravm_hash(&memory_buffer)
-                .map_err(|error| anyhow::anyhow!("Object `{path}` bytecode hashing: {error}"))?;
-            output.ignored.insert(
-                path.clone(),
-                OutputIgnored::new(bytecode_string, hex::encode(hash)),
-            );
-            factory_dependencies.insert(path, hash);
-        }
-
-        loop {
-            let mut linked_counter = 0;
-            let mut remaining_objects = Vec::new();
-            for (path, bytecode_buffer) in unlinked_objects.drain(..) {
-                let (unlinked_linker_symbols, unlinked_factory_dependencies) =
-                    bytecode_buffer.get_undefined_references_eravm();
-                let (bytecode_buffer_after_linking, object_format) =
-                    era_compiler_llvm_context::eravm_link(
-                        bytecode_buffer,
-                        &linker_symbols,
-                        &factory_dependencies,
-                    )?;
-                match object_format {
-                    era_compiler_common::ObjectFormat::ELF => {
-                        remaining_objects.push((path, bytecode_buffer_after_linking));
-                    }
-                    era_compiler_common::ObjectFormat::Raw => {
-                        let bytecode = hex::encode(bytecode_buffer_after_linking.as_slice());
-                        let hash =
-                            era_compiler_llvm_context::eravm_hash(&bytecode_buffer_after_linking)
-                                .expect("Always valid");
-
-                        output.linked.insert(
-                            path.clone(),
-                            OutputLinked::new(
-                                bytecode,
-                                hex::encode(hash.as_slice()),
-                                unlinked_linker_symbols,
-                                unlinked_factory_dependencies,
-                            ),
-                        );
-
-                        factory_dependencies.insert(path, hash);
-                        linked_counter += 1;
-                    }
+            let unlinked_linker_symbols = memory_buffer.get_undefined_references_evm();
+            let (memory_buffer_linked, object_format) =
+                era_compiler_llvm_context::evm_link(memory_buffer, &linker_symbols)
+                    .map_err(|error| anyhow::anyhow!("Object `{path}` linking: {error}"))?;
+            match object_format {
+                era_compiler_common::ObjectFormat::ELF => {
+                    let unlinked = OutputUnlinked::new(
+                        memory_buffer_linked.get_undefined_references_evm(),
+                        vec![],
+                    );
+                    output.unlinked.insert(path, unlinked);
+                }
+                era_compiler_common::ObjectFormat::Raw => {
+                    let bytecode = hex::encode(memory_buffer_linked.as_slice());
+                    let hash =
+                        era_compiler_common::Hash::keccak256(memory_buffer_linked.as_slice());
+                    output.linked.insert(
+                        path,
+                        OutputLinked::new(
+                            bytecode,
+                            hash.to_string(),
+                            unlinked_linker_symbols,
+                            vec![],
+                        ),
+                    );
                 }
             }
-            unlinked_objects = remaining_objects;
-            if linked_counter == 0 {
-                break;
+        }
+
+        Ok(output)
+    }
+
+    ///
+    /// Returns the `solc`-compatible placeholder of the library address.
+    ///
+    /// The placeholder consists of the first 34 hexadecimal characters of the library full path
+    /// hash, wrapped with `__$` and `$__`, so it has the size of an address.
+    ///
+    pub fn placeholder(library: &str) -> String {
+        let hash = era_compiler_common::Hash::keccak256(library.as_bytes());
+        let hash = hex::encode(hash.as_bytes());
+        format!(
+            "{}{}{}",
+            Self::PLACEHOLDER_PREFIX,
+            &hash[..era_compiler_common::BYTE_LENGTH_ETH_ADDRESS * 2
+                - Self::PLACEHOLDER_PREFIX.len()
+                - Self::PLACEHOLDER_SUFFIX.len()],
+            Self::PLACEHOLDER_SUFFIX,
+        )
+    }
+
+    ///
+    /// Replaces the library address placeholders in the hexadecimal bytecode.
+    ///
+    /// Placeholders of the libraries that are not passed are reported as unlinked symbols.
+    /// If only some of the libraries are passed, the partially linked bytecode is reported along
+    /// with them, so it can be written back like `solc --link` does.
+    ///
+    fn link_placeholders(
+        output: &mut Output,
+        path: String,
+        bytecode_string: &str,
+        linker_symbols: &BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
+    ) -> anyhow::Result<()> {
+        let mut bytecode_string = bytecode_string.to_owned();
+        let mut linked_symbols = Vec::with_capacity(linker_symbols.len());
+        for (library, address) in linker_symbols.iter() {
+            let placeholder = Self::placeholder(library.as_str());
+            if bytecode_string.contains(placeholder.as_str()) {
+                bytecode_string =
+                    bytecode_string.replace(placeholder.as_str(), hex::encode(address).as_str());
+                linked_symbols.push(library.to_owned());
             }
         }
 
-        output.unlinked = unlinked_objects
-            .into_iter()
-            .map(|(path, bytecode_buffer)| {
-                let (library_symbols, factory_dependencies) =
-                    bytecode_buffer.get_undefined_references_eravm();
-                let unlinked = OutputUnlinked::new(library_symbols, factory_dependencies);
-                (path, unlinked)
+        let unlinked_symbols = bytecode_string
+            .match_indices(Self::PLACEHOLDER_PREFIX)
+            .filter_map(|(offset, _)| {
+                bytecode_string
+                    .get(offset..offset + era_compiler_common::BYTE_LENGTH_ETH_ADDRESS * 2)
             })
-            .collect();
-        Ok(output)
+            .map(|placeholder| placeholder.to_owned())
+            .collect::<BTreeSet<String>>();
+        if !unlinked_symbols.is_empty() {
+            let mut unlinked = OutputUnlinked::new(unlinked_symbols.into_iter().collect(), vec![]);
+            if !linked_symbols.is_empty() {
+                unlinked = unlinked.with_bytecode(bytecode_string);
+            }
+            output.unlinked.insert(path, unlinked);
+            return Ok(());
+        }
+
+        let bytecode = hex::decode(bytecode_string.as_str()).map_err(|error| {
+            anyhow::anyhow!("Object `{path}` hexadecimal string decoding: {error}")
+        })?;
+        let hash = era_compiler_common::Hash::keccak256(bytecode.as_slice());
+        output.linked.insert(
+            path,
+            OutputLinked::new(bytecode_string, hash.to_string(), linked_symbols, vec![]),
+        );
+        Ok(())
     }
 }
@@ -11,6 +11,9 @@ pub struct Unlinked {
     pub linker_symbols: Vec<String>,
     /// Unlinked factory dependencies (CREATE/CREATE2 dependencies).
     pub factory_dependencies: Vec<String>,
+    /// The partially linked bytecode, if some of the library placeholders have been replaced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytecode: Option<String>,
 }
 
 impl Unlinked {
@@ -21,6 +24,15 @@ impl Unlinked {
         Self {
             linker_symbols,
             factory_dependencies,
+            bytecode: None,
         }
     }
+
+    ///
+    /// Sets the partially linked bytecode.
+    ///
+    pub fn with_bytecode(mut self, bytecode: String) -> Self {
+        self.bytecode = Some(bytecode);
+        self
+    }
 }
@@ -0,0 +1,25 @@
+//!
+//! The LSP document location.
+//!
+
+use crate::lsp::diagnostic::range::Range;
+
+///
+/// The LSP document location.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Location {
+    /// The document URI.
+    pub uri: String,
+    /// The range in the document.
+    pub range: Range,
+}
+
+impl Location {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(uri: String, range: Range) -> Self {
+        Self { uri, range }
+    }
+}
@@ -0,0 +1,81 @@
+//!
+//! The LSP diagnostic.
+//!
+
+pub mod location;
+pub mod position;
+pub mod range;
+pub mod related_information;
+
+use self::range::Range;
+use self::related_information::RelatedInformation;
+
+///
+/// The LSP diagnostic.
+///
+#[derive(Debug, serde::Serialize)]
+pub struct Diagnostic {
+    /// The diagnostic range.
+    pub range: Range,
+    /// The diagnostic severity.
+    pub severity: usize,
+    /// The diagnostic code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The diagnostic source.
+    pub source: &'static str,
+    /// The diagnostic message.
+    pub message: String,
+    /// The related code locations.
+    #[serde(rename = "relatedInformation", skip_serializing_if = "Vec::is_empty")]
+    pub related_information: Vec<RelatedInformation>,
+}
+
+impl Diagnostic {
+    /// The diagnostic source name.
+    pub const SOURCE: &'static str = "solx";
+
+    /// The error severity.
+    pub const SEVERITY_ERROR: usize = 1;
+
+    /// The warning severity.
+    pub const SEVERITY_WARNING: usize = 2;
+
+    /// The information severity.
+    pub const SEVERITY_INFORMATION: usize = 3;
+
+    ///
+    /// Converts the compiler message into a diagnostic of the document with `source_code`.
+    ///
+    /// The messages without location are placed at the beginning of the document.
+    ///
+    pub fn new(
+        error: &solx_solc::StandardJsonOutputError,
+        source_code: Option<&str>,
+        related_information: Vec<RelatedInformation>,
+    ) -> Self {
+        let range = error
+            .source_location
+            .as_ref()
+            .zip(source_code)
+            .and_then(|(source_location, source_code)| {
+                Range::try_from_offsets(source_code, source_location.start, source_location.end)
+            })
+            .unwrap_or_default();
+
+        let severity = match error.severity.as_str() {
+            "error" => Self::SEVERITY_ERROR,
+            "warning" => Self::SEVERITY_WARNING,
+            _ => Self::SEVERITY_INFORMATION,
+        };
+
+        Self {
+            range,
+            severity,
+            code: error.error_code.clone(),
+            source: Self::SOURCE,
+            message: error.message.trim().to_owned(),
+            related_information,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//!
+//! The LSP document position.
+//!
+
+///
+/// The LSP document position, where both the line and the character are zero-based.
+///
+/// The character offset is counted in UTF-16 code units, which is the default LSP encoding.
+///
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct Position {
+    /// The line number.
+    pub line: usize,
+    /// The character offset in the line.
+    pub character: usize,
+}
+
+impl Position {
+    ///
+    /// Converts the byte `offset` in the `source_code` into a position.
+    ///
+    /// Returns `None` if the offset is out of bounds or not at a character boundary.
+    ///
+    pub fn try_from_offset(source_code: &str, offset: usize) -> Option<Self> {
+        let preceding = source_code.get(..offset)?;
+        let line_start = preceding
+            .rfind('\n')
+            .map(|index| index + 1)
+            .unwrap_or_default();
+        Some(Self {
+            line: preceding.matches('\n').count(),
+            character: preceding[line_start..].encode_utf16().count(),
+        })
+    }
+}
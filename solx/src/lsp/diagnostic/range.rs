@@ -0,0 +1,38 @@
+//!
+//! The LSP document range.
+//!
+
+use crate::lsp::diagnostic::position::Position;
+
+///
+/// The LSP document range.
+///
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct Range {
+    /// The start position.
+    pub start: Position,
+    /// The end position.
+    pub end: Position,
+}
+
+impl Range {
+    ///
+    /// Converts the byte offsets in the `source_code` into a range.
+    ///
+    /// Returns `None` if the offsets are negative, reversed, or not at character boundaries.
+    /// The end offset past the end of the source code is clamped.
+    ///
+    pub fn try_from_offsets(source_code: &str, start: isize, end: isize) -> Option<Self> {
+        if start < 0 || end < start {
+            return None;
+        }
+
+        Some(Self {
+            start: Position::try_from_offset(source_code, start as usize)?,
+            end: Position::try_from_offset(
+                source_code,
+                std::cmp::min(end as usize, source_code.len()),
+            )?,
+        })
+    }
+}
@@ -0,0 +1,27 @@
+//!
+//! The LSP diagnostic related information.
+//!
+
+use crate::lsp::diagnostic::location::Location;
+
+///
+/// The LSP diagnostic related information.
+///
+/// Points at the code related to the diagnostic, e.g. a previous declaration of an identifier.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RelatedInformation {
+    /// The location of the related code.
+    pub location: Location,
+    /// The note message.
+    pub message: String,
+}
+
+impl RelatedInformation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, message: String) -> Self {
+        Self { location, message }
+    }
+}
@@ -0,0 +1,132 @@
+//!
+//! The LSP JSON-RPC message.
+//!
+
+use std::io::BufRead;
+use std::io::Write;
+
+///
+/// The LSP JSON-RPC message.
+///
+/// Requests have both `id` and `method`, notifications have only `method`, and responses
+/// from the client have only `id`.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Message {
+    /// The request ID.
+    #[serde(default)]
+    pub id: Option<serde_json::Value>,
+    /// The request or notification method.
+    #[serde(default)]
+    pub method: Option<String>,
+    /// The request or notification parameters.
+    #[serde(default)]
+    pub params: serde_json::Value,
+}
+
+impl Message {
+    /// The content length header name.
+    pub const HEADER_CONTENT_LENGTH: &'static str = "Content-Length";
+
+    /// The JSON-RPC error code of unknown methods.
+    pub const ERROR_CODE_METHOD_NOT_FOUND: i64 = -32601;
+
+    /// The JSON-RPC error code of invalid parameters.
+    pub const ERROR_CODE_INVALID_PARAMS: i64 = -32602;
+
+    ///
+    /// Reads a message from the `reader`.
+    ///
+    /// Returns `None` if the input stream is closed.
+    ///
+    pub fn read<R>(reader: &mut R) -> anyhow::Result<Option<Self>>
+    where
+        R: BufRead,
+    {
+        let mut content_length = None;
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header)? == 0 {
+                return Ok(None);
+            }
+            let header = header.trim();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name
+                    .trim()
+                    .eq_ignore_ascii_case(Self::HEADER_CONTENT_LENGTH)
+                {
+                    content_length = Some(value.trim().parse::<usize>().map_err(|error| {
+                        anyhow::anyhow!("LSP header `{header}` parsing: {error}")
+                    })?);
+                }
+            }
+        }
+
+        let content_length = content_length.ok_or_else(|| {
+            anyhow::anyhow!(
+                "LSP message without the `{}` header",
+                Self::HEADER_CONTENT_LENGTH
+            )
+        })?;
+        let mut content = vec![0u8; content_length];
+        reader.read_exact(content.as_mut_slice())?;
+        let message = serde_json::from_slice(content.as_slice())
+            .map_err(|error| anyhow::anyhow!("LSP message parsing: {error}"))?;
+        Ok(Some(message))
+    }
+
+    ///
+    /// Writes the response to the request `id` to stdout.
+    ///
+    pub fn write_response(id: serde_json::Value, result: serde_json::Value) -> anyhow::Result<()> {
+        Self::write(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "result": result,
+        }))
+    }
+
+    ///
+    /// Writes the error response to the request `id` to stdout.
+    ///
+    pub fn write_error(id: serde_json::Value, code: i64, message: String) -> anyhow::Result<()> {
+        Self::write(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": {
+                "code": code,
+                "message": message,
+            },
+        }))
+    }
+
+    ///
+    /// Writes the notification to stdout.
+    ///
+    pub fn write_notification(method: &str, params: serde_json::Value) -> anyhow::Result<()> {
+        Self::write(serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": method,
+            "params": params,
+        }))
+    }
+
+    ///
+    /// Writes the JSON-RPC message with its header to stdout.
+    ///
+    fn write(message: serde_json::Value) -> anyhow::Result<()> {
+        let content = serde_json::to_string(&message).expect("Always valid");
+        let mut stdout = std::io::stdout().lock();
+        write!(
+            stdout,
+            "{}: {}\r\n\r\n{content}",
+            Self::HEADER_CONTENT_LENGTH,
+            content.len()
+        )?;
+        stdout.flush()?;
+        Ok(())
+    }
+}
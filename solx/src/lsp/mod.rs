@@ -0,0 +1,456 @@
+//!
+//! The Language Server Protocol server.
+//!
+
+pub mod diagnostic;
+pub mod message;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::Duration;
+
+use crate::settings::Settings as CompilationSettings;
+
+use self::diagnostic::location::Location;
+use self::diagnostic::range::Range;
+use self::diagnostic::related_information::RelatedInformation;
+use self::diagnostic::Diagnostic;
+use self::message::Message;
+
+///
+/// The Language Server Protocol server.
+///
+/// Speaks LSP over stdio and publishes the `solc` and `solx` diagnostics of the open documents.
+/// The open documents are compiled together as a single project with the standard JSON
+/// pipeline on opening, changing and saving. The compilation settings are accepted in the
+/// `initializationOptions` in the format of the standard JSON input `settings`.
+///
+/// The messages located in the imported files that are not open are published under the URIs
+/// of these files. The messages that are not bound to a location are published for every
+/// open document.
+///
+#[derive(Debug, Default)]
+pub struct Server {
+    /// The standard JSON input settings from the `initializationOptions`.
+    settings: serde_json::Value,
+    /// The open documents, keyed by their file paths, with their URIs and contents.
+    documents: BTreeMap<String, (String, String)>,
+    /// The URIs of the files that are not open, but have diagnostics published.
+    published_uris: BTreeSet<String>,
+    /// Whether the shutdown request has been received.
+    is_shutdown: bool,
+}
+
+impl Server {
+    /// The document URI scheme.
+    pub const URI_SCHEME: &'static str = "file://";
+
+    /// The full document synchronization kind.
+    pub const TEXT_DOCUMENT_SYNC_FULL: usize = 1;
+
+    /// The delay after the last change of a document before it is recompiled.
+    pub const CHANGE_DEBOUNCE_DELAY: Duration = Duration::from_millis(300);
+
+    ///
+    /// Runs the server until the `exit` notification is received or the input stream is closed.
+    ///
+    /// The messages are read on a separate thread, so that the diagnostics are only published
+    /// once a document has not been changed for [`Self::CHANGE_DEBOUNCE_DELAY`], or right before
+    /// another message is handled.
+    ///
+    pub fn run(mut self) -> anyhow::Result<()> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let mut stdin = std::io::stdin().lock();
+            loop {
+                let message = Message::read(&mut stdin);
+                let is_end = !matches!(message, Ok(Some(_)));
+                if sender.send(message).is_err() || is_end {
+                    break;
+                }
+            }
+        });
+
+        let mut is_change_pending = false;
+        loop {
+            let message = if is_change_pending {
+                match receiver.recv_timeout(Self::CHANGE_DEBOUNCE_DELAY) {
+                    Ok(message) => message,
+                    Err(RecvTimeoutError::Timeout) => {
+                        is_change_pending = false;
+                        self.publish_diagnostics()?;
+                        continue;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            } else {
+                match receiver.recv() {
+                    Ok(message) => message,
+                    Err(_) => break,
+                }
+            };
+            let message = match message? {
+                Some(message) => message,
+                None => break,
+            };
+            let method = match message.method {
+                Some(method) => method,
+                None => continue,
+            };
+
+            if is_change_pending && !Self::is_document_synchronization(method.as_str()) {
+                is_change_pending = false;
+                self.publish_diagnostics()?;
+            }
+
+            match (method.as_str(), message.id) {
+                ("initialize", Some(id)) => {
+                    let settings = message.params["initializationOptions"].to_owned();
+                    match Self::parse_settings(&settings) {
+                        Ok(_) => {
+                            self.settings = settings;
+                            Message::write_response(
+                                id,
+                                serde_json::json!({
+                                    "capabilities": {
+                                        "textDocumentSync": {
+                                            "openClose": true,
+                                            "change": Self::TEXT_DOCUMENT_SYNC_FULL,
+                                            "save": { "includeText": true },
+                                        },
+                                    },
+                                    "serverInfo": {
+                                        "name": env!("CARGO_PKG_NAME"),
+                                        "version": env!("CARGO_PKG_VERSION"),
+                                    },
+                                }),
+                            )?;
+                        }
+                        Err(error) => Message::write_error(
+                            id,
+                            Message::ERROR_CODE_INVALID_PARAMS,
+                            format!("Initialization options parsing: {error}"),
+                        )?,
+                    }
+                }
+                ("shutdown", Some(id)) => {
+                    self.is_shutdown = true;
+                    Message::write_response(id, serde_json::Value::Null)?;
+                }
+                ("exit", _) => {
+                    std::process::exit(if self.is_shutdown {
+                        era_compiler_common::EXIT_CODE_SUCCESS
+                    } else {
+                        era_compiler_common::EXIT_CODE_FAILURE
+                    });
+                }
+                ("textDocument/didOpen", None) => {
+                    let document = &message.params["textDocument"];
+                    if let (Some(uri), Some(text)) =
+                        (document["uri"].as_str(), document["text"].as_str())
+                    {
+                        self.open(uri, text.to_owned());
+                        is_change_pending = false;
+                        self.publish_diagnostics()?;
+                    }
+                }
+                ("textDocument/didChange", None) => {
+                    let uri = message.params["textDocument"]["uri"].as_str();
+                    let text = message.params["contentChanges"]
+                        .as_array()
+                        .and_then(|changes| changes.last())
+                        .and_then(|change| change["text"].as_str());
+                    if let (Some(uri), Some(text)) = (uri, text) {
+                        self.open(uri, text.to_owned());
+                        is_change_pending = true;
+                    }
+                }
+                ("textDocument/didSave", None) => {
+                    let uri = message.params["textDocument"]["uri"].as_str();
+                    if let (Some(uri), Some(text)) = (uri, message.params["text"].as_str()) {
+                        self.open(uri, text.to_owned());
+                    }
+                    is_change_pending = false;
+                    self.publish_diagnostics()?;
+                }
+                ("textDocument/didClose", None) => {
+                    if let Some(uri) = message.params["textDocument"]["uri"].as_str() {
+                        self.documents.remove(Self::uri_to_path(uri).as_str());
+                        Message::write_notification(
+                            "textDocument/publishDiagnostics",
+                            serde_json::json!({ "uri": uri, "diagnostics": [] }),
+                        )?;
+                        is_change_pending = false;
+                        self.publish_diagnostics()?;
+                    }
+                }
+                (method, Some(id)) => Message::write_error(
+                    id,
+                    Message::ERROR_CODE_METHOD_NOT_FOUND,
+                    format!("Method `{method}` is not supported."),
+                )?,
+                (_, None) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Whether the `method` is a document synchronization notification.
+    ///
+    /// Such notifications publish the diagnostics themselves, so the pending changes
+    /// do not have to be published before they are handled.
+    ///
+    fn is_document_synchronization(method: &str) -> bool {
+        matches!(
+            method,
+            "textDocument/didOpen"
+                | "textDocument/didChange"
+                | "textDocument/didSave"
+                | "textDocument/didClose"
+        )
+    }
+
+    ///
+    /// Stores the document content.
+    ///
+    fn open(&mut self, uri: &str, text: String) {
+        self.documents
+            .insert(Self::uri_to_path(uri), (uri.to_owned(), text));
+    }
+
+    ///
+    /// Compiles the open documents and publishes the diagnostics for each of them.
+    ///
+    /// The diagnostics of the files that are not open anymore, or not affected by the
+    /// messages anymore, are cleared.
+    ///
+    fn publish_diagnostics(&mut self) -> anyhow::Result<()> {
+        let messages = match self.compile() {
+            Ok(messages) => messages,
+            Err(error) => vec![solx_solc::StandardJsonOutputError::new_error(
+                None, error, None, None,
+            )],
+        };
+
+        let external_paths: BTreeSet<&str> =
+            messages
+                .iter()
+                .flat_map(|message| {
+                    message
+                        .source_location
+                        .iter()
+                        .map(|source_location| source_location.file.as_str())
+                        .chain(message.secondary_source_locations.iter().map(
+                            |secondary_source_location| secondary_source_location.file.as_str(),
+                        ))
+                })
+                .filter(|path| !path.is_empty() && !self.documents.contains_key(*path))
+                .collect();
+        let external_sources: BTreeMap<&str, Option<String>> = external_paths
+            .into_iter()
+            .map(|path| (path, std::fs::read_to_string(path).ok()))
+            .collect();
+        let source_code = |path: &str| match self.documents.get(path) {
+            Some((_, text)) => Some(text.as_str()),
+            None => external_sources.get(path).and_then(Option::as_deref),
+        };
+
+        let mut diagnostics: BTreeMap<String, Vec<Diagnostic>> = self
+            .documents
+            .keys()
+            .map(|path| (path.to_owned(), Vec::new()))
+            .collect();
+        for message in messages.iter() {
+            let related_information = message
+                .secondary_source_locations
+                .iter()
+                .map(|secondary_source_location| {
+                    let path = secondary_source_location.file.as_str();
+                    let range = source_code(path)
+                        .and_then(|source_code| {
+                            Range::try_from_offsets(
+                                source_code,
+                                secondary_source_location.start,
+                                secondary_source_location.end,
+                            )
+                        })
+                        .unwrap_or_default();
+                    RelatedInformation::new(
+                        Location::new(self.uri(path), range),
+                        secondary_source_location
+                            .message
+                            .to_owned()
+                            .unwrap_or_default(),
+                    )
+                })
+                .collect::<Vec<RelatedInformation>>();
+
+            let path = message
+                .source_location
+                .as_ref()
+                .map(|source_location| source_location.file.as_str())
+                .filter(|path| !path.is_empty());
+            match path {
+                Some(path) => {
+                    let diagnostic =
+                        Diagnostic::new(message, source_code(path), related_information);
+                    diagnostics
+                        .entry(path.to_owned())
+                        .or_default()
+                        .push(diagnostic);
+                }
+                None => {
+                    for document_diagnostics in diagnostics
+                        .iter_mut()
+                        .filter(|(path, _)| self.documents.contains_key(path.as_str()))
+                        .map(|(_, diagnostics)| diagnostics)
+                    {
+                        document_diagnostics.push(Diagnostic::new(
+                            message,
+                            None,
+                            related_information.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut published_uris = BTreeSet::new();
+        for (path, diagnostics) in diagnostics.into_iter() {
+            let uri = self.uri(path.as_str());
+            if !self.documents.contains_key(path.as_str()) {
+                published_uris.insert(uri.clone());
+            }
+            Message::write_notification(
+                "textDocument/publishDiagnostics",
+                serde_json::json!({ "uri": uri, "diagnostics": diagnostics }),
+            )?;
+        }
+        for uri in self.published_uris.difference(&published_uris) {
+            Message::write_notification(
+                "textDocument/publishDiagnostics",
+                serde_json::json!({ "uri": uri, "diagnostics": [] }),
+            )?;
+        }
+        self.published_uris = published_uris;
+        Ok(())
+    }
+
+    ///
+    /// Compiles the open documents and returns the `solc` and `solx` messages.
+    ///
+    /// The documents are compiled with the standard JSON pipeline, which stops at the first
+    /// stage that reports errors. The output selection of the settings is ignored.
+    ///
+    fn compile(&self) -> anyhow::Result<Vec<solx_solc::StandardJsonOutputError>> {
+        let sources = self
+            .documents
+            .iter()
+            .map(|(path, (_, text))| (path.to_owned(), text.to_owned().into()))
+            .collect();
+        let allow_paths = self
+            .documents
+            .keys()
+            .filter_map(|path| Path::new(path).parent())
+            .map(|directory| directory.to_string_lossy().to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        let mut settings = Self::parse_settings(&self.settings)?;
+        let via_ir = settings.via_ir;
+        settings.output_selection = solx_solc::StandardJsonInputSelection::new(via_ir);
+        let mut solc_input = solx_solc::StandardJsonInput {
+            language: solx_solc::StandardJsonInputLanguage::Solidity,
+            sources,
+            settings,
+        };
+
+        let mut messages = Vec::new();
+        let mut solc_output = crate::standard_json_input_to_evm(
+            via_ir,
+            &mut solc_input,
+            &mut messages,
+            None,
+            vec![],
+            Some(allow_paths),
+            None,
+            CompilationSettings::default(),
+        )?;
+        solc_output.warning_settings.apply(&mut solc_output.errors);
+        Ok(solc_output.errors)
+    }
+
+    ///
+    /// Parses the standard JSON input settings from the `initializationOptions`.
+    ///
+    /// If the options are omitted, the default settings are used.
+    ///
+    fn parse_settings(
+        options: &serde_json::Value,
+    ) -> serde_json::Result<solx_solc::StandardJsonInputSettings> {
+        match options {
+            serde_json::Value::Null => serde_json::from_value(serde_json::json!({})),
+            options => serde_json::from_value(options.to_owned()),
+        }
+    }
+
+    ///
+    /// Returns the URI of the open document at `path`, or converts the path of other files into a URI.
+    ///
+    fn uri(&self, path: &str) -> String {
+        match self.documents.get(path) {
+            Some((uri, _)) => uri.to_owned(),
+            None => Self::path_to_uri(path),
+        }
+    }
+
+    ///
+    /// Converts the file path into a `file://` URI, percent-encoding the reserved characters.
+    ///
+    fn path_to_uri(path: &str) -> String {
+        let mut uri = Self::URI_SCHEME.to_owned();
+        for byte in path.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                    uri.push(byte as char)
+                }
+                byte => uri.push_str(format!("%{byte:02X}").as_str()),
+            }
+        }
+        uri
+    }
+
+    ///
+    /// Converts the `file://` URI into a file path, decoding the percent-encoded characters.
+    ///
+    fn uri_to_path(uri: &str) -> String {
+        let encoded = uri.strip_prefix(Self::URI_SCHEME).unwrap_or(uri);
+        let mut decoded = Vec::with_capacity(encoded.len());
+        let mut bytes = encoded.bytes();
+        while let Some(byte) = bytes.next() {
+            if byte == b'%' {
+                let hexadecimal = [bytes.next(), bytes.next()];
+                if let [Some(high), Some(low)] = hexadecimal {
+                    if let Ok(byte) = u8::from_str_radix(
+                        String::from_utf8_lossy(&[high, low]).as_ref(),
+                        era_compiler_common::BASE_HEXADECIMAL,
+                    ) {
+                        decoded.push(byte);
+                        continue;
+                    }
+                    decoded.extend([b'%', high, low]);
+                    continue;
+                }
+                decoded.push(byte);
+                decoded.extend(hexadecimal.into_iter().flatten());
+                continue;
+            }
+            decoded.push(byte);
+        }
+        String::from_utf8_lossy(decoded.as_slice()).to_string()
+    }
+}
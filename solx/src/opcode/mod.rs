@@ -0,0 +1,239 @@
+//!
+//! The EVM opcode properties.
+//!
+
+/// The `PUSH*` opcode mnemonics.
+const PUSH_NAMES: [&str; 32] = [
+    "PUSH1", "PUSH2", "PUSH3", "PUSH4", "PUSH5", "PUSH6", "PUSH7", "PUSH8", "PUSH9", "PUSH10",
+    "PUSH11", "PUSH12", "PUSH13", "PUSH14", "PUSH15", "PUSH16", "PUSH17", "PUSH18", "PUSH19",
+    "PUSH20", "PUSH21", "PUSH22", "PUSH23", "PUSH24", "PUSH25", "PUSH26", "PUSH27", "PUSH28",
+    "PUSH29", "PUSH30", "PUSH31", "PUSH32",
+];
+
+/// The `DUP*` opcode mnemonics.
+const DUP_NAMES: [&str; 16] = [
+    "DUP1", "DUP2", "DUP3", "DUP4", "DUP5", "DUP6", "DUP7", "DUP8", "DUP9", "DUP10", "DUP11",
+    "DUP12", "DUP13", "DUP14", "DUP15", "DUP16",
+];
+
+/// The `SWAP*` opcode mnemonics.
+const SWAP_NAMES: [&str; 16] = [
+    "SWAP1", "SWAP2", "SWAP3", "SWAP4", "SWAP5", "SWAP6", "SWAP7", "SWAP8", "SWAP9", "SWAP10",
+    "SWAP11", "SWAP12", "SWAP13", "SWAP14", "SWAP15", "SWAP16",
+];
+
+/// The `LOG*` opcode mnemonics.
+const LOG_NAMES: [&str; 5] = ["LOG0", "LOG1", "LOG2", "LOG3", "LOG4"];
+
+///
+/// The EVM opcode properties.
+///
+/// The gas costs are the static parts only, assuming warm storage and account accesses.
+/// Dynamic costs such as memory expansion, copying, and logging data are not included.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Opcode {
+    /// The mnemonic.
+    pub name: &'static str,
+    /// The number of stack inputs.
+    pub inputs: usize,
+    /// The number of stack outputs.
+    pub outputs: usize,
+    /// The static gas cost.
+    pub gas: u64,
+}
+
+impl Opcode {
+    /// The `STOP` opcode.
+    pub const STOP: u8 = 0x00;
+    /// The `ADD` opcode.
+    pub const ADD: u8 = 0x01;
+    /// The `MUL` opcode.
+    pub const MUL: u8 = 0x02;
+    /// The `SUB` opcode.
+    pub const SUB: u8 = 0x03;
+    /// The `LT` opcode.
+    pub const LT: u8 = 0x10;
+    /// The `GT` opcode.
+    pub const GT: u8 = 0x11;
+    /// The `EQ` opcode.
+    pub const EQ: u8 = 0x14;
+    /// The `ISZERO` opcode.
+    pub const ISZERO: u8 = 0x15;
+    /// The `AND` opcode.
+    pub const AND: u8 = 0x16;
+    /// The `OR` opcode.
+    pub const OR: u8 = 0x17;
+    /// The `XOR` opcode.
+    pub const XOR: u8 = 0x18;
+    /// The `SHL` opcode.
+    pub const SHL: u8 = 0x1b;
+    /// The `SHR` opcode.
+    pub const SHR: u8 = 0x1c;
+    /// The `CALLDATALOAD` opcode.
+    pub const CALLDATALOAD: u8 = 0x35;
+    /// The `JUMP` opcode.
+    pub const JUMP: u8 = 0x56;
+    /// The `JUMPI` opcode.
+    pub const JUMPI: u8 = 0x57;
+    /// The `JUMPDEST` opcode.
+    pub const JUMPDEST: u8 = 0x5b;
+    /// The `PUSH0` opcode.
+    pub const PUSH0: u8 = 0x5f;
+    /// The `PUSH1` opcode.
+    pub const PUSH1: u8 = 0x60;
+    /// The `PUSH32` opcode.
+    pub const PUSH32: u8 = 0x7f;
+    /// The `DUP1` opcode.
+    pub const DUP1: u8 = 0x80;
+    /// The `DUP16` opcode.
+    pub const DUP16: u8 = 0x8f;
+    /// The `SWAP1` opcode.
+    pub const SWAP1: u8 = 0x90;
+    /// The `SWAP16` opcode.
+    pub const SWAP16: u8 = 0x9f;
+    /// The `LOG0` opcode.
+    pub const LOG0: u8 = 0xa0;
+    /// The `RETURN` opcode.
+    pub const RETURN: u8 = 0xf3;
+    /// The `REVERT` opcode.
+    pub const REVERT: u8 = 0xfd;
+    /// The `INVALID` opcode.
+    pub const INVALID: u8 = 0xfe;
+    /// The `SELFDESTRUCT` opcode.
+    pub const SELFDESTRUCT: u8 = 0xff;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    const fn new(name: &'static str, inputs: usize, outputs: usize, gas: u64) -> Self {
+        Self {
+            name,
+            inputs,
+            outputs,
+            gas,
+        }
+    }
+
+    ///
+    /// Returns the opcode properties, or `None` if the opcode is undefined.
+    ///
+    pub fn get(opcode: u8) -> Option<Self> {
+        let opcode = match opcode {
+            0x00 => Self::new("STOP", 0, 0, 0),
+            0x01 => Self::new("ADD", 2, 1, 3),
+            0x02 => Self::new("MUL", 2, 1, 5),
+            0x03 => Self::new("SUB", 2, 1, 3),
+            0x04 => Self::new("DIV", 2, 1, 5),
+            0x05 => Self::new("SDIV", 2, 1, 5),
+            0x06 => Self::new("MOD", 2, 1, 5),
+            0x07 => Self::new("SMOD", 2, 1, 5),
+            0x08 => Self::new("ADDMOD", 3, 1, 8),
+            0x09 => Self::new("MULMOD", 3, 1, 8),
+            0x0a => Self::new("EXP", 2, 1, 10),
+            0x0b => Self::new("SIGNEXTEND", 2, 1, 5),
+            0x10 => Self::new("LT", 2, 1, 3),
+            0x11 => Self::new("GT", 2, 1, 3),
+            0x12 => Self::new("SLT", 2, 1, 3),
+            0x13 => Self::new("SGT", 2, 1, 3),
+            0x14 => Self::new("EQ", 2, 1, 3),
+            0x15 => Self::new("ISZERO", 1, 1, 3),
+            0x16 => Self::new("AND", 2, 1, 3),
+            0x17 => Self::new("OR", 2, 1, 3),
+            0x18 => Self::new("XOR", 2, 1, 3),
+            0x19 => Self::new("NOT", 1, 1, 3),
+            0x1a => Self::new("BYTE", 2, 1, 3),
+            0x1b => Self::new("SHL", 2, 1, 3),
+            0x1c => Self::new("SHR", 2, 1, 3),
+            0x1d => Self::new("SAR", 2, 1, 3),
+            0x20 => Self::new("KECCAK256", 2, 1, 30),
+            0x30 => Self::new("ADDRESS", 0, 1, 2),
+            0x31 => Self::new("BALANCE", 1, 1, 100),
+            0x32 => Self::new("ORIGIN", 0, 1, 2),
+            0x33 => Self::new("CALLER", 0, 1, 2),
+            0x34 => Self::new("CALLVALUE", 0, 1, 2),
+            0x35 => Self::new("CALLDATALOAD", 1, 1, 3),
+            0x36 => Self::new("CALLDATASIZE", 0, 1, 2),
+            0x37 => Self::new("CALLDATACOPY", 3, 0, 3),
+            0x38 => Self::new("CODESIZE", 0, 1, 2),
+            0x39 => Self::new("CODECOPY", 3, 0, 3),
+            0x3a => Self::new("GASPRICE", 0, 1, 2),
+            0x3b => Self::new("EXTCODESIZE", 1, 1, 100),
+            0x3c => Self::new("EXTCODECOPY", 4, 0, 100),
+            0x3d => Self::new("RETURNDATASIZE", 0, 1, 2),
+            0x3e => Self::new("RETURNDATACOPY", 3, 0, 3),
+            0x3f => Self::new("EXTCODEHASH", 1, 1, 100),
+            0x40 => Self::new("BLOCKHASH", 1, 1, 20),
+            0x41 => Self::new("COINBASE", 0, 1, 2),
+            0x42 => Self::new("TIMESTAMP", 0, 1, 2),
+            0x43 => Self::new("NUMBER", 0, 1, 2),
+            0x44 => Self::new("PREVRANDAO", 0, 1, 2),
+            0x45 => Self::new("GASLIMIT", 0, 1, 2),
+            0x46 => Self::new("CHAINID", 0, 1, 2),
+            0x47 => Self::new("SELFBALANCE", 0, 1, 5),
+            0x48 => Self::new("BASEFEE", 0, 1, 2),
+            0x49 => Self::new("BLOBHASH", 1, 1, 3),
+            0x4a => Self::new("BLOBBASEFEE", 0, 1, 2),
+            0x50 => Self::new("POP", 1, 0, 2),
+            0x51 => Self::new("MLOAD", 1, 1, 3),
+            0x52 => Self::new("MSTORE", 2, 0, 3),
+            0x53 => Self::new("MSTORE8", 2, 0, 3),
+            0x54 => Self::new("SLOAD", 1, 1, 100),
+            0x55 => Self::new("SSTORE", 2, 0, 100),
+            0x56 => Self::new("JUMP", 1, 0, 8),
+            0x57 => Self::new("JUMPI", 2, 0, 10),
+            0x58 => Self::new("PC", 0, 1, 2),
+            0x59 => Self::new("MSIZE", 0, 1, 2),
+            0x5a => Self::new("GAS", 0, 1, 2),
+            0x5b => Self::new("JUMPDEST", 0, 0, 1),
+            0x5c => Self::new("TLOAD", 1, 1, 100),
+            0x5d => Self::new("TSTORE", 2, 0, 100),
+            0x5e => Self::new("MCOPY", 3, 0, 3),
+            0x5f => Self::new("PUSH0", 0, 1, 2),
+            0x60..=0x7f => Self::new(PUSH_NAMES[(opcode - Self::PUSH1) as usize], 0, 1, 3),
+            0x80..=0x8f => {
+                let depth = (opcode - Self::DUP1 + 1) as usize;
+                Self::new(DUP_NAMES[depth - 1], depth, depth + 1, 3)
+            }
+            0x90..=0x9f => {
+                let depth = (opcode - Self::SWAP1 + 1) as usize;
+                Self::new(SWAP_NAMES[depth - 1], depth + 1, depth + 1, 3)
+            }
+            0xa0..=0xa4 => {
+                let topics = (opcode - Self::LOG0) as usize;
+                Self::new(LOG_NAMES[topics], topics + 2, 0, 375 * (topics as u64 + 1))
+            }
+            0xf0 => Self::new("CREATE", 3, 1, 32000),
+            0xf1 => Self::new("CALL", 7, 1, 100),
+            0xf2 => Self::new("CALLCODE", 7, 1, 100),
+            0xf3 => Self::new("RETURN", 2, 0, 0),
+            0xf4 => Self::new("DELEGATECALL", 6, 1, 100),
+            0xf5 => Self::new("CREATE2", 4, 1, 32000),
+            0xfa => Self::new("STATICCALL", 6, 1, 100),
+            0xfd => Self::new("REVERT", 2, 0, 0),
+            0xff => Self::new("SELFDESTRUCT", 1, 0, 5000),
+            _ => return None,
+        };
+        Some(opcode)
+    }
+
+    ///
+    /// Whether the opcode halts the execution.
+    ///
+    pub fn is_halting(opcode: u8) -> bool {
+        matches!(
+            opcode,
+            Self::STOP | Self::RETURN | Self::REVERT | Self::INVALID | Self::SELFDESTRUCT
+        )
+    }
+
+    ///
+    /// Returns the size of the instruction with the `opcode`, including its immediate data.
+    ///
+    pub fn size(opcode: u8) -> usize {
+        match opcode {
+            Self::PUSH1..=Self::PUSH32 => 1 + (opcode - Self::PUSH1 + 1) as usize,
+            _ => 1,
+        }
+    }
+}
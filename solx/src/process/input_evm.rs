@@ -20,6 +20,8 @@ pub struct Input {
     pub identifier_paths: BTreeMap<String, String>,
     /// Already deployed libraries.
     pub deployed_libraries: BTreeSet<String>,
+    /// The EVM version to produce bytecode for.
+    pub evm_version: Option<era_compiler_common::EVMVersion>,
     /// The metadata hash type.
     pub metadata_hash_type: era_compiler_common::HashType,
     /// The optimizer settings.
@@ -38,6 +40,7 @@ impl Input {
         contract: Contract,
         identifier_paths: BTreeMap<String, String>,
         deployed_libraries: BTreeSet<String>,
+        evm_version: Option<era_compiler_common::EVMVersion>,
         metadata_hash_type: era_compiler_common::HashType,
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
@@ -47,6 +50,7 @@ impl Input {
             contract,
             identifier_paths,
             deployed_libraries,
+            evm_version,
             metadata_hash_type,
             optimizer_settings,
             llvm_options,
@@ -0,0 +1,111 @@
+//!
+//! The resource limits of the compilation subprocess.
+//!
+
+use std::process::Command;
+use std::process::ExitStatus;
+use std::time::Duration;
+
+///
+/// The resource limits of the compilation subprocess.
+///
+/// The limits are applied to each contract separately, so that a single pathological contract
+/// is reported as an error instead of hanging or exhausting the memory of the whole build.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Limits {
+    /// The wall-clock time limit.
+    pub timeout: Option<Duration>,
+    /// The address space limit in mebibytes.
+    pub memory_limit: Option<u64>,
+}
+
+impl Limits {
+    /// The number of bytes in a mebibyte.
+    pub const BYTES_PER_MEBIBYTE: u64 = 1024 * 1024;
+
+    /// The lowercase `stderr` patterns of the allocation failures reported by Rust, LLVM, and the OS.
+    pub const ALLOCATION_FAILURE_PATTERNS: [&'static str; 5] = [
+        "memory allocation of",
+        "out of memory",
+        "outofmemory",
+        "bad_alloc",
+        "cannot allocate memory",
+    ];
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(timeout: Option<Duration>, memory_limit: Option<u64>) -> Self {
+        Self {
+            timeout,
+            memory_limit,
+        }
+    }
+
+    ///
+    /// Sets the address space limit of the subprocess spawned by the `command`.
+    ///
+    /// Only supported on Unix platforms, where the limit is set with `setrlimit` right before
+    /// the executable is loaded.
+    ///
+    pub fn apply(&self, command: &mut Command) {
+        #[cfg(unix)]
+        if let Some(memory_limit) = self.memory_limit {
+            use std::os::unix::process::CommandExt;
+
+            let bytes = memory_limit.saturating_mul(Self::BYTES_PER_MEBIBYTE) as libc::rlim_t;
+            unsafe {
+                command.pre_exec(move || {
+                    let limit = libc::rlimit {
+                        rlim_cur: bytes,
+                        rlim_max: bytes,
+                    };
+                    if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                        return Err(std::io::Error::last_os_error());
+                    }
+                    Ok(())
+                });
+            }
+        }
+        #[cfg(not(unix))]
+        let _ = command;
+    }
+
+    ///
+    /// Whether the subprocess terminated with `status` has exceeded the memory limit.
+    ///
+    /// Neither the exit code nor the signal tells an allocation failure apart from other
+    /// crashes, so the limit is only blamed if the subprocess `stderr` reports a failed
+    /// allocation.
+    ///
+    pub fn is_memory_limit_exceeded(&self, status: &ExitStatus, stderr: &[u8]) -> bool {
+        if self.memory_limit.is_none() || status.success() {
+            return false;
+        }
+
+        let stderr = String::from_utf8_lossy(stderr).to_lowercase();
+        Self::ALLOCATION_FAILURE_PATTERNS
+            .iter()
+            .any(|pattern| stderr.contains(pattern))
+    }
+
+    ///
+    /// Returns the error message of the subprocess that has exceeded the time limit.
+    ///
+    pub fn timeout_message(&self) -> String {
+        format!(
+            "Contract compilation exceeded the time limit of {:.3} seconds.",
+            self.timeout.unwrap_or_default().as_secs_f64()
+        )
+    }
+
+    ///
+    /// Returns the error message of the subprocess that has exceeded the memory limit, if it is set.
+    ///
+    pub fn memory_limit_message(&self) -> Option<String> {
+        self.memory_limit.map(|memory_limit| {
+            format!("Contract compilation exceeded the memory limit of {memory_limit} MiB.")
+        })
+    }
+}
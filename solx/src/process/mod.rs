@@ -3,19 +3,29 @@
 //!
 
 pub mod input_evm;
+pub mod limits;
 pub mod output_evm;
 
+use std::io::Read;
 use std::io::Write;
 use std::path::PathBuf;
 use std::process::Command;
 use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::error::located::Located as LocatedError;
 
 use self::input_evm::Input as EVMInput;
+use self::limits::Limits;
 use self::output_evm::Output as EVMOutput;
 
 /// The overridden executable name used when the compiler is run as a library.
 pub static EXECUTABLE: OnceLock<PathBuf> = OnceLock::new();
 
+/// The interval between two consecutive checks of the subprocess status under a time limit.
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
 ///
 /// Read input from `stdin`, compile a contract, and write the output to `stdout`.
 ///
@@ -25,13 +35,33 @@ pub fn run() -> anyhow::Result<()> {
     let input: EVMInput = era_compiler_common::deserialize_from_str(input_json.as_str())
         .map_err(|error| anyhow::anyhow!("Stdin parsing error: {error}"))?;
 
-    let source_location =
-        solx_solc::StandardJsonOutputErrorSourceLocation::new(input.contract.name.path.clone());
-    let result = input
+    let result = compile(input);
+    serde_json::to_writer(std::io::stdout(), &result)
+        .map_err(|error| anyhow::anyhow!("Stdout writing error: {error}"))?;
+    unsafe { inkwell::support::shutdown_llvm() };
+    Ok(())
+}
+
+///
+/// Compiles a single contract in the current process.
+///
+/// Each contract is compiled in its own LLVM context, so the function can be called from
+/// multiple threads at once. Unlike with a subprocess, a crash of the backend terminates
+/// the whole compiler. The LLVM target must be initialized by the caller.
+///
+/// The backend errors are located at the instruction or statement being translated when they
+/// occurred, or at the contract file if it is unknown. Only the offsets are reported, so the
+/// caller must render the location with the input sources.
+///
+pub fn compile(input: EVMInput) -> crate::Result<EVMOutput> {
+    let path = input.contract.name.path.clone();
+    let source_paths = input.contract.ir.source_paths();
+    input
         .contract
         .compile_to_evm(
             input.identifier_paths,
             input.deployed_libraries,
+            input.evm_version,
             input.metadata_hash_type,
             input.optimizer_settings,
             input.llvm_options,
@@ -39,18 +69,19 @@ pub fn run() -> anyhow::Result<()> {
         )
         .map(EVMOutput::new)
         .map_err(|error| {
+            let source_location = LocatedError::source_location(&error, &source_paths)
+                .unwrap_or_else(|| solx_solc::StandardJsonOutputErrorSourceLocation::new(path));
             solx_solc::StandardJsonOutputError::new_error(None, error, Some(source_location), None)
-        });
-    serde_json::to_writer(std::io::stdout(), &result)
-        .map_err(|error| anyhow::anyhow!("Stdout writing error: {error}"))?;
-    unsafe { inkwell::support::shutdown_llvm() };
-    Ok(())
+        })
 }
 
 ///
 /// Runs this process recursively to compile a single contract.
 ///
-pub fn call<I, O>(path: &str, input: I) -> crate::Result<O>
+/// The subprocess is killed if it exceeds the time limit. The failures to spawn or communicate
+/// with the subprocess are returned as errors located at the contract `path`.
+///
+pub fn call<I, O>(path: &str, input: I, limits: &Limits) -> crate::Result<O>
 where
     I: serde::Serialize,
     O: serde::de::DeserializeOwned,
@@ -59,6 +90,16 @@ where
         .get()
         .cloned()
         .unwrap_or_else(|| std::env::current_exe().expect("Current executable path getting error"));
+    let error = |message: String| {
+        solx_solc::StandardJsonOutputError::new_error(
+            None,
+            message,
+            Some(solx_solc::StandardJsonOutputErrorSourceLocation::new(
+                path.to_owned(),
+            )),
+            None,
+        )
+    };
 
     let mut command = Command::new(executable.as_path());
     command.stdin(std::process::Stdio::piped());
@@ -66,49 +107,99 @@ where
     command.stderr(std::process::Stdio::piped());
     command.arg("--recursive-process");
     command.arg(path);
+    limits.apply(&mut command);
 
-    let mut process = command
-        .spawn()
-        .unwrap_or_else(|error| panic!("{executable:?} subprocess spawning: {error:?}"));
+    let mut process =
+        command
+            .spawn()
+            .map_err(|spawn_error| match limits.memory_limit_message() {
+                Some(message) if spawn_error.kind() == std::io::ErrorKind::OutOfMemory => {
+                    error(message)
+                }
+                _ => error(format!("{executable:?} subprocess spawning: {spawn_error}")),
+            })?;
 
-    let stdin = process
-        .stdin
-        .as_mut()
-        .unwrap_or_else(|| panic!("{executable:?} subprocess stdin getting error"));
+    // The input is written before the output is read, as the subprocess reads the whole input first.
+    // A writing failure means that the subprocess has terminated, so its status is checked below.
     let stdin_input = serde_json::to_vec(&input).expect("Always valid");
-    stdin
-        .write_all(stdin_input.as_slice())
-        .unwrap_or_else(|error| panic!("{executable:?} subprocess stdin writing: {error:?}"));
+    if let Some(mut stdin) = process.stdin.take() {
+        let _ = stdin.write_all(stdin_input.as_slice());
+    }
+    let stdout = read_in_background(process.stdout.take());
+    let stderr = read_in_background(process.stderr.take());
 
-    let result = process
-        .wait_with_output()
-        .unwrap_or_else(|error| panic!("{executable:?} subprocess output reading: {error:?}"));
+    let status = match limits.timeout {
+        Some(timeout) => {
+            let start = Instant::now();
+            loop {
+                match process.try_wait() {
+                    Ok(Some(status)) => break Ok(status),
+                    Ok(None) if start.elapsed() >= timeout => {
+                        let _ = process.kill();
+                        let _ = process.wait();
+                        return Err(error(limits.timeout_message()));
+                    }
+                    Ok(None) => std::thread::sleep(WAIT_POLL_INTERVAL),
+                    Err(wait_error) => break Err(wait_error),
+                }
+            }
+        }
+        None => process.wait(),
+    }
+    .map_err(|wait_error| error(format!("{executable:?} subprocess waiting: {wait_error}")))?;
+    let stdout = stdout.join().expect("Always valid");
+    let stderr = stderr.join().expect("Always valid");
 
-    if result.status.code() != Some(era_compiler_common::EXIT_CODE_SUCCESS) {
+    if status.code() != Some(era_compiler_common::EXIT_CODE_SUCCESS) {
+        #[cfg(unix)]
+        let signal = std::os::unix::process::ExitStatusExt::signal(&status);
+        #[cfg(not(unix))]
+        let signal: Option<i32> = None;
         let message = format!(
-            "{executable:?} subprocess failed with exit code {:?}:\n{}\n{}",
-            result.status.code(),
-            String::from_utf8_lossy(result.stdout.as_slice()),
-            String::from_utf8_lossy(result.stderr.as_slice()),
+            "{executable:?} subprocess failed with exit code {:?}{}:\n{}\n{}",
+            status.code(),
+            signal
+                .map(|signal| format!(", terminated by signal {signal}"))
+                .unwrap_or_default(),
+            String::from_utf8_lossy(stdout.as_slice()),
+            String::from_utf8_lossy(stderr.as_slice()),
         );
-        return Err(solx_solc::StandardJsonOutputError::new_error(
-            None,
-            message,
-            Some(solx_solc::StandardJsonOutputErrorSourceLocation::new(
-                path.to_owned(),
-            )),
-            None,
-        ));
+        let message = match limits.memory_limit_message() {
+            Some(memory_limit_message)
+                if limits.is_memory_limit_exceeded(&status, stderr.as_slice()) =>
+            {
+                format!("{memory_limit_message}\n{message}")
+            }
+            _ => message,
+        };
+        return Err(error(message));
     }
 
-    match era_compiler_common::deserialize_from_slice(result.stdout.as_slice()) {
+    match era_compiler_common::deserialize_from_slice(stdout.as_slice()) {
         Ok(output) => output,
-        Err(error) => {
-            panic!(
-                "{executable:?} subprocess stdout parsing error: {error:?}\n{}\n{}",
-                String::from_utf8_lossy(result.stdout.as_slice()),
-                String::from_utf8_lossy(result.stderr.as_slice()),
-            );
-        }
+        Err(parsing_error) => Err(error(format!(
+            "{executable:?} subprocess stdout parsing error: {parsing_error:?}\n{}\n{}",
+            String::from_utf8_lossy(stdout.as_slice()),
+            String::from_utf8_lossy(stderr.as_slice()),
+        ))),
     }
 }
+
+///
+/// Reads the subprocess output stream to the end in a separate thread.
+///
+/// Reading in the background prevents the subprocess from blocking on a full pipe
+/// while its termination is being awaited.
+///
+fn read_in_background<R>(stream: Option<R>) -> std::thread::JoinHandle<Vec<u8>>
+where
+    R: Read + Send + 'static,
+{
+    std::thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut stream) = stream {
+            let _ = stream.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
@@ -2,6 +2,7 @@
 //! The contract EVM legacy assembly source code.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::evmla::assembly::Assembly;
@@ -13,13 +14,19 @@ use crate::evmla::assembly::Assembly;
 pub struct EVMLA {
     /// The EVM legacy assembly source code.
     pub assembly: Assembly,
+    /// The mapping of `solc` source identifiers to paths, used to resolve the instruction locations.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub source_paths: BTreeMap<usize, String>,
 }
 
 impl EVMLA {
     ///
     /// Transforms the `solc` standard JSON output contract into an EVM legacy assembly object.
     ///
-    pub fn try_from_contract(contract: &solx_solc::StandardJsonOutputContract) -> Option<Self> {
+    pub fn try_from_contract(
+        contract: &solx_solc::StandardJsonOutputContract,
+        source_paths: &BTreeMap<usize, String>,
+    ) -> Option<Self> {
         let evm = contract.evm.as_ref()?;
 
         let mut assembly: Assembly = serde_json::from_value(evm.legacy_assembly.to_owned()).ok()?;
@@ -28,7 +35,10 @@ impl EVMLA {
             runtime_code.extra_metadata = evm.extra_metadata.to_owned();
         }
 
-        Some(Self { assembly })
+        Some(Self {
+            assembly,
+            source_paths: source_paths.to_owned(),
+        })
     }
 
     ///
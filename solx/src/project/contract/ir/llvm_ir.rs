@@ -2,22 +2,196 @@
 //! The contract LLVM IR source code.
 //!
 
+use inkwell::values::AnyValue;
+
 ///
 /// The contract LLVM IR source code.
 ///
+/// Each LLVM IR module represents a single code segment, and is identified by its `source_filename`.
+/// The runtime code module of a contract must have the identifier of its deploy code module with the
+/// `.runtime` suffix. Dependencies are declared by referencing their identifiers in the
+/// `llvm.evm.dataoffset` and `llvm.evm.datasize` intrinsics, e.g. `@llvm.evm.datasize(metadata !"Test.runtime")`.
+///
+/// The module is parsed once on creation, and the dependencies are collected from the intrinsic calls.
+///
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct LLVMIR {
     /// The LLVM IR file path.
     pub path: String,
+    /// The LLVM IR module identifier.
+    pub identifier: String,
     /// The LLVM IR source code.
     pub source: String,
+    /// The identifiers referenced by the dependency intrinsic calls, in the order of appearance.
+    pub dependencies: Vec<String>,
+    /// The runtime code module.
+    pub runtime_code: Option<Box<Self>>,
 }
 
 impl LLVMIR {
+    /// The entry function name every module must define.
+    pub const ENTRY_FUNCTION_NAME: &'static str = "__entry";
+
+    /// The target triple prefix every module must be compiled for.
+    pub const TARGET_TRIPLE_PREFIX: &'static str = "evm";
+
+    /// The runtime code module identifier suffix.
+    pub const RUNTIME_CODE_SUFFIX: &'static str = ".runtime";
+
+    /// The intrinsics whose metadata operands are treated as dependencies.
+    pub const DEPENDENCY_INTRINSICS: [&'static str; 2] =
+        ["llvm.evm.dataoffset", "llvm.evm.datasize"];
+
+    /// The prefix of the metadata string operand of the dependency intrinsics.
+    pub const DEPENDENCY_OPERAND_PREFIX: &'static str = "metadata !\"";
+
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(path: String, source: String) -> Self {
-        Self { path, source }
+    pub fn new(
+        path: String,
+        identifier: String,
+        source: String,
+        dependencies: Vec<String>,
+    ) -> Self {
+        Self {
+            path,
+            identifier,
+            source,
+            dependencies,
+            runtime_code: None,
+        }
+    }
+
+    ///
+    /// Parses and validates the LLVM IR module.
+    ///
+    /// The module is checked to target EVM and define the entry function, so that the mistakes
+    /// are reported as compilation errors instead of LLVM crashes in the backend.
+    ///
+    pub fn try_from_source(path: String, source: String) -> anyhow::Result<Self> {
+        let llvm = inkwell::context::Context::create();
+        let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range_copy(
+            source.as_bytes(),
+            path.as_str(),
+        );
+        let module = llvm
+            .create_module_from_ir(memory_buffer)
+            .map_err(|error| anyhow::anyhow!("LLVM IR parsing: {}", error.to_string()))?;
+
+        let target_triple = module.get_triple();
+        let target_triple = target_triple.as_str().to_string_lossy();
+        if !target_triple.starts_with(Self::TARGET_TRIPLE_PREFIX) {
+            anyhow::bail!(
+                "LLVM IR module target triple `{target_triple}` is not supported. Only `{}` targets are allowed.",
+                Self::TARGET_TRIPLE_PREFIX
+            );
+        }
+
+        match module.get_function(Self::ENTRY_FUNCTION_NAME) {
+            Some(function) if function.count_basic_blocks() > 0 => {}
+            Some(_) => anyhow::bail!(
+                "LLVM IR module entry function `{}` is declared but not defined.",
+                Self::ENTRY_FUNCTION_NAME
+            ),
+            None => anyhow::bail!(
+                "LLVM IR module entry function `{}` is missing.",
+                Self::ENTRY_FUNCTION_NAME
+            ),
+        }
+
+        let identifier = module.get_source_file_name().to_string_lossy().to_string();
+        let identifier = if identifier.is_empty() {
+            path.clone()
+        } else {
+            identifier
+        };
+
+        let dependencies = Self::collect_dependencies(&module);
+
+        Ok(Self::new(path, identifier, source, dependencies))
+    }
+
+    ///
+    /// Whether the module is a runtime code module.
+    ///
+    pub fn is_runtime_code(&self) -> bool {
+        self.identifier.ends_with(Self::RUNTIME_CODE_SUFFIX)
+    }
+
+    ///
+    /// Returns the identifier of the runtime code module expected for this deploy code module.
+    ///
+    pub fn runtime_code_identifier(&self) -> String {
+        format!("{}{}", self.identifier, Self::RUNTIME_CODE_SUFFIX)
+    }
+
+    ///
+    /// Extracts the runtime code module.
+    ///
+    pub fn take_runtime_code(&mut self) -> Option<Self> {
+        self.runtime_code.take().map(|runtime_code| *runtime_code)
+    }
+
+    ///
+    /// Get the list of EVM dependencies.
+    ///
+    pub fn get_evm_dependencies(&self, runtime_code: Option<&Self>) -> solx_yul::Dependencies {
+        let mut dependencies = solx_yul::Dependencies::new(self.identifier.as_str());
+        if let Some(runtime_code) = runtime_code {
+            dependencies.push(runtime_code.identifier.to_owned(), true);
+        }
+
+        for dependency in self.dependencies.iter() {
+            dependencies.push(dependency.to_owned(), false);
+        }
+
+        dependencies
+    }
+
+    ///
+    /// Collects the identifiers referenced by the dependency intrinsic calls in the module.
+    ///
+    /// The callee is the last operand of a call instruction. The metadata operand cannot be
+    /// accessed as a value, so it is read from the printed instruction.
+    ///
+    fn collect_dependencies(module: &inkwell::module::Module) -> Vec<String> {
+        let mut dependencies = Vec::new();
+        for function in module.get_functions() {
+            for block in function.get_basic_blocks().into_iter() {
+                let mut instruction = block.get_first_instruction();
+                while let Some(current) = instruction {
+                    instruction = current.get_next_instruction();
+
+                    if current.get_opcode() != inkwell::values::InstructionOpcode::Call {
+                        continue;
+                    }
+                    let is_dependency_intrinsic = current
+                        .get_num_operands()
+                        .checked_sub(1)
+                        .and_then(|index| current.get_operand(index))
+                        .and_then(|operand| operand.left())
+                        .filter(|callee| callee.is_pointer_value())
+                        .map(|callee| {
+                            let callee = callee.into_pointer_value();
+                            let name = callee.get_name().to_string_lossy();
+                            Self::DEPENDENCY_INTRINSICS.contains(&name.as_ref())
+                        })
+                        .unwrap_or_default();
+                    if !is_dependency_intrinsic {
+                        continue;
+                    }
+
+                    let instruction_text = current.print_to_string().to_string();
+                    if let Some(dependency) = instruction_text
+                        .split_once(Self::DEPENDENCY_OPERAND_PREFIX)
+                        .and_then(|(_, operand)| operand.split('"').next())
+                    {
+                        dependencies.push(dependency.to_owned());
+                    }
+                }
+            }
+        }
+        dependencies
     }
 }
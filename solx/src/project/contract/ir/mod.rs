@@ -6,6 +6,7 @@ pub mod evmla;
 pub mod llvm_ir;
 pub mod yul;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use self::evmla::EVMLA;
@@ -47,6 +48,30 @@ impl IR {
             Self::LLVMIR(_inner) => BTreeSet::new(),
         }
     }
+
+    ///
+    /// Get the identifiers resolved via the project identifier paths during code generation.
+    ///
+    /// Only the Yul code generator resolves object identifiers to contract paths.
+    ///
+    pub fn get_dependency_identifiers(&self) -> BTreeSet<String> {
+        match self {
+            Self::Yul(inner) => inner.get_dependency_identifiers(),
+            Self::EVMLA(_inner) => BTreeSet::new(),
+            Self::LLVMIR(_inner) => BTreeSet::new(),
+        }
+    }
+
+    ///
+    /// Returns the mapping of source file identifiers to paths, used to resolve the error locations.
+    ///
+    pub fn source_paths(&self) -> BTreeMap<usize, String> {
+        match self {
+            Self::Yul(inner) => inner.source_paths(),
+            Self::EVMLA(inner) => inner.source_paths.clone(),
+            Self::LLVMIR(_inner) => BTreeMap::new(),
+        }
+    }
 }
 
 impl From<Yul> for IR {
@@ -2,6 +2,7 @@
 //! The contract Yul source code.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use solx_yul::yul::lexer::Lexer;
@@ -59,6 +60,59 @@ impl Yul {
         self.object.0.get_unlinked_libraries()
     }
 
+    ///
+    /// Returns the mapping of `@src` file indexes to paths, taken from the `@use-src` annotations.
+    ///
+    pub fn source_paths(&self) -> BTreeMap<usize, String> {
+        let mut source_paths = self.object.0.source_files.clone();
+        if let Some(runtime_code) = self.object.0.inner_object.as_ref() {
+            source_paths.extend(runtime_code.source_files.clone());
+        }
+        source_paths
+    }
+
+    ///
+    /// Get the data segments of the `object`, decoded into bytes.
+    ///
+    pub fn get_data_segments(
+        object: &Object<EraDialect>,
+    ) -> anyhow::Result<BTreeMap<String, Vec<u8>>> {
+        object
+            .data
+            .iter()
+            .map(|(identifier, data)| {
+                let bytes = data
+                    .to_hexadecimal()
+                    .and_then(|hexadecimal| hex::decode(hexadecimal).map_err(anyhow::Error::from))
+                    .map_err(|error| {
+                        anyhow::anyhow!(
+                            "{} Data segment `{identifier}` decoding: {error}",
+                            data.location
+                        )
+                    })?;
+                Ok((identifier.to_owned(), bytes))
+            })
+            .collect()
+    }
+
+    ///
+    /// Get the identifiers of the deploy and runtime code objects and their EVM dependencies.
+    ///
+    pub fn get_dependency_identifiers(&self) -> BTreeSet<String> {
+        let mut identifiers = BTreeSet::new();
+        identifiers.insert(self.object.0.identifier.to_owned());
+        identifiers.extend(
+            self.object
+                .0
+                .get_evm_dependencies(self.object.0.inner_object.as_deref())
+                .inner,
+        );
+        if let Some(runtime_code) = self.object.0.inner_object.as_deref() {
+            identifiers.extend(runtime_code.get_evm_dependencies(None).inner);
+        }
+        identifiers
+    }
+
     ///
     /// Get the list of EVM dependencies.
     ///
@@ -7,13 +7,19 @@ pub mod metadata;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::time::Instant;
 
 use era_compiler_llvm_context::IContext;
 
 use crate::build_evm::contract::object::Object as EVMContractObject;
 use crate::build_evm::contract::Contract as EVMContractBuild;
+use crate::error::located::Located as LocatedError;
+use crate::source_map::SourceMap;
+use crate::statistics::contract::Contract as ContractStatistics;
+use crate::statistics::Statistics;
 use crate::yul::parser::wrapper::Wrap;
 
+use self::ir::yul::Yul;
 use self::ir::IR;
 use self::metadata::Metadata;
 
@@ -28,9 +34,18 @@ pub struct Contract {
     pub ir: IR,
     /// The origin `solc` metadata.
     pub source_metadata: String,
+    /// The IR parsing time in milliseconds, if measured.
+    #[serde(skip)]
+    pub parsing_time: Option<f64>,
 }
 
 impl Contract {
+    /// The intrinsics of instructions introduced in the Cancun EVM version.
+    const CANCUN_INTRINSICS: [(&'static str, &'static str); 2] = [
+        ("llvm.evm.blobhash", "BLOBHASH"),
+        ("llvm.evm.blobbasefee", "BLOBBASEFEE"),
+    ];
+
     ///
     /// A shortcut constructor.
     ///
@@ -39,6 +54,7 @@ impl Contract {
             name,
             ir,
             source_metadata,
+            parsing_time: None,
         }
     }
 
@@ -46,13 +62,13 @@ impl Contract {
     /// Returns the contract identifier, which is:
     /// - the Yul object identifier for Yul
     /// - the full contract path for EVM legacy assembly
-    /// - the module name for LLVM IR
+    /// - the deploy code module `source_filename` for LLVM IR
     ///
     pub fn identifier(&self) -> &str {
         match self.ir {
             IR::Yul(ref yul) => yul.object.0.identifier.as_str(),
             IR::EVMLA(ref evm) => evm.assembly.full_path(),
-            IR::LLVMIR(ref llvm_ir) => llvm_ir.path.as_str(),
+            IR::LLVMIR(ref llvm_ir) => llvm_ir.identifier.as_str(),
         }
     }
 
@@ -66,6 +82,7 @@ impl Contract {
         self,
         identifier_paths: BTreeMap<String, String>,
         deployed_libraries: BTreeSet<String>,
+        evm_version: Option<era_compiler_common::EVMVersion>,
         metadata_hash_type: era_compiler_common::HashType,
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
@@ -92,6 +109,7 @@ impl Contract {
             }
         };
 
+        let mut statistics = ContractStatistics::default();
         match self.ir {
             IR::Yul(mut deploy_code) => {
                 let runtime_code = deploy_code.take_runtime_code().ok_or_else(|| {
@@ -105,6 +123,8 @@ impl Contract {
 
                 let deploy_code_dependecies = deploy_code.get_evm_dependencies(Some(&runtime_code));
                 let runtime_code_dependecies = runtime_code.get_evm_dependencies(None);
+                let deploy_code_data = Yul::get_data_segments(&deploy_code.object.0)?;
+                let runtime_code_data = Yul::get_data_segments(&runtime_code)?;
                 let mut runtime_code = runtime_code.wrap();
 
                 let deploy_code_identifier = deploy_code.object.0.identifier.clone();
@@ -126,13 +146,23 @@ impl Contract {
                 runtime_context.set_yul_data(era_compiler_llvm_context::EVMContextYulData::new(
                     identifier_paths.clone(),
                 ));
+                let ir_generation_start = Instant::now();
                 runtime_code.declare(&mut runtime_context)?;
                 runtime_code
                     .into_llvm(&mut runtime_context)
                     .map_err(|error| {
-                        anyhow::anyhow!("{runtime_code_segment} code LLVM IR generator: {error}")
+                        LocatedError::map(error, |error| {
+                            anyhow::anyhow!(
+                                "{runtime_code_segment} code LLVM IR generator: {error}"
+                            )
+                        })
                     })?;
+                Statistics::add_time(&mut statistics.llvm_ir_generation_time, ir_generation_start);
+                Self::check_evm_version(runtime_context.module(), evm_version)?;
+                let llvm_build_start = Instant::now();
                 let (runtime_buffer, runtime_code_errors) = runtime_context.build()?;
+                Statistics::add_time(&mut statistics.llvm_build_time, llvm_build_start);
+                let immutables_map = runtime_buffer.get_immutables_evm();
                 let runtime_object = EVMContractObject::new(
                     runtime_code_identifier,
                     self.name.clone(),
@@ -141,11 +171,12 @@ impl Contract {
                     runtime_code_segment,
                     runtime_code_dependecies,
                     runtime_code_libraries,
+                    runtime_code_data,
+                    immutables_map.clone(),
+                    SourceMap::get_locations(&runtime_buffer),
                     runtime_code_errors,
                 );
 
-                let immutables_map = runtime_buffer.get_immutables_evm();
-
                 let deploy_code_segment = era_compiler_common::CodeSegment::Deploy;
                 let deploy_llvm = inkwell::context::Context::create();
                 let deploy_module = deploy_llvm.create_module(
@@ -165,13 +196,20 @@ impl Contract {
                 deploy_context.set_yul_data(era_compiler_llvm_context::EVMContextYulData::new(
                     identifier_paths,
                 ));
+                let ir_generation_start = Instant::now();
                 deploy_code.declare(&mut deploy_context)?;
                 deploy_code
                     .into_llvm(&mut deploy_context)
                     .map_err(|error| {
-                        anyhow::anyhow!("{deploy_code_segment} code LLVM IR generator: {error}")
+                        LocatedError::map(error, |error| {
+                            anyhow::anyhow!("{deploy_code_segment} code LLVM IR generator: {error}")
+                        })
                     })?;
+                Statistics::add_time(&mut statistics.llvm_ir_generation_time, ir_generation_start);
+                Self::check_evm_version(deploy_context.module(), evm_version)?;
+                let llvm_build_start = Instant::now();
                 let (deploy_buffer, deploy_code_errors) = deploy_context.build()?;
+                Statistics::add_time(&mut statistics.llvm_build_time, llvm_build_start);
                 let deploy_object = EVMContractObject::new(
                     deploy_code_identifier,
                     self.name.clone(),
@@ -180,6 +218,9 @@ impl Contract {
                     deploy_code_segment,
                     deploy_code_dependecies,
                     deploy_code_libraries,
+                    deploy_code_data,
+                    BTreeMap::new(),
+                    SourceMap::get_locations(&deploy_buffer),
                     deploy_code_errors,
                 );
 
@@ -189,6 +230,7 @@ impl Contract {
                     runtime_object,
                     metadata_hash,
                     metadata_string,
+                    statistics,
                 ))
             }
             IR::EVMLA(mut deploy_code) => {
@@ -228,13 +270,23 @@ impl Contract {
                     debug_config.clone(),
                 );
                 runtime_context.set_evmla_data(evmla_data.clone());
+                let ir_generation_start = Instant::now();
                 runtime_code_assembly.declare(&mut runtime_context)?;
                 runtime_code_assembly
-                    .into_llvm(&mut runtime_context)
+                    .into_llvm_with_statistics(&mut runtime_context, &mut statistics)
                     .map_err(|error| {
-                        anyhow::anyhow!("{runtime_code_segment} code LLVM IR generator: {error}")
+                        LocatedError::map(error, |error| {
+                            anyhow::anyhow!(
+                                "{runtime_code_segment} code LLVM IR generator: {error}"
+                            )
+                        })
                     })?;
+                Statistics::add_time(&mut statistics.llvm_ir_generation_time, ir_generation_start);
+                Self::check_evm_version(runtime_context.module(), evm_version)?;
+                let llvm_build_start = Instant::now();
                 let (runtime_buffer, runtime_code_errors) = runtime_context.build()?;
+                Statistics::add_time(&mut statistics.llvm_build_time, llvm_build_start);
+                let immutables_map = runtime_buffer.get_immutables_evm();
                 let runtime_object = EVMContractObject::new(
                     runtime_code_identifier,
                     self.name.clone(),
@@ -243,11 +295,12 @@ impl Contract {
                     runtime_code_segment,
                     runtime_code_dependecies,
                     runtime_code_libraries,
+                    BTreeMap::new(),
+                    immutables_map.clone(),
+                    SourceMap::get_locations(&runtime_buffer),
                     runtime_code_errors,
                 );
 
-                let immutables_map = runtime_buffer.get_immutables_evm();
-
                 let deploy_llvm = inkwell::context::Context::create();
                 let deploy_module = deploy_llvm.create_module(deploy_code_identifier.as_str());
                 let mut deploy_context = era_compiler_llvm_context::EVMContext::new(
@@ -262,13 +315,21 @@ impl Contract {
                     era_compiler_llvm_context::EVMContextSolidityData::new(immutables_map),
                 );
                 deploy_context.set_evmla_data(evmla_data);
+                let ir_generation_start = Instant::now();
                 deploy_code.declare(&mut deploy_context)?;
                 deploy_code
-                    .into_llvm(&mut deploy_context)
+                    .assembly
+                    .into_llvm_with_statistics(&mut deploy_context, &mut statistics)
                     .map_err(|error| {
-                        anyhow::anyhow!("{deploy_code_segment} code LLVM IR generator: {error}")
+                        LocatedError::map(error, |error| {
+                            anyhow::anyhow!("{deploy_code_segment} code LLVM IR generator: {error}")
+                        })
                     })?;
+                Statistics::add_time(&mut statistics.llvm_ir_generation_time, ir_generation_start);
+                Self::check_evm_version(deploy_context.module(), evm_version)?;
+                let llvm_build_start = Instant::now();
                 let (deploy_buffer, deploy_code_errors) = deploy_context.build()?;
+                Statistics::add_time(&mut statistics.llvm_build_time, llvm_build_start);
                 let deploy_object = EVMContractObject::new(
                     deploy_code_identifier,
                     self.name.clone(),
@@ -277,6 +338,9 @@ impl Contract {
                     deploy_code_segment,
                     deploy_code_dependecies,
                     deploy_code_libraries,
+                    BTreeMap::new(),
+                    BTreeMap::new(),
+                    SourceMap::get_locations(&deploy_buffer),
                     deploy_code_errors,
                 );
 
@@ -286,9 +350,113 @@ impl Contract {
                     runtime_object,
                     metadata_hash,
                     metadata_string,
+                    statistics,
+                ))
+            }
+            IR::LLVMIR(mut deploy_code) => {
+                let runtime_code = deploy_code.take_runtime_code().ok_or_else(|| {
+                    anyhow::anyhow!("Contract `{identifier}` has no runtime code")
+                })?;
+
+                let deploy_code_segment = era_compiler_common::CodeSegment::Deploy;
+                let runtime_code_segment = era_compiler_common::CodeSegment::Runtime;
+
+                let deploy_code_dependecies = deploy_code.get_evm_dependencies(Some(&runtime_code));
+                let runtime_code_dependecies = runtime_code.get_evm_dependencies(None);
+
+                let runtime_llvm = inkwell::context::Context::create();
+                let runtime_memory_buffer =
+                    inkwell::memory_buffer::MemoryBuffer::create_from_memory_range_copy(
+                        runtime_code.source.as_bytes(),
+                        runtime_code.identifier.as_str(),
+                    );
+                let runtime_module = runtime_llvm
+                    .create_module_from_ir(runtime_memory_buffer)
+                    .map_err(|error| {
+                        anyhow::anyhow!(
+                            "{runtime_code_segment} code LLVM IR parsing: {}",
+                            error.to_string()
+                        )
+                    })?;
+                let runtime_context = era_compiler_llvm_context::EVMContext::new(
+                    &runtime_llvm,
+                    runtime_module,
+                    llvm_options.clone(),
+                    runtime_code_segment,
+                    optimizer.clone(),
+                    debug_config.clone(),
+                );
+                Self::check_evm_version(runtime_context.module(), evm_version)?;
+                let llvm_build_start = Instant::now();
+                let (runtime_buffer, runtime_code_errors) = runtime_context.build()?;
+                Statistics::add_time(&mut statistics.llvm_build_time, llvm_build_start);
+                let immutables_map = runtime_buffer.get_immutables_evm();
+                let runtime_object = EVMContractObject::new(
+                    runtime_code.identifier,
+                    self.name.clone(),
+                    runtime_buffer.as_slice().to_owned(),
+                    false,
+                    runtime_code_segment,
+                    runtime_code_dependecies,
+                    BTreeSet::new(),
+                    BTreeMap::new(),
+                    immutables_map.clone(),
+                    SourceMap::get_locations(&runtime_buffer),
+                    runtime_code_errors,
+                );
+
+                let deploy_llvm = inkwell::context::Context::create();
+                let deploy_memory_buffer =
+                    inkwell::memory_buffer::MemoryBuffer::create_from_memory_range_copy(
+                        deploy_code.source.as_bytes(),
+                        deploy_code.identifier.as_str(),
+                    );
+                let deploy_module = deploy_llvm
+                    .create_module_from_ir(deploy_memory_buffer)
+                    .map_err(|error| {
+                        anyhow::anyhow!(
+                            "{deploy_code_segment} code LLVM IR parsing: {}",
+                            error.to_string()
+                        )
+                    })?;
+                let mut deploy_context = era_compiler_llvm_context::EVMContext::new(
+                    &deploy_llvm,
+                    deploy_module,
+                    llvm_options.clone(),
+                    deploy_code_segment,
+                    optimizer.clone(),
+                    debug_config.clone(),
+                );
+                deploy_context.set_solidity_data(
+                    era_compiler_llvm_context::EVMContextSolidityData::new(immutables_map),
+                );
+                Self::check_evm_version(deploy_context.module(), evm_version)?;
+                let llvm_build_start = Instant::now();
+                let (deploy_buffer, deploy_code_errors) = deploy_context.build()?;
+                Statistics::add_time(&mut statistics.llvm_build_time, llvm_build_start);
+                let deploy_object = EVMContractObject::new(
+                    deploy_code.identifier,
+                    self.name.clone(),
+                    deploy_buffer.as_slice().to_owned(),
+                    false,
+                    deploy_code_segment,
+                    deploy_code_dependecies,
+                    BTreeSet::new(),
+                    BTreeMap::new(),
+                    BTreeMap::new(),
+                    SourceMap::get_locations(&deploy_buffer),
+                    deploy_code_errors,
+                );
+
+                Ok(EVMContractBuild::new(
+                    self.name,
+                    deploy_object,
+                    runtime_object,
+                    metadata_hash,
+                    metadata_string,
+                    statistics,
                 ))
             }
-            IR::LLVMIR(_llvm_ir) => anyhow::bail!("LLVM IR is not supported yet."),
         }
     }
 
@@ -305,4 +473,55 @@ impl Contract {
             .filter(|library| !deployed_libraries.contains(library))
             .collect::<BTreeSet<String>>()
     }
+
+    ///
+    /// Checks that the instructions used in the `module` are available in the target EVM version.
+    ///
+    /// The check is performed on the LLVM IR intrinsics, so it covers all the input languages.
+    /// The error names the function using the instruction, and is located at the contract file
+    /// like the other backend errors.
+    ///
+    fn check_evm_version(
+        module: &inkwell::module::Module,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+    ) -> anyhow::Result<()> {
+        let evm_version = match evm_version {
+            Some(evm_version) => evm_version,
+            None => return Ok(()),
+        };
+
+        if evm_version < era_compiler_common::EVMVersion::Cancun {
+            for (intrinsic, instruction) in Self::CANCUN_INTRINSICS.into_iter() {
+                let intrinsic = match module.get_function(intrinsic) {
+                    Some(intrinsic) => intrinsic,
+                    None => continue,
+                };
+
+                let mut user_names = BTreeSet::new();
+                let mut intrinsic_use = intrinsic.get_first_use();
+                while let Some(r#use) = intrinsic_use {
+                    if let inkwell::values::AnyValueEnum::InstructionValue(user) = r#use.get_user()
+                    {
+                        if let Some(function) =
+                            user.get_parent().and_then(|block| block.get_parent())
+                        {
+                            user_names.insert(function.get_name().to_string_lossy().to_string());
+                        }
+                    }
+                    intrinsic_use = r#use.get_next_use();
+                }
+                let user_name = match user_names.into_iter().next() {
+                    Some(user_name) => user_name,
+                    None => continue,
+                };
+
+                anyhow::bail!(
+                    "The `{instruction}` instruction used in function `{user_name}` is only available since EVM version `{}`, but `{evm_version}` is targeted",
+                    era_compiler_common::EVMVersion::Cancun,
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -6,15 +6,20 @@ pub mod contract;
 
 use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::time::Instant;
 
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 
 use crate::build_evm::contract::Contract as EVMContractBuild;
 use crate::build_evm::Build as EVMBuild;
+use crate::cache::Cache;
 use crate::evmla::assembly::Assembly;
 use crate::process::input_evm::Input as EVMProcessInput;
+use crate::process::limits::Limits as ProcessLimits;
 use crate::process::output_evm::Output as EVMOutput;
+use crate::statistics::contract::Contract as ContractStatistics;
+use crate::statistics::Statistics;
 
 use self::contract::ir::evmla::EVMLA as ContractEVMLA;
 use self::contract::ir::llvm_ir::LLVMIR as ContractLLVMIR;
@@ -75,6 +80,12 @@ impl Project {
             Assembly::preprocess_dependencies(&mut solc_output.contracts)?;
         }
 
+        let source_paths = solc_output
+            .sources
+            .iter()
+            .map(|(path, source)| (source.id, path.to_owned()))
+            .collect::<BTreeMap<usize, String>>();
+
         let mut input_contracts = Vec::with_capacity(solc_output.contracts.len());
         for (path, file) in solc_output.contracts.iter() {
             for (name, contract) in file.iter() {
@@ -89,6 +100,7 @@ impl Project {
         let results = input_contracts
             .into_par_iter()
             .filter_map(|(name, contract)| {
+                let parsing_start = Instant::now();
                 let result = if via_ir {
                     ContractYul::try_from_source(
                         name.full_path.as_str(),
@@ -97,13 +109,15 @@ impl Project {
                     )
                     .map(|yul| yul.map(ContractIR::from))
                 } else {
-                    Ok(ContractEVMLA::try_from_contract(contract).map(ContractIR::from))
+                    Ok(ContractEVMLA::try_from_contract(contract, &source_paths)
+                        .map(ContractIR::from))
                 };
                 let ir = match result {
                     Ok(ir) => ir?,
                     Err(error) => return Some((name.full_path, Err(error))),
                 };
-                let contract = Contract::new(name.clone(), ir, contract.metadata.clone());
+                let mut contract = Contract::new(name.clone(), ir, contract.metadata.clone());
+                Statistics::add_time(&mut contract.parsing_time, parsing_start);
                 Some((name.full_path, Ok(contract)))
             })
             .collect::<BTreeMap<String, anyhow::Result<Contract>>>();
@@ -155,6 +169,7 @@ impl Project {
         let results = sources
             .into_par_iter()
             .filter_map(|(path, mut source)| {
+                let parsing_start = Instant::now();
                 let source_code = match source.try_resolve() {
                     Ok(()) => source.take_content().expect("Always exists"),
                     Err(error) => return Some((path, Err(error))),
@@ -181,7 +196,8 @@ impl Project {
                     Some(ir.object.0.identifier.clone()),
                 );
                 let full_path = name.full_path.clone();
-                let contract = Contract::new(name, ir.into(), source_metadata);
+                let mut contract = Contract::new(name, ir.into(), source_metadata);
+                Statistics::add_time(&mut contract.parsing_time, parsing_start);
                 Some((full_path, Ok(contract)))
             })
             .collect::<BTreeMap<String, anyhow::Result<Contract>>>();
@@ -231,31 +247,76 @@ impl Project {
         libraries: solx_solc::StandardJsonInputLibraries,
         mut solc_output: Option<&mut solx_solc::StandardJsonOutput>,
     ) -> anyhow::Result<Self> {
-        let results = sources
+        let modules = sources
             .into_par_iter()
             .map(|(path, mut source)| {
                 let source_code = match source.try_resolve() {
                     Ok(()) => source.take_content().expect("Always exists"),
                     Err(error) => return (path, Err(error)),
                 };
+                let module = ContractLLVMIR::try_from_source(path.clone(), source_code);
+                (path, module)
+            })
+            .collect::<BTreeMap<String, anyhow::Result<ContractLLVMIR>>>();
 
-                let source_hash = era_compiler_common::Hash::keccak256(source_code.as_bytes());
-                let source_metadata_json = serde_json::json!({
-                    "source_hash": source_hash.to_string(),
-                    "llvm_version": era_compiler_llvm_context::LLVM_VERSION,
-                });
-                let source_metadata =
-                    serde_json::to_string(&source_metadata_json).expect("Always valid");
+        let mut results = BTreeMap::new();
+        let mut deploy_modules = Vec::with_capacity(modules.len());
+        let mut runtime_modules = BTreeMap::new();
+        for (path, module) in modules.into_iter() {
+            match module {
+                Ok(module) if module.is_runtime_code() => {
+                    runtime_modules.insert(module.identifier.clone(), module);
+                }
+                Ok(module) => deploy_modules.push(module),
+                Err(error) => {
+                    results.insert(path, Err(error));
+                }
+            }
+        }
 
-                let contract = Contract::new(
-                    era_compiler_common::ContractName::new(path.clone(), None),
-                    ContractLLVMIR::new(path.clone(), source_code).into(),
-                    source_metadata,
-                );
+        for mut deploy_code in deploy_modules.into_iter() {
+            let path = deploy_code.path.clone();
+            let runtime_code_identifier = deploy_code.runtime_code_identifier();
+            let runtime_code = match runtime_modules.remove(runtime_code_identifier.as_str()) {
+                Some(runtime_code) => runtime_code,
+                None => {
+                    results.insert(
+                        path,
+                        Err(anyhow::anyhow!(
+                            "Runtime code module `{runtime_code_identifier}` of `{}` is missing.",
+                            deploy_code.identifier
+                        )),
+                    );
+                    continue;
+                }
+            };
 
-                (path, Ok(contract))
-            })
-            .collect::<BTreeMap<String, anyhow::Result<Contract>>>();
+            let mut source_code = deploy_code.source.clone();
+            source_code.push_str(runtime_code.source.as_str());
+            let source_hash = era_compiler_common::Hash::keccak256(source_code.as_bytes());
+            let source_metadata_json = serde_json::json!({
+                "source_hash": source_hash.to_string(),
+                "llvm_version": era_compiler_llvm_context::LLVM_VERSION,
+            });
+            let source_metadata =
+                serde_json::to_string(&source_metadata_json).expect("Always valid");
+
+            deploy_code.runtime_code = Some(Box::new(runtime_code));
+            let contract = Contract::new(
+                era_compiler_common::ContractName::new(path.clone(), None),
+                deploy_code.into(),
+                source_metadata,
+            );
+            results.insert(path, Ok(contract));
+        }
+        for (identifier, runtime_code) in runtime_modules.into_iter() {
+            results.insert(
+                runtime_code.path,
+                Err(anyhow::anyhow!(
+                    "Runtime code module `{identifier}` has no corresponding deploy code module."
+                )),
+            );
+        }
 
         let mut contracts = BTreeMap::new();
         for (path, result) in results.into_iter() {
@@ -279,31 +340,74 @@ impl Project {
     ///
     /// Compiles all contracts to EVM, returning their build artifacts.
     ///
+    /// If `cache` is provided, contracts whose builds are already cached are not recompiled,
+    /// and the successful builds are stored for subsequent runs.
+    ///
+    /// If `in_process` is set, the contracts are compiled on the thread pool of the current
+    /// process. Otherwise, each contract is compiled in a subprocess, which isolates
+    /// the backend crashes at the cost of the process startup time. The subprocesses are
+    /// constrained by `process_limits`.
+    ///
+    /// The compilation time is recorded in the build statistics. The contracts taken from
+    /// the cache only report their parsing time.
+    ///
     pub fn compile_to_evm(
         self,
         messages: &mut Vec<solx_solc::StandardJsonOutputError>,
+        evm_version: Option<era_compiler_common::EVMVersion>,
         metadata_hash_type: era_compiler_common::HashType,
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+        cache: Option<&Cache>,
+        in_process: bool,
+        process_limits: ProcessLimits,
     ) -> anyhow::Result<EVMBuild> {
+        let compilation_start = Instant::now();
         let deployed_libraries = self.libraries.as_paths();
         let results = self.contracts.into_par_iter().map(|(path, contract)| {
+            let parsing_time = contract.parsing_time;
             let input = EVMProcessInput::new(
                 contract,
                 self.identifier_paths.clone(),
                 deployed_libraries.clone(),
+                evm_version,
                 metadata_hash_type,
                 optimizer_settings.clone(),
                 llvm_options.clone(),
                 debug_config.clone(),
             );
-            let result: crate::Result<EVMOutput> =
-                crate::process::call(path.as_str(), input);
+
+            // The debug output is produced as a side effect of compilation, so it is bypassed.
+            let cache = cache.filter(|_| input.debug_config.is_none());
+            let key = cache.map(|_| Cache::key(&input, &self.solc_version));
+            if let (Some(cache), Some(key)) = (cache, key.as_deref()) {
+                if let Some(mut build) = cache.get(key) {
+                    build.statistics = ContractStatistics::default();
+                    build.statistics.parsing_time = parsing_time;
+                    return (path, Ok(build));
+                }
+            }
+
+            let result: crate::Result<EVMOutput> = if in_process {
+                crate::process::compile(input)
+            } else {
+                crate::process::call(path.as_str(), input, &process_limits)
+            };
             let result = result.map(|output| output.build);
+            if let (Some(cache), Some(key), Ok(build)) = (cache, key.as_deref(), result.as_ref()) {
+                // The cache is best-effort, so a failure to store an entry must not fail the build.
+                let _ = cache.put(key, build);
+            }
+            let result = result.map(|mut build| {
+                build.statistics.parsing_time = parsing_time;
+                build
+            });
             (path, result)
         }).collect::<BTreeMap<String, Result<EVMContractBuild, solx_solc::StandardJsonOutputError>>>();
 
-        Ok(EVMBuild::new(results, messages))
+        let mut build = EVMBuild::new(results, messages);
+        Statistics::add_time(&mut build.statistics.compilation_time, compilation_start);
+        Ok(build)
     }
 }
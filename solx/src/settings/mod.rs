@@ -0,0 +1,52 @@
+//!
+//! The compilation settings shared by the EVM compilation modes.
+//!
+
+use std::path::PathBuf;
+
+use crate::process::limits::Limits as ProcessLimits;
+
+///
+/// The compilation settings shared by the EVM compilation modes.
+///
+/// The settings do not affect the bytecode, but control how the contracts are compiled
+/// and how the diagnostics are reported.
+///
+#[derive(Debug, Default)]
+pub struct Settings<'a> {
+    /// The cache directory.
+    pub cache_dir: Option<PathBuf>,
+    /// Whether to compile the contracts on the thread pool of the current process.
+    pub in_process: bool,
+    /// The resource limits of the compilation subprocesses.
+    pub process_limits: ProcessLimits,
+    /// Whether exceeding the bytecode size limits is an error.
+    pub deny_size_limits: bool,
+    /// The warning suppression and promotion settings.
+    pub warning_settings: solx_solc::StandardJsonInputWarnings,
+    /// The diagnostics sink. If unset, the diagnostics are written to the terminal.
+    pub diagnostics: Option<&'a mut solx_solc::CollectedDiagnostics>,
+}
+
+impl<'a> Settings<'a> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        cache_dir: Option<PathBuf>,
+        in_process: bool,
+        process_limits: ProcessLimits,
+        deny_size_limits: bool,
+        warning_settings: solx_solc::StandardJsonInputWarnings,
+        diagnostics: Option<&'a mut solx_solc::CollectedDiagnostics>,
+    ) -> Self {
+        Self {
+            cache_dir,
+            in_process,
+            process_limits,
+            deny_size_limits,
+            warning_settings,
+            diagnostics,
+        }
+    }
+}
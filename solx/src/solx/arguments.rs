@@ -5,10 +5,13 @@
 use std::collections::BTreeSet;
 use std::path::Path;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use clap::Parser;
 use path_slash::PathExt;
 
+use crate::subcommand::Subcommand;
+
 ///
 /// Compiles the provided Solidity input files (or use the standard input if no files
 /// are given or "-" is specified as a file name). Outputs the components based on the
@@ -86,6 +89,21 @@ pub struct Arguments {
     #[arg(short, long)]
     pub threads: Option<usize>,
 
+    /// Compile the contracts in threads of the current process instead of child processes.
+    /// Avoids the process startup overhead on small projects, but a crash of the backend aborts the whole compilation.
+    #[arg(long)]
+    pub in_process: bool,
+
+    /// Set the wall-clock time limit in seconds for compiling each contract in its child process.
+    /// A contract exceeding the limit is reported as an error.
+    #[arg(long)]
+    pub contract_timeout: Option<f64>,
+
+    /// Set the address space limit in mebibytes for compiling each contract in its child process.
+    /// A contract exceeding the limit is reported as an error. Only supported on Unix platforms.
+    #[arg(long)]
+    pub contract_memory_limit: Option<u64>,
+
     /// Switch to Yul mode.
     /// Only one input Yul file is allowed.
     /// Cannot be used with standard JSON mode.
@@ -93,9 +111,10 @@ pub struct Arguments {
     pub yul: bool,
 
     /// Switch to LLVM IR mode.
-    /// Only one input LLVM IR file is allowed.
+    /// Each contract consists of a deploy code module and a runtime code module,
+    /// whose `source_filename` is that of the deploy code module with the `.runtime` suffix.
     /// Cannot be used with standard JSON mode.
-    /// Use this mode at your own risk, as LLVM IR input validation is not implemented.
+    /// Use this mode at your own risk, as only basic LLVM IR input validation is implemented.
     #[arg(long)]
     pub llvm_ir: bool,
 
@@ -124,10 +143,107 @@ pub struct Arguments {
     #[arg(long = "metadata")]
     pub output_metadata: bool,
 
-    /// Output bytecode of the compiled contracts.
+    /// Output the disassembly of the compiled contracts' final bytecode.
+    #[arg(long = "asm")]
+    pub output_assembly: bool,
+
+    /// Output the deploy bytecode of the compiled contracts.
     #[arg(long = "bin")]
     pub output_binary: bool,
 
+    /// Output the runtime bytecode of the compiled contracts.
+    #[arg(long = "bin-runtime")]
+    pub output_binary_runtime: bool,
+
+    /// Output the ABI specification of the compiled contracts.
+    #[arg(long = "abi")]
+    pub output_abi: bool,
+
+    /// Output the function signature hashes of the compiled contracts.
+    #[arg(long = "hashes")]
+    pub output_hashes: bool,
+
+    /// Output the storage layout of the compiled contracts.
+    #[arg(long = "storage-layout")]
+    pub output_storage_layout: bool,
+
+    /// Output the user documentation of the compiled contracts.
+    #[arg(long = "userdoc")]
+    pub output_userdoc: bool,
+
+    /// Output the developer documentation of the compiled contracts.
+    #[arg(long = "devdoc")]
+    pub output_devdoc: bool,
+
+    /// Output a single JSON document with the comma-separated list of artifacts, compatible with `solc --combined-json`.
+    /// Available artifacts: `abi`, `bin`, `bin-runtime`, `metadata`, `hashes`, `storage-layout`, `userdoc`, `devdoc`.
+    /// Example: `--combined-json abi,bin,bin-runtime`.
+    #[arg(long)]
+    pub combined_json: Option<String>,
+
+    /// Keep running and recompile the project every time one of its source files changes.
+    /// Only the contracts affected by the change are recompiled.
+    /// Only available in Solidity mode.
+    #[arg(long)]
+    pub watch: bool,
+
+    /// Print the JSON report with the compilation phase timings, EthIR statistics, and bytecode sizes
+    /// of each contract to stderr.
+    /// In standard JSON mode, use the `settings.solxStatistics` input field instead.
+    #[arg(long)]
+    pub time_report: bool,
+
+    /// Print the deploy, runtime, and metadata size of each contract to stderr, along with the headroom
+    /// until the EIP-3860 initcode and EIP-170 runtime code size limits.
+    #[arg(long)]
+    pub size_report: bool,
+
+    /// Report the contracts exceeding the EIP-3860 initcode or EIP-170 runtime code size limits
+    /// as errors instead of warnings.
+    #[arg(long)]
+    pub deny_size_limits: bool,
+
+    /// Disable a solx-specific AST lint. Can be specified multiple times.
+    /// Available lints: `selfdestruct`, `callcode`, `assembly-pc`, `tx-origin`, `extcodesize-check`,
+    /// `internal-function-pointer`.
+    /// In standard JSON mode, the lints must be disabled via the `settings.solxDisabledLints` field.
+    #[arg(long)]
+    pub disable_lint: Vec<solx_solc::StandardJsonOutputSourceLint>,
+
+    /// Remove the warnings matching the filter from the output. Can be specified multiple times.
+    /// The filter format is `<code>`, `<code>:<path glob>`, or `*:<path glob>`.
+    /// Example: `--ignore-warning 2072:lib/**`.
+    #[arg(long)]
+    pub ignore_warning: Vec<solx_solc::StandardJsonInputWarningsFilter>,
+
+    /// Keep the warnings matching the filter as warnings, even if they are denied.
+    /// Can be specified multiple times. The filter format is the same as in `--ignore-warning`.
+    #[arg(long)]
+    pub allow_warning: Vec<solx_solc::StandardJsonInputWarningsFilter>,
+
+    /// Report the warnings matching the filter as errors. Can be specified multiple times.
+    /// The filter format is the same as in `--ignore-warning`.
+    #[arg(long)]
+    pub deny_warning: Vec<solx_solc::StandardJsonInputWarningsFilter>,
+
+    /// Report all warnings that are not allowed with `--allow-warning` as errors.
+    /// In standard JSON mode, the warning settings must be specified via the `settings.solxWarnings` field.
+    #[arg(long)]
+    pub deny_warnings: bool,
+
+    /// Set the diagnostics output format.
+    /// Available formats: `human`, `json`, `sarif`.
+    /// The default is `human`. With `json` and `sarif`, all errors and warnings are written to stderr
+    /// as a single JSON document at the end of the compilation.
+    #[arg(long)]
+    pub diagnostics_format: Option<solx::DiagnosticsFormat>,
+
+    /// Cache contract builds in the specified directory and reuse them in subsequent runs.
+    /// A contract is recompiled only if its IR, the settings, or the compiler versions change.
+    /// In standard JSON mode, the directory must be specified via the `settings.cacheDir` field.
+    #[arg(long)]
+    pub cache_dir: Option<PathBuf>,
+
     /// Dump all IRs to files in the specified directory.
     /// Only for testing and debugging.
     #[arg(long)]
@@ -147,6 +263,10 @@ pub struct Arguments {
     /// Only for usage from within the compiler.
     #[arg(long)]
     pub recursive_process: bool,
+
+    /// The subcommand to run instead of the compilation.
+    #[command(subcommand)]
+    pub subcommand: Option<Subcommand>,
 }
 
 impl Arguments {
@@ -165,6 +285,44 @@ impl Arguments {
             ));
         }
 
+        if self.subcommand.is_some() && std::env::args().count() > 2 {
+            messages.push(solx_solc::StandardJsonOutputError::new_error(
+                None,
+                "No other options are allowed in LSP mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.in_process
+            && (self.contract_timeout.is_some() || self.contract_memory_limit.is_some())
+        {
+            messages.push(solx_solc::StandardJsonOutputError::new_error(
+                None,
+                "Contract time and memory limits cannot be used together with in-process compilation.",
+                None,
+                None,
+            ));
+        }
+        if let Some(contract_timeout) = self.contract_timeout {
+            if !contract_timeout.is_finite() || contract_timeout <= 0.0 {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None,
+                    "Contract timeout must be a positive number of seconds.",
+                    None,
+                    None,
+                ));
+            }
+        }
+        if self.contract_memory_limit.is_some() && !cfg!(unix) {
+            messages.push(solx_solc::StandardJsonOutputError::new_error(
+                None,
+                "Contract memory limit is only supported on Unix platforms.",
+                None,
+                None,
+            ));
+        }
+
         let modes_count = [
             self.yul,
             self.llvm_ir,
@@ -222,6 +380,44 @@ impl Arguments {
                     None,
                 ));
             }
+
+            if self.output_abi
+                || self.output_hashes
+                || self.output_storage_layout
+                || self.output_userdoc
+                || self.output_devdoc
+            {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None,
+                    "ABI, hashes, storage layout, and documentation are only available in Solidity mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.combined_json.is_some() {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None,
+                    "Combined JSON is only available in Solidity mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.watch {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None,
+                    "Watch mode is only available in Solidity mode.",
+                    None,
+                    None,
+                ));
+            }
+            if !self.disable_lint.is_empty() {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None,
+                    "Lints are only available in Solidity mode.",
+                    None,
+                    None,
+                ));
+            }
         }
 
         if self.llvm_ir && !self.libraries.is_empty() {
@@ -248,8 +444,54 @@ impl Arguments {
             ));
         }
 
+        if self.combined_json.is_some() && self.output_selection().has_selectors() {
+            messages.push(solx_solc::StandardJsonOutputError::new_error(
+                None,
+                "Combined JSON cannot be used together with other output flags.",
+                None,
+                None,
+            ));
+        }
+
+        if self.watch && self.combined_json.is_some() {
+            messages.push(solx_solc::StandardJsonOutputError::new_error(
+                None,
+                "Watch mode cannot be used with combined JSON.",
+                None,
+                None,
+            ));
+        }
+        if self.watch && self.time_report {
+            messages.push(solx_solc::StandardJsonOutputError::new_error(
+                None,
+                "Watch mode cannot be used with the time report.",
+                None,
+                None,
+            ));
+        }
+        if self.watch && self.size_report {
+            messages.push(solx_solc::StandardJsonOutputError::new_error(
+                None,
+                "Watch mode cannot be used with the size report.",
+                None,
+                None,
+            ));
+        }
+        if self.watch
+            && self
+                .diagnostics_format
+                .is_some_and(|format| format != solx::DiagnosticsFormat::Human)
+        {
+            messages.push(solx_solc::StandardJsonOutputError::new_error(
+                None,
+                "Watch mode can only be used with the human-readable diagnostics format.",
+                None,
+                None,
+            ));
+        }
+
         if self.standard_json.is_some() {
-            if self.output_metadata || self.output_binary {
+            if self.output_selection().has_selectors() || self.combined_json.is_some() {
                 messages.push(solx_solc::StandardJsonOutputError::new_error(
                     None,
                     "Cannot output data outside of JSON in standard JSON mode.",
@@ -348,11 +590,134 @@ impl Arguments {
                     None,
                 ));
             }
+            if self.watch {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None,
+                    "Watch mode cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.time_report {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None,
+                    "Time report must be requested with `settings.solxStatistics` in standard JSON input.",
+                    None,
+                    None,
+                ));
+            }
+            if self.size_report {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None,
+                    "Size report cannot be used in standard JSON mode. The bytecode sizes are available in the `settings.solxStatistics` report.",
+                    None,
+                    None,
+                ));
+            }
+            if self.cache_dir.is_some() {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None,
+                    "Cache directory must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
+            if !self.disable_lint.is_empty() {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None,
+                    "Lints must be disabled in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
+            if self.diagnostics_format.is_some() {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None,
+                    "Diagnostics format cannot be used in standard JSON mode. The diagnostics are a part of the standard JSON output.",
+                    None,
+                    None,
+                ));
+            }
+            if !self.warning_settings().is_empty() {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None,
+                    "Warning settings must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
         }
 
         messages
     }
 
+    ///
+    /// Returns the warning suppression and promotion settings.
+    ///
+    pub fn warning_settings(&self) -> solx_solc::StandardJsonInputWarnings {
+        solx_solc::StandardJsonInputWarnings::new(
+            self.ignore_warning.clone(),
+            self.allow_warning.clone(),
+            self.deny_warning.clone(),
+            self.deny_warnings,
+        )
+    }
+
+    ///
+    /// Returns the resource limits of the compilation subprocesses.
+    ///
+    pub fn process_limits(&self) -> solx::ProcessLimits {
+        solx::ProcessLimits::new(
+            self.contract_timeout.map(Duration::from_secs_f64),
+            self.contract_memory_limit,
+        )
+    }
+
+    ///
+    /// Returns the output selection requested via the output flags.
+    ///
+    pub fn output_selection(&self) -> solx_solc::StandardJsonInputSelection {
+        let selectors = [
+            (
+                self.output_metadata,
+                solx_solc::StandardJsonInputSelector::Metadata,
+            ),
+            (
+                self.output_assembly,
+                solx_solc::StandardJsonInputSelector::Assembly,
+            ),
+            (
+                self.output_binary,
+                solx_solc::StandardJsonInputSelector::BytecodeObject,
+            ),
+            (
+                self.output_binary_runtime,
+                solx_solc::StandardJsonInputSelector::RuntimeBytecodeObject,
+            ),
+            (self.output_abi, solx_solc::StandardJsonInputSelector::ABI),
+            (
+                self.output_hashes,
+                solx_solc::StandardJsonInputSelector::MethodIdentifiers,
+            ),
+            (
+                self.output_storage_layout,
+                solx_solc::StandardJsonInputSelector::StorageLayout,
+            ),
+            (
+                self.output_userdoc,
+                solx_solc::StandardJsonInputSelector::Userdoc,
+            ),
+            (
+                self.output_devdoc,
+                solx_solc::StandardJsonInputSelector::Devdoc,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(is_requested, selector)| is_requested.then_some(selector))
+        .collect();
+        solx_solc::StandardJsonInputSelection::new_required(selectors)
+    }
+
     ///
     /// Returns remappings from input paths.
     ///
@@ -3,7 +3,9 @@
 //!
 
 pub mod arguments;
+pub mod subcommand;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::io::Write;
 use std::path::PathBuf;
@@ -11,6 +13,7 @@ use std::path::PathBuf;
 use clap::Parser;
 
 use self::arguments::Arguments;
+use self::subcommand::Subcommand;
 
 /// The rayon worker stack size.
 const RAYON_WORKER_STACK_SIZE: usize = 16 * 1024 * 1024;
@@ -21,24 +24,34 @@ const RAYON_WORKER_STACK_SIZE: usize = 16 * 1024 * 1024;
 fn main() -> anyhow::Result<()> {
     let arguments = Arguments::try_parse()?;
     let is_standard_json = arguments.standard_json.is_some();
+    let diagnostics_format = arguments.diagnostics_format.unwrap_or_default();
+    let mut collected = (diagnostics_format != solx::DiagnosticsFormat::Human)
+        .then(solx_solc::CollectedDiagnostics::default);
+    let warning_settings = arguments.warning_settings();
     let mut messages = arguments.validate();
+    warning_settings.apply(&mut messages);
     if messages.iter().all(|error| error.severity != "error") {
         if !is_standard_json {
-            std::io::stderr()
-                .write_all(
-                    messages
-                        .drain(..)
-                        .map(|error| error.to_string())
-                        .collect::<Vec<String>>()
-                        .join("\n")
-                        .as_bytes(),
-                )
-                .expect("Stderr writing error");
+            match collected.as_mut() {
+                Some(collected) => collected.messages.append(&mut messages),
+                None => std::io::stderr()
+                    .write_all(
+                        messages
+                            .drain(..)
+                            .map(|error| error.to_terminal_string())
+                            .collect::<Vec<String>>()
+                            .join("\n")
+                            .as_bytes(),
+                    )
+                    .expect("Stderr writing error"),
+            }
         }
-        if let Err(error) = main_inner(arguments, &mut messages) {
-            messages.push(solx_solc::StandardJsonOutputError::new_error(
-                None, error, None, None,
-            ));
+        if let Err(error) = main_inner(arguments, &mut messages, collected.as_mut()) {
+            if !error.is::<solx_solc::CollectedErrors>() {
+                messages.push(solx_solc::StandardJsonOutputError::new_error(
+                    None, error, None, None,
+                ));
+            }
         }
     }
 
@@ -47,20 +60,21 @@ fn main() -> anyhow::Result<()> {
         output.write_and_exit(BTreeSet::new());
     }
 
+    let sources = match collected {
+        Some(mut collected) => {
+            collected.messages.append(&mut messages);
+            messages = collected.messages;
+            collected.sources
+        }
+        None => BTreeMap::new(),
+    };
+    warning_settings.apply(&mut messages);
     let exit_code = if messages.iter().any(|error| error.severity == "error") {
         era_compiler_common::EXIT_CODE_FAILURE
     } else {
         era_compiler_common::EXIT_CODE_SUCCESS
     };
-    std::io::stderr()
-        .write_all(
-            messages
-                .into_iter()
-                .map(|error| error.to_string())
-                .collect::<Vec<String>>()
-                .join("\n")
-                .as_bytes(),
-        )
+    solx::Diagnostics::write_to_terminal(messages, &sources, diagnostics_format)
         .expect("Stderr writing error");
     std::process::exit(exit_code);
 }
@@ -71,6 +85,7 @@ fn main() -> anyhow::Result<()> {
 fn main_inner(
     arguments: Arguments,
     messages: &mut Vec<solx_solc::StandardJsonOutputError>,
+    mut collected: Option<&mut solx_solc::CollectedDiagnostics>,
 ) -> anyhow::Result<()> {
     if arguments.version {
         let solc = solx_solc::Compiler::default();
@@ -103,8 +118,22 @@ fn main_inner(
         return solx::run_recursive();
     }
 
+    if let Some(Subcommand::Lsp) = arguments.subcommand {
+        return solx::LspServer::default().run();
+    }
+
     let (input_files, remappings) = arguments.split_input_files_and_remappings()?;
 
+    if arguments.link {
+        if let Some(standard_json) = arguments.standard_json {
+            return solx::standard_json_link_evm(standard_json.map(PathBuf::from));
+        }
+
+        let output = solx::link_evm(input_files.as_slice(), arguments.libraries)?;
+        serde_json::to_writer(std::io::stdout(), &output)?;
+        return Ok(());
+    }
+
     let mut optimizer_settings = match arguments.optimization {
         Some(mode) => era_compiler_llvm_context::OptimizerSettings::try_from_cli(mode)?,
         None => era_compiler_llvm_context::OptimizerSettings::cycles(),
@@ -140,6 +169,25 @@ fn main_inner(
         .metadata_hash
         .unwrap_or(era_compiler_common::HashType::Keccak256);
 
+    let combined_json_selectors = arguments
+        .combined_json
+        .as_deref()
+        .map(solx_solc::CombinedJsonSelector::from_cli)
+        .transpose()?;
+    let output_selection = match combined_json_selectors {
+        Some(ref selectors) => solx_solc::StandardJsonInputSelection::new_required(
+            selectors.iter().copied().map(Into::into).collect(),
+        ),
+        None => arguments.output_selection(),
+    };
+    let settings = solx::CompilationSettings::new(
+        arguments.cache_dir,
+        arguments.in_process,
+        arguments.process_limits(),
+        arguments.deny_size_limits,
+        arguments.warning_settings(),
+        collected.as_deref_mut(),
+    );
     let build = if arguments.yul {
         solx::yul_to_evm(
             input_files.as_slice(),
@@ -149,6 +197,7 @@ fn main_inner(
             optimizer_settings,
             llvm_options,
             debug_config,
+            settings,
         )
     } else if arguments.llvm_ir {
         solx::llvm_ir_to_evm(
@@ -159,9 +208,8 @@ fn main_inner(
             optimizer_settings,
             llvm_options,
             debug_config,
+            settings,
         )
-    } else if arguments.link {
-        anyhow::bail!("The EVM target does not support linking yet.");
     } else if let Some(standard_json) = arguments.standard_json {
         return solx::standard_json_evm(
             arguments.via_ir,
@@ -171,6 +219,31 @@ fn main_inner(
             arguments.include_path,
             arguments.allow_paths,
             debug_config,
+            settings,
+        );
+    } else if arguments.watch {
+        return solx::watch_evm(
+            input_files.as_slice(),
+            solx::WatchSettings {
+                libraries: arguments.libraries,
+                evm_version: arguments.evm_version,
+                via_ir: arguments.via_ir,
+                output_selection,
+                output_directory: arguments.output_dir,
+                overwrite: arguments.overwrite,
+                metadata_hash_type,
+                use_literal_content: arguments.metadata_literal,
+                base_path: arguments.base_path,
+                include_paths: arguments.include_path,
+                allow_paths: arguments.allow_paths,
+                remappings,
+                disabled_lints: arguments.disable_lint.into_iter().collect(),
+                optimizer_settings,
+                llvm_options,
+                debug_config,
+                diagnostics_format: arguments.diagnostics_format.unwrap_or_default(),
+            },
+            settings,
         );
     } else {
         solx::standard_output_evm(
@@ -179,27 +252,53 @@ fn main_inner(
             messages,
             arguments.evm_version,
             arguments.via_ir,
+            output_selection.clone(),
             metadata_hash_type,
             arguments.metadata_literal,
             arguments.base_path,
             arguments.include_path,
             arguments.allow_paths,
             remappings,
+            arguments.disable_lint.into_iter().collect(),
             optimizer_settings,
             llvm_options,
             debug_config,
+            settings,
         )
     }?;
 
-    if let Some(output_directory) = arguments.output_dir {
+    if arguments.time_report {
+        build.statistics().write_to_terminal()?;
+    }
+    if arguments.size_report {
+        build.write_size_report()?;
+    }
+
+    if let Some(selectors) = combined_json_selectors {
+        let mut combined_json =
+            solx_solc::CombinedJson::new(solx_solc::Compiler::default().version.long);
+        build.write_to_combined_json(&selectors, &mut combined_json, collected.as_deref_mut())?;
+        match arguments.output_dir {
+            Some(output_directory) => {
+                combined_json.write_to_directory(&output_directory, arguments.overwrite)?;
+                if collected.is_none() {
+                    writeln!(
+                        std::io::stderr(),
+                        "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
+                    )?;
+                }
+            }
+            None => combined_json.write_to_terminal()?,
+        }
+    } else if let Some(output_directory) = arguments.output_dir {
         build.write_to_directory(
             &output_directory,
-            arguments.output_metadata,
-            arguments.output_binary,
+            &output_selection,
             arguments.overwrite,
+            collected,
         )?;
     } else {
-        build.write_to_terminal(arguments.output_metadata, arguments.output_binary)?;
+        build.write_to_terminal(&output_selection, collected)?;
     }
 
     Ok(())
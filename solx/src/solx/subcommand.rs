@@ -0,0 +1,13 @@
+//!
+//! Solidity compiler subcommands.
+//!
+
+///
+/// Solidity compiler subcommands.
+///
+#[derive(Debug, clap::Subcommand)]
+pub enum Subcommand {
+    /// Run the Language Server Protocol server over stdio.
+    /// Publishes the `solc` and `solx` diagnostics of the documents open in the editor.
+    Lsp,
+}
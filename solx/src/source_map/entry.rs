@@ -0,0 +1,94 @@
+//!
+//! The source map entry.
+//!
+
+use crate::source_map::jump::Jump;
+
+///
+/// The source map entry.
+///
+/// Describes the source code range a single bytecode instruction was generated from.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Entry {
+    /// The start offset in the source file.
+    pub start: isize,
+    /// The length of the source range.
+    pub length: isize,
+    /// The source file index, or `-1` if the instruction is compiler-generated.
+    pub file: isize,
+    /// The jump type.
+    pub jump: Jump,
+    /// The modifier depth.
+    pub modifier_depth: usize,
+}
+
+impl Default for Entry {
+    fn default() -> Self {
+        Self {
+            start: -1,
+            length: -1,
+            file: -1,
+            jump: Jump::Regular,
+            modifier_depth: 0,
+        }
+    }
+}
+
+impl Entry {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(start: isize, length: isize, file: isize, jump: Jump) -> Self {
+        Self {
+            start,
+            length,
+            file,
+            jump,
+            modifier_depth: 0,
+        }
+    }
+
+    ///
+    /// Writes the entry in the compressed form, omitting the fields equal to the `previous` entry.
+    ///
+    pub fn compress(&self, previous: Option<&Self>) -> String {
+        let fields = [
+            (
+                self.start.to_string(),
+                previous.map(|previous| previous.start == self.start),
+            ),
+            (
+                self.length.to_string(),
+                previous.map(|previous| previous.length == self.length),
+            ),
+            (
+                self.file.to_string(),
+                previous.map(|previous| previous.file == self.file),
+            ),
+            (
+                self.jump.to_string(),
+                previous.map(|previous| previous.jump == self.jump),
+            ),
+            (
+                self.modifier_depth.to_string(),
+                previous.map(|previous| previous.modifier_depth == self.modifier_depth),
+            ),
+        ];
+
+        let mut fields = fields
+            .into_iter()
+            .map(|(value, is_same)| {
+                if is_same.unwrap_or_default() {
+                    String::new()
+                } else {
+                    value
+                }
+            })
+            .collect::<Vec<String>>();
+        while fields.last().map(String::is_empty).unwrap_or_default() {
+            fields.pop();
+        }
+        fields.join(":")
+    }
+}
@@ -0,0 +1,27 @@
+//!
+//! The source map jump type.
+//!
+
+///
+/// The source map jump type.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Jump {
+    /// The jump into a function.
+    In,
+    /// The return from a function.
+    Out,
+    /// A regular jump or any other instruction.
+    #[default]
+    Regular,
+}
+
+impl std::fmt::Display for Jump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::In => write!(f, "i"),
+            Self::Out => write!(f, "o"),
+            Self::Regular => write!(f, "-"),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//!
+//! The `solc`-compatible source map.
+//!
+
+pub mod entry;
+pub mod jump;
+
+use crate::opcode::Opcode;
+
+use self::entry::Entry;
+use self::jump::Jump;
+
+///
+/// The `solc`-compatible source map.
+///
+/// Contains one entry per bytecode instruction, in the `s:l:f:j:m` format.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SourceMap {
+    /// The source map entries, one per instruction.
+    pub entries: Vec<Entry>,
+}
+
+impl SourceMap {
+    ///
+    /// Builds the source map from the final `bytecode` and the source locations emitted by LLVM.
+    ///
+    /// The `locations` are pairs of bytecode offsets and entries, sorted by offset. Each instruction
+    /// is assigned the last location starting at or before its offset, or an empty entry if there is
+    /// none. Like in `solc` source maps, there is one entry per instruction in the first `code_size`
+    /// bytes, whereas the data appended to the code, e.g. the runtime code in a deploy code object
+    /// or the metadata, is not mapped.
+    ///
+    /// LLVM does not emit jump types, so they are recovered from the bytecode. A `JUMP` followed by
+    /// a `JUMPDEST` whose offset is pushed in the same basic block is a call, as the offset is the
+    /// return address. A `JUMP` to a target not pushed right before it is a return.
+    ///
+    pub fn from_locations(bytecode: &[u8], code_size: usize, locations: &[(usize, Entry)]) -> Self {
+        let code_size = code_size.min(bytecode.len());
+
+        let mut entries = Vec::with_capacity(code_size);
+        let mut locations = locations.iter().peekable();
+        let mut current = Entry::default();
+        let mut block_pushes = Vec::new();
+        let mut is_previous_push = false;
+        let mut offset = 0;
+        while offset < code_size {
+            while let Some((_, entry)) = locations.next_if(|(location, _)| *location <= offset) {
+                current = *entry;
+            }
+
+            let opcode = bytecode[offset];
+            let size = Opcode::size(opcode);
+            let mut entry = current;
+            entry.jump = Jump::Regular;
+            match opcode {
+                Opcode::PUSH1..=Opcode::PUSH32 => {
+                    let immediate = &bytecode[offset + 1..(offset + size).min(code_size)];
+                    if immediate.len() <= std::mem::size_of::<u32>() {
+                        block_pushes.push(
+                            immediate
+                                .iter()
+                                .fold(0usize, |value, byte| (value << 8) | (*byte as usize)),
+                        );
+                    }
+                }
+                Opcode::JUMP => {
+                    let next = offset + size;
+                    entry.jump = if bytecode.get(next) == Some(&Opcode::JUMPDEST)
+                        && block_pushes.contains(&next)
+                    {
+                        Jump::In
+                    } else if !is_previous_push {
+                        Jump::Out
+                    } else {
+                        Jump::Regular
+                    };
+                    block_pushes.clear();
+                }
+                Opcode::JUMPI | Opcode::JUMPDEST => block_pushes.clear(),
+                opcode if Opcode::is_halting(opcode) => block_pushes.clear(),
+                _ => {}
+            }
+            is_previous_push = (Opcode::PUSH1..=Opcode::PUSH32).contains(&opcode);
+
+            entries.push(entry);
+            offset += size;
+        }
+
+        Self { entries }
+    }
+
+    ///
+    /// Extracts the source locations emitted by LLVM from the object `buffer`.
+    ///
+    /// Must be called before the object is assembled, as the locations are only stored in ELF objects.
+    ///
+    pub fn get_locations(buffer: &inkwell::memory_buffer::MemoryBuffer) -> Vec<(usize, Entry)> {
+        buffer
+            .get_source_locations_evm()
+            .into_iter()
+            .map(|(offset, start, length, file)| {
+                (offset, Entry::new(start, length, file, Jump::Regular))
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for SourceMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut previous = None;
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let compressed = entry.compress(previous);
+                previous = Some(entry);
+                compressed
+            })
+            .collect::<Vec<String>>();
+        write!(f, "{}", entries.join(";"))
+    }
+}
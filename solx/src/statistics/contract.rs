@@ -0,0 +1,57 @@
+//!
+//! The contract compilation statistics.
+//!
+
+///
+/// The contract compilation statistics.
+///
+/// The timings are in milliseconds and summed over the deploy and runtime code. The entries
+/// that do not apply to the contract, e.g. the EthIR data of Yul contracts, are omitted.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contract {
+    /// The IR parsing time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parsing_time: Option<f64>,
+    /// The EthIR traversal time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ethir_traversal_time: Option<f64>,
+    /// The LLVM IR generation time, including the EthIR traversal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llvm_ir_generation_time: Option<f64>,
+    /// The LLVM optimization and assembly time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llvm_build_time: Option<f64>,
+    /// The number of EthIR blocks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ethir_blocks: Option<usize>,
+    /// The number of EthIR functions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ethir_functions: Option<usize>,
+    /// The deploy bytecode size in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deploy_bytecode_size: Option<usize>,
+    /// The runtime bytecode size in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime_bytecode_size: Option<usize>,
+    /// The size of the CBOR metadata at the end of the runtime code in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_size: Option<usize>,
+    /// The number of bytes left until the EIP-3860 initcode size limit, negative if exceeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deploy_headroom: Option<isize>,
+    /// The number of bytes left until the EIP-170 runtime code size limit, negative if exceeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime_headroom: Option<isize>,
+}
+
+impl Contract {
+    ///
+    /// Adds the EthIR counts of a code segment.
+    ///
+    pub fn add_ethir(&mut self, blocks: usize, functions: usize) {
+        self.ethir_blocks = Some(self.ethir_blocks.unwrap_or_default() + blocks);
+        self.ethir_functions = Some(self.ethir_functions.unwrap_or_default() + functions);
+    }
+}
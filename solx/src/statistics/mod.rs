@@ -0,0 +1,78 @@
+//!
+//! The compilation timing and statistics report.
+//!
+
+pub mod contract;
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::time::Instant;
+
+use crate::cache::Cache;
+
+use self::contract::Contract;
+
+///
+/// The compilation timing and statistics report.
+///
+/// The timings are in milliseconds. The project phases are measured as wall-clock time,
+/// whereas the contracts are compiled in parallel, so their timings may add up to more
+/// than the compilation phase.
+///
+#[derive(Debug, Default, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Statistics {
+    /// The `solc` standard JSON time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solc_time: Option<f64>,
+    /// The project parsing time, including the EVM legacy assembly preprocessing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parsing_time: Option<f64>,
+    /// The contract compilation time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compilation_time: Option<f64>,
+    /// The linking time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linking_time: Option<f64>,
+    /// The number of contracts found in the compilation cache.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_hits: Option<usize>,
+    /// The number of contracts missing from the compilation cache.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_misses: Option<usize>,
+    /// The contract statistics.
+    pub contracts: BTreeMap<String, Contract>,
+}
+
+impl Statistics {
+    ///
+    /// Adds the time elapsed since `start` to the `timing`.
+    ///
+    pub fn add_time(timing: &mut Option<f64>, start: Instant) {
+        *timing = Some(timing.unwrap_or_default() + Self::elapsed_time(start));
+    }
+
+    ///
+    /// Returns the time elapsed since `start` in milliseconds.
+    ///
+    pub fn elapsed_time(start: Instant) -> f64 {
+        start.elapsed().as_secs_f64() * 1000.0
+    }
+
+    ///
+    /// Sets the compilation cache hit and miss counters.
+    ///
+    pub fn set_cache(&mut self, cache: &Cache) {
+        self.cache_hits = Some(cache.hits());
+        self.cache_misses = Some(cache.misses());
+    }
+
+    ///
+    /// Writes the report to stderr.
+    ///
+    pub fn write_to_terminal(&self) -> anyhow::Result<()> {
+        let report = serde_json::to_string_pretty(self).expect("Always valid");
+        writeln!(std::io::stderr(), "{report}")?;
+        Ok(())
+    }
+}
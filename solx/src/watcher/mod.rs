@@ -0,0 +1,171 @@
+//!
+//! The source file watcher.
+//!
+
+pub mod settings;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+
+///
+/// The source file watcher.
+///
+/// Tracks the source files of a project, including the imported ones, and polls their
+/// modification times. The import graph is taken from the `solc` AST, so that the files
+/// affected by a change can be determined without parsing the sources.
+///
+#[derive(Debug)]
+pub struct Watcher {
+    /// The directories the source names are resolved against, in the order of priority.
+    search_paths: Vec<PathBuf>,
+    /// The watched source files with their last known modification times.
+    files: BTreeMap<String, Option<SystemTime>>,
+    /// The source names imported by each source file.
+    imports: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Watcher {
+    /// The interval between two consecutive checks of the source files.
+    pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The input `paths` are watched from the start, so that the project is recompiled even
+    /// if the first compilation fails before `solc` returns the sources.
+    /// The source names are resolved like `solc` does, that is, against the base path and
+    /// the include paths, and finally against the current directory.
+    ///
+    pub fn new(paths: &[PathBuf], base_path: Option<&str>, include_paths: &[String]) -> Self {
+        let search_paths = base_path
+            .map(PathBuf::from)
+            .into_iter()
+            .chain(include_paths.iter().map(PathBuf::from))
+            .chain(std::iter::once(PathBuf::new()))
+            .collect::<Vec<PathBuf>>();
+
+        let files = paths
+            .iter()
+            .map(|path| {
+                let name = path.to_string_lossy().to_string();
+                let modified = Self::modified(search_paths.as_slice(), name.as_str());
+                (name, modified)
+            })
+            .collect();
+
+        Self {
+            search_paths,
+            files,
+            imports: BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// Starts watching the sources of the `solc` output and updates the import graph.
+    ///
+    /// The imports of the sources without AST, e.g. due to syntax errors, are kept as is.
+    ///
+    pub fn update(&mut self, solc_output: &solx_solc::StandardJsonOutput) {
+        for (name, source) in solc_output.sources.iter() {
+            if !self.files.contains_key(name.as_str()) {
+                let modified = Self::modified(self.search_paths.as_slice(), name.as_str());
+                self.files.insert(name.to_owned(), modified);
+            }
+            if let Some(ast) = source.ast.as_ref() {
+                self.imports.insert(name.to_owned(), Self::get_imports(ast));
+            }
+        }
+    }
+
+    ///
+    /// Blocks until at least one of the watched files changes, and returns the changed files.
+    ///
+    pub fn wait_for_changes(&mut self) -> BTreeSet<String> {
+        loop {
+            std::thread::sleep(Self::POLL_INTERVAL);
+
+            let mut changed = BTreeSet::new();
+            for (name, last_modified) in self.files.iter_mut() {
+                let modified = Self::modified(self.search_paths.as_slice(), name.as_str());
+                if modified != *last_modified {
+                    *last_modified = modified;
+                    changed.insert(name.to_owned());
+                }
+            }
+            if !changed.is_empty() {
+                return changed;
+            }
+        }
+    }
+
+    ///
+    /// Returns the `changed` files together with all files importing them, directly or transitively.
+    ///
+    pub fn affected(&self, changed: &BTreeSet<String>) -> BTreeSet<String> {
+        let mut affected = changed.to_owned();
+        loop {
+            let importers = self
+                .imports
+                .iter()
+                .filter(|(name, imports)| {
+                    !affected.contains(name.as_str()) && !imports.is_disjoint(&affected)
+                })
+                .map(|(name, _)| name.to_owned())
+                .collect::<Vec<String>>();
+            if importers.is_empty() {
+                return affected;
+            }
+            affected.extend(importers);
+        }
+    }
+
+    ///
+    /// Returns the number of watched files.
+    ///
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    ///
+    /// Whether no files are watched.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    ///
+    /// Returns the source names imported in the AST.
+    ///
+    fn get_imports(ast: &serde_json::Value) -> BTreeSet<String> {
+        ast.get("nodes")
+            .and_then(|nodes| nodes.as_array())
+            .map(|nodes| {
+                nodes
+                    .iter()
+                    .filter(|node| {
+                        node.get("nodeType")
+                            .and_then(|node_type| node_type.as_str())
+                            == Some("ImportDirective")
+                    })
+                    .filter_map(|node| node.get("absolutePath")?.as_str())
+                    .map(|path| path.to_owned())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns the modification time of the source file, or `None` if it cannot be read.
+    ///
+    fn modified(search_paths: &[PathBuf], name: &str) -> Option<SystemTime> {
+        search_paths
+            .iter()
+            .map(|search_path| search_path.join(name))
+            .find(|path| path.exists())
+            .and_then(|path| std::fs::metadata(path).ok())
+            .and_then(|metadata| metadata.modified().ok())
+    }
+}
@@ -0,0 +1,51 @@
+//!
+//! The watch mode settings.
+//!
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use crate::diagnostics::format::Format as DiagnosticsFormat;
+
+///
+/// The watch mode settings.
+///
+/// The settings are applied to every recompilation of the project.
+///
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// The deployed libraries.
+    pub libraries: Vec<String>,
+    /// The EVM version.
+    pub evm_version: Option<era_compiler_common::EVMVersion>,
+    /// Whether to use the IR codegen.
+    pub via_ir: bool,
+    /// The output selection.
+    pub output_selection: solx_solc::StandardJsonInputSelection,
+    /// The output directory. If unset, the output is written to the terminal.
+    pub output_directory: Option<PathBuf>,
+    /// Whether to overwrite the existing files in the output directory.
+    pub overwrite: bool,
+    /// The metadata hash type.
+    pub metadata_hash_type: era_compiler_common::HashType,
+    /// Whether to use the literal source code in the metadata.
+    pub use_literal_content: bool,
+    /// The base path.
+    pub base_path: Option<String>,
+    /// The include paths.
+    pub include_paths: Vec<String>,
+    /// The allowed paths.
+    pub allow_paths: Option<String>,
+    /// The import remappings.
+    pub remappings: BTreeSet<String>,
+    /// The disabled solx lints.
+    pub disabled_lints: BTreeSet<solx_solc::StandardJsonOutputSourceLint>,
+    /// The LLVM optimizer settings.
+    pub optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    /// The extra LLVM options.
+    pub llvm_options: Vec<String>,
+    /// The debug output configuration.
+    pub debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    /// The format the diagnostics of every recompilation are written in.
+    pub diagnostics_format: DiagnosticsFormat,
+}
@@ -69,6 +69,7 @@ mod tests {
             location: Location { line: 0, column: 0 },
             inner: name.to_string(),
             r#type: None,
+            source_location: None,
         }
     }
 
@@ -6,6 +6,7 @@ use era_compiler_llvm_context::IContext;
 use solx_yul::yul::parser::statement::Statement;
 
 use crate::declare_wrapper;
+use crate::error::located::Located as LocatedError;
 use crate::yul::parser::dialect::era::EraDialect;
 use crate::yul::parser::wrapper::Wrap;
 
@@ -33,7 +34,14 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Block {
         }
 
         for function in functions.into_iter() {
-            function.wrap().into_llvm(context)?;
+            let source_location = function.source_location;
+            function
+                .wrap()
+                .into_llvm(context)
+                .map_err(|error| match source_location {
+                    Some(source_location) => LocatedError::wrap(error, source_location),
+                    None => error,
+                })?;
         }
 
         context.set_current_function(current_function.as_str())?;
@@ -43,18 +51,41 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Block {
                 break;
             }
 
+            let source_location = statement.source_location();
+            if let Some(source_location) = source_location {
+                context.set_source_location(
+                    source_location.file,
+                    source_location.start,
+                    source_location.length(),
+                );
+            }
+            let locate = |error| match source_location {
+                Some(source_location) => LocatedError::wrap(error, source_location),
+                None => error,
+            };
+
             match statement {
                 Statement::Block(block) => {
-                    block.wrap().into_llvm(context)?;
+                    block.wrap().into_llvm(context).map_err(locate)?;
                 }
                 Statement::Expression(expression) => {
-                    expression.wrap().into_llvm(context)?;
+                    expression.wrap().into_llvm(context).map_err(locate)?;
+                }
+                Statement::VariableDeclaration(statement) => {
+                    statement.wrap().into_llvm(context).map_err(locate)?
+                }
+                Statement::Assignment(statement) => {
+                    statement.wrap().into_llvm(context).map_err(locate)?
+                }
+                Statement::IfConditional(statement) => {
+                    statement.wrap().into_llvm(context).map_err(locate)?
+                }
+                Statement::Switch(statement) => {
+                    statement.wrap().into_llvm(context).map_err(locate)?
+                }
+                Statement::ForLoop(statement) => {
+                    statement.wrap().into_llvm(context).map_err(locate)?
                 }
-                Statement::VariableDeclaration(statement) => statement.wrap().into_llvm(context)?,
-                Statement::Assignment(statement) => statement.wrap().into_llvm(context)?,
-                Statement::IfConditional(statement) => statement.wrap().into_llvm(context)?,
-                Statement::Switch(statement) => statement.wrap().into_llvm(context)?,
-                Statement::ForLoop(statement) => statement.wrap().into_llvm(context)?,
                 Statement::Continue(_location) => {
                     context.build_unconditional_branch(context.r#loop().continue_block)?;
                     break;
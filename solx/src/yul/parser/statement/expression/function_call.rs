@@ -756,6 +756,16 @@ impl FunctionCall {
             Name::BaseFee => {
                 era_compiler_llvm_context::evm_contract_context::basefee(context).map(Some)
             }
+            Name::BlobHash => {
+                let arguments = self.pop_arguments_llvm::<1>(context)?;
+                let index = arguments[0].into_int_value();
+
+                era_compiler_llvm_context::evm_contract_context::blob_hash(context, index)
+                    .map(Some)
+            }
+            Name::BlobBaseFee => {
+                era_compiler_llvm_context::evm_contract_context::blob_base_fee(context).map(Some)
+            }
             Name::MSize => {
                 era_compiler_llvm_context::evm_contract_context::msize(context).map(Some)
             }
@@ -769,8 +779,12 @@ impl FunctionCall {
                 let _arguments = self.pop_arguments_llvm::<1>(context)?;
                 anyhow::bail!("{location} The `SELFDESTRUCT` instruction is not supported")
             }
-
-            _ => Ok(None),
+            Name::Verbatim {
+                input_size,
+                output_size,
+            } => anyhow::bail!(
+                "{location} The `verbatim_{input_size}i_{output_size}o` instruction is not supported"
+            ),
         }
     }
 
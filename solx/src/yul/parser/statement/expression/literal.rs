@@ -91,7 +91,7 @@ impl Literal {
                 ))
             }
             LexicalLiteral::String(inner) => {
-                let string = inner.inner;
+                let string = inner.inner.clone();
                 let r#type = self
                     .0
                     .yul_type
@@ -99,68 +99,7 @@ impl Literal {
                     .wrap()
                     .into_llvm(context);
 
-                let mut hex_string = if inner.is_hexadecimal {
-                    string.clone()
-                } else {
-                    let mut hex_string =
-                        String::with_capacity(era_compiler_common::BYTE_LENGTH_FIELD * 2);
-                    let mut index = 0;
-                    loop {
-                        if index >= string.len() {
-                            break;
-                        }
-
-                        if string[index..].starts_with('\\') {
-                            index += 1;
-
-                            if string[index..].starts_with('x') {
-                                hex_string.push_str(&string[index + 1..index + 3]);
-                                index += 3;
-                            } else if string[index..].starts_with('u') {
-                                let codepoint_str = &string[index + 1..index + 5];
-                                let codepoint = u32::from_str_radix(
-                                    codepoint_str,
-                                    era_compiler_common::BASE_HEXADECIMAL,
-                                )
-                                .map_err(|error| {
-                                    anyhow::anyhow!("Invalid codepoint `{codepoint_str}`: {error}")
-                                })?;
-                                let unicode_char = char::from_u32(codepoint).ok_or_else(|| {
-                                    anyhow::anyhow!("Invalid codepoint {codepoint}")
-                                })?;
-                                let mut unicode_bytes = vec![0u8; 3];
-                                unicode_char.encode_utf8(&mut unicode_bytes);
-
-                                for byte in unicode_bytes.into_iter() {
-                                    hex_string.push_str(format!("{:02x}", byte).as_str());
-                                }
-                                index += 5;
-                            } else if string[index..].starts_with('t') {
-                                hex_string.push_str("09");
-                                index += 1;
-                            } else if string[index..].starts_with('n') {
-                                hex_string.push_str("0a");
-                                index += 1;
-                            } else if string[index..].starts_with('r') {
-                                hex_string.push_str("0d");
-                                index += 1;
-                            } else if string[index..].starts_with("\r\n") {
-                                index += 2;
-                            } else if string[index..].starts_with('\n') {
-                                index += 1;
-                            } else {
-                                hex_string
-                                    .push_str(format!("{:02x}", string.as_bytes()[index]).as_str());
-                                index += 1;
-                            }
-                        } else {
-                            hex_string
-                                .push_str(format!("{:02x}", string.as_bytes()[index]).as_str());
-                            index += 1;
-                        }
-                    }
-                    hex_string
-                };
+                let mut hex_string = inner.to_hexadecimal()?;
 
                 if hex_string.len() > era_compiler_common::BYTE_LENGTH_FIELD * 2 {
                     return Ok(era_compiler_llvm_context::Value::new_with_original(
@@ -0,0 +1,92 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::path::PathBuf;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_DOCUMENTED_PATH,
+        "--abi",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Contract JSON ABI:"))
+        .stdout(predicate::str::contains("\"name\":\"set\""));
+
+    Ok(())
+}
+
+#[test]
+fn output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let input_path = PathBuf::from(crate::common::TEST_SOLIDITY_CONTRACT_DOCUMENTED_PATH);
+    let input_file = input_path
+        .file_name()
+        .expect("Always exists")
+        .to_str()
+        .expect("Always valid");
+
+    let args = &[
+        input_path.to_str().expect("Always valid"),
+        "--abi",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let output_file = tmp_dir_solx
+        .path()
+        .join(input_file)
+        .join(format!("Documented.{}", solx::r#const::EXTENSION_ABI));
+    assert!(output_file.exists());
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_YUL_CONTRACT_PATH, "--yul", "--abi"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "ABI, hashes, storage layout, and documentation are only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--abi",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
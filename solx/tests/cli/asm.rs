@@ -0,0 +1,77 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::path::PathBuf;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--asm"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("EVM assembly:"))
+        .stdout(predicate::str::contains(".code "))
+        .stdout(predicate::str::contains("JUMPDEST"));
+
+    Ok(())
+}
+
+#[test]
+fn output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let input_path = PathBuf::from(crate::common::TEST_YUL_CONTRACT_PATH);
+    let input_file = input_path
+        .file_name()
+        .expect("Always exists")
+        .to_str()
+        .expect("Always valid");
+
+    let args = &[
+        input_path.to_str().expect("Always valid"),
+        "--yul",
+        "--asm",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let output_file = tmp_dir_solx
+        .path()
+        .join(input_file)
+        .join(format!("Return.{}", solx::r#const::EXTENSION_EVM_ASSEMBLY));
+    assert!(output_file.exists());
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--asm",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::path::PathBuf;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--bin-runtime"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary of the runtime part:\n"))
+        .stdout(predicate::str::contains("Binary:\n").not());
+
+    Ok(())
+}
+
+#[test]
+fn deploy_contains_runtime() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let input_path = PathBuf::from(crate::common::TEST_SOLIDITY_CONTRACT_PATH);
+    let input_file = input_path
+        .file_name()
+        .expect("Always exists")
+        .to_str()
+        .expect("Always valid");
+
+    let metadata_hash_type = era_compiler_common::HashType::None.to_string();
+    let args = &[
+        input_path.to_str().expect("Always valid"),
+        "-O3",
+        "--metadata-hash",
+        metadata_hash_type.as_str(),
+        "--bin",
+        "--bin-runtime",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let output_path = tmp_dir_solx.path().join(input_file);
+    let deploy = std::fs::read_to_string(output_path.join(format!(
+        "Test.{}",
+        era_compiler_common::EXTENSION_EVM_BINARY
+    )))?;
+    let runtime = std::fs::read_to_string(output_path.join(format!(
+        "Test.{}",
+        solx::r#const::EXTENSION_EVM_BINARY_RUNTIME
+    )))?;
+    assert!(!runtime.is_empty(), "The runtime bytecode is empty");
+    assert!(
+        deploy.contains(runtime.as_str()),
+        "The deploy bytecode does not contain the runtime bytecode"
+    );
+
+    let standard_json_input = serde_json::json!({
+        "language": "Solidity",
+        "sources": {
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH: {
+                "content": std::fs::read_to_string(input_path.as_path())?,
+            },
+        },
+        "settings": {
+            "optimizer": {
+                "mode": "3",
+            },
+            "metadata": {
+                "bytecodeHash": metadata_hash_type,
+            },
+            "outputSelection": {
+                "*": {
+                    "*": ["evm.bytecode.object", "evm.deployedBytecode.object"],
+                },
+            },
+        },
+    });
+    let standard_json_input_path = tmp_dir_solx.path().join("input.json");
+    std::fs::write(
+        standard_json_input_path.as_path(),
+        serde_json::to_vec(&standard_json_input)?,
+    )?;
+
+    let args = &[
+        "--standard-json",
+        standard_json_input_path.to_str().expect("Always valid"),
+    ];
+    let result = crate::cli::execute_solx(args)?;
+    let output = result.success().get_output().stdout.clone();
+    let output: serde_json::Value = serde_json::from_slice(output.as_slice())?;
+    let evm = &output["contracts"][crate::common::TEST_SOLIDITY_CONTRACT_PATH]["Test"]["evm"];
+    assert_eq!(
+        evm["bytecode"]["object"].as_str(),
+        Some(deploy.as_str()),
+        "The deploy bytecode differs from the standard JSON output"
+    );
+    assert_eq!(
+        evm["deployedBytecode"]["object"].as_str(),
+        Some(runtime.as_str()),
+        "The runtime bytecode differs from the standard JSON output"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--bin-runtime",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
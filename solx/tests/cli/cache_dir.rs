@@ -0,0 +1,123 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_cache = TempDir::with_prefix("solx_cache")?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--cache-dir",
+        tmp_dir_cache.path().to_str().unwrap(),
+        "--time-report",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("\"cacheHits\": 0"))
+        .stderr(predicate::str::contains("\"cacheMisses\": 1"));
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"))
+        .stderr(predicate::str::contains("\"cacheHits\": 1"))
+        .stderr(predicate::str::contains("\"cacheMisses\": 0"));
+
+    Ok(())
+}
+
+#[test]
+fn settings_changed() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_cache = TempDir::with_prefix("solx_cache")?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--cache-dir",
+        tmp_dir_cache.path().to_str().unwrap(),
+    ];
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    let args = &[
+        "--bin",
+        "-Oz",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--cache-dir",
+        tmp_dir_cache.path().to_str().unwrap(),
+        "--time-report",
+    ];
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("\"cacheHits\": 0"))
+        .stderr(predicate::str::contains("\"cacheMisses\": 1"));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_cache = TempDir::with_prefix("solx_cache")?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--cache-dir",
+        tmp_dir_cache.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cache directory must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_settings() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_cache = TempDir::with_prefix("solx_cache")?;
+    let tmp_dir_input = TempDir::with_prefix("solx_input")?;
+
+    let mut input: serde_json::Value = serde_json::from_str(
+        std::fs::read_to_string(crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_STATISTICS_PATH)?
+            .as_str(),
+    )?;
+    input["settings"]["cacheDir"] = serde_json::Value::from(tmp_dir_cache.path().to_str().unwrap());
+    let input_path = tmp_dir_input.path().join("input.json");
+    std::fs::write(input_path.as_path(), serde_json::to_vec(&input)?)?;
+
+    let args = &["--standard-json", input_path.to_str().unwrap()];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"cacheHits\":0"))
+        .stdout(predicate::str::contains("\"cacheMisses\":1"))
+        .stderr(predicate::str::is_empty());
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"cacheHits\":1"))
+        .stdout(predicate::str::contains("\"cacheMisses\":0"))
+        .stderr(predicate::str::is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_DOCUMENTED_PATH,
+        "--combined-json",
+        "abi,bin,bin-runtime,metadata,hashes",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    let output = result.success().get_output().stdout.clone();
+
+    let combined_json: serde_json::Value = serde_json::from_slice(output.as_slice())?;
+    let contract = &combined_json["contracts"][format!(
+        "{}:Documented",
+        crate::common::TEST_SOLIDITY_CONTRACT_DOCUMENTED_PATH
+    )];
+    assert!(contract["abi"].is_array());
+    assert!(contract["bin"].is_string());
+    assert!(contract["bin-runtime"].is_string());
+    assert!(contract["metadata"].is_string());
+    assert_eq!(contract["hashes"]["set(uint256)"], "60fe47b1");
+    assert!(contract.get("devdoc").is_none());
+    assert!(combined_json["version"].is_string());
+
+    Ok(())
+}
+
+#[test]
+fn output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_DOCUMENTED_PATH,
+        "--combined-json",
+        "bin",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    assert!(tmp_dir_solx
+        .path()
+        .join(solx_solc::CombinedJson::FILE_NAME)
+        .exists());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_selector() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_DOCUMENTED_PATH,
+        "--combined-json",
+        "abi,ast",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid option to --combined-json: `ast`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn other_output_flags() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_DOCUMENTED_PATH,
+        "--combined-json",
+        "abi",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Combined JSON cannot be used together with other output flags.",
+    ));
+
+    Ok(())
+}
+
+#[test_case("--yul", crate::common::TEST_YUL_CONTRACT_PATH)]
+#[test_case("--llvm-ir", crate::common::TEST_LLVM_IR_CONTRACT_PATH)]
+fn non_solidity_mode(mode: &str, path: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[path, mode, "--combined-json", "bin"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Combined JSON is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
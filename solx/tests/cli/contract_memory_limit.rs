@@ -0,0 +1,94 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--contract-memory-limit",
+        "65536",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn exceeded() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    // The limit is far below what the backend needs to compile the contract. Depending on the
+    // platform, the subprocess runs out of memory while starting or while compiling.
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--contract-memory-limit",
+        "64",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "Contract compilation exceeded the memory limit of 64 MiB.",
+        ))
+        .stderr(predicate::str::contains(
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        ));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn exceeded_on_spawn() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--contract-memory-limit",
+        "1",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "Contract compilation exceeded the memory limit of 1 MiB.",
+        ))
+        .stderr(predicate::str::contains(
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn in_process() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--contract-memory-limit",
+        "65536",
+        "--in-process",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Contract time and memory limits cannot be used together with in-process compilation.",
+    ));
+
+    Ok(())
+}
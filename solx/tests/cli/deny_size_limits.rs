@@ -0,0 +1,46 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--deny-size-limits",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    Ok(())
+}
+
+#[test]
+fn exceeded() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_input = TempDir::with_prefix("solx_input")?;
+    let path = crate::common::write_large_contract(tmp_dir_input.path())?;
+
+    let args = &[
+        path.to_str().expect("Always valid"),
+        "--deny-size-limits",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Error"))
+        .stderr(predicate::str::contains(
+            "exceeds the EIP-170 limit of 24576 bytes",
+        ));
+
+    Ok(())
+}
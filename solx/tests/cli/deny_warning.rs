@@ -0,0 +1,64 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH,
+        "--deny-warning",
+        "10004",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Error: Using `tx.origin` for authorization"))
+        .stderr(predicate::str::contains(
+            "Warning: Code size checks cannot reliably tell externally owned accounts from contracts",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn path_not_matched() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH,
+        "--deny-warning",
+        "10004:lib/**",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stderr(predicate::str::contains(
+        "Warning: Using `tx.origin` for authorization",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH,
+        "--deny-warning",
+        "10004:",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid warning filter `10004:`."));
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH,
+        "--deny-warnings",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Error: Using `tx.origin` for authorization",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn allowed() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH,
+        "--deny-warnings",
+        "--allow-warning",
+        "10004",
+        "--allow-warning",
+        "10005",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stderr(predicate::str::contains(
+        "Warning: Using `tx.origin` for authorization",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--deny-warnings",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Warning settings must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
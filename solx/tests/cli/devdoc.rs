@@ -0,0 +1,60 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::path::PathBuf;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_DOCUMENTED_PATH,
+        "--devdoc",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Developer Documentation:"))
+        .stdout(predicate::str::contains("Overwrites the previous value."));
+
+    Ok(())
+}
+
+#[test]
+fn output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let input_path = PathBuf::from(crate::common::TEST_SOLIDITY_CONTRACT_DOCUMENTED_PATH);
+    let input_file = input_path
+        .file_name()
+        .expect("Always exists")
+        .to_str()
+        .expect("Always valid");
+
+    let args = &[
+        input_path.to_str().expect("Always valid"),
+        "--devdoc",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let output_file = tmp_dir_solx
+        .path()
+        .join(input_file)
+        .join(format!("Documented.{}", solx::r#const::EXTENSION_DEVDOC));
+    assert!(output_file.exists());
+
+    Ok(())
+}
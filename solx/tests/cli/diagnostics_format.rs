@@ -0,0 +1,210 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH,
+        "--diagnostics-format",
+        "json",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary"))
+        .stderr(predicate::str::starts_with("["))
+        .stderr(predicate::str::contains("\"errorCode\":\"10004\""))
+        .stderr(predicate::str::contains("\"severity\":\"warning\""))
+        .stderr(predicate::str::contains("\"line\":13"));
+
+    Ok(())
+}
+
+#[test]
+fn sarif() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH,
+        "--diagnostics-format",
+        "sarif",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("\"version\":\"2.1.0\""))
+        .stderr(predicate::str::contains("\"ruleId\":\"10004\""))
+        .stderr(predicate::str::contains("\"level\":\"warning\""))
+        .stderr(predicate::str::contains(format!(
+            "\"uri\":\"{}\"",
+            crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH
+        )))
+        .stderr(predicate::str::contains("\"startLine\":13"));
+
+    Ok(())
+}
+
+#[test]
+fn sarif_error() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH,
+        "--diagnostics-format",
+        "sarif",
+        "--deny-warning",
+        "10004",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::starts_with("{"))
+        .stderr(predicate::str::contains("\"level\":\"error\""));
+
+    Ok(())
+}
+
+#[test]
+fn sarif_related_locations() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_input = TempDir::with_prefix("solx_input")?;
+    let input_path = tmp_dir_input.path().join("Duplicate.sol");
+    std::fs::write(
+        input_path.as_path(),
+        "// SPDX-License-Identifier: MIT\npragma solidity >=0.8.0;\n\ncontract Duplicate {\n    uint256 a;\n    uint256 a;\n}\n",
+    )?;
+
+    let args = &[
+        input_path.to_str().expect("Always valid"),
+        "--diagnostics-format",
+        "sarif",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("\"ruleId\":\"2333\""))
+        .stderr(predicate::str::contains("\"relatedLocations\""))
+        .stderr(predicate::str::contains(
+            "\"text\":\"The previous declaration is here:\"",
+        ))
+        .stderr(predicate::str::contains("\"startLine\":5"));
+
+    Ok(())
+}
+
+#[test]
+fn human_related_locations() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_input = TempDir::with_prefix("solx_input")?;
+    let input_path = tmp_dir_input.path().join("Duplicate.sol");
+    std::fs::write(
+        input_path.as_path(),
+        "// SPDX-License-Identifier: MIT\npragma solidity >=0.8.0;\n\ncontract Duplicate {\n    uint256 a;\n    uint256 a;\n}\n",
+    )?;
+
+    let args = &[input_path.to_str().expect("Always valid"), "--bin"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Identifier already declared."))
+        .stderr(predicate::str::contains(
+            "Note: The previous declaration is here:",
+        ))
+        .stderr(predicate::str::contains(" 5 |     uint256 a;"));
+
+    Ok(())
+}
+
+#[test]
+fn json_include_path() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_input = TempDir::with_prefix("solx_input")?;
+    let library_directory = tmp_dir_input.path().join("lib");
+    std::fs::create_dir_all(library_directory.as_path())?;
+    std::fs::write(
+        library_directory.join("Origin.sol"),
+        "// SPDX-License-Identifier: MIT\npragma solidity >=0.8.0;\n\ncontract Origin {\n    function f() public view returns (address) { return tx.origin; }\n}\n",
+    )?;
+    let main_path = tmp_dir_input.path().join("Main.sol");
+    std::fs::write(
+        main_path.as_path(),
+        "// SPDX-License-Identifier: MIT\npragma solidity >=0.8.0;\n\nimport \"Origin.sol\";\n\ncontract Main is Origin {}\n",
+    )?;
+
+    // The imported file is not reachable from the working directory, so its source code
+    // must be taken from the include path it has been compiled from.
+    let args = &[
+        main_path.to_str().expect("Always valid"),
+        "--base-path",
+        tmp_dir_input.path().to_str().expect("Always valid"),
+        "--include-path",
+        library_directory.to_str().expect("Always valid"),
+        "--diagnostics-format",
+        "json",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("\"errorCode\":\"10004\""))
+        .stderr(predicate::str::contains("\"file\":\"Origin.sol\""))
+        .stderr(predicate::str::contains("\"line\":5"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--diagnostics-format",
+        "xml",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid diagnostics format: `xml`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--diagnostics-format",
+        "json",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Diagnostics format cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH, "--bin"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains(
+            "Using `tx.origin` for authorization",
+        ))
+        .stderr(predicate::str::contains(
+            "Code size checks cannot reliably tell externally owned accounts from contracts",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn disabled() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH,
+        "--disable-lint",
+        "tx-origin",
+        "--disable-lint",
+        "extcodesize-check",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Using `tx.origin` for authorization").not())
+        .stderr(
+            predicate::str::contains(
+                "Code size checks cannot reliably tell externally owned accounts from contracts",
+            )
+            .not(),
+        );
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH,
+        "--disable-lint",
+        "unknown",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid lint: `unknown`."));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--yul",
+        "--disable-lint",
+        "tx-origin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Lints are only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--disable-lint",
+        "tx-origin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Lints must be disabled in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
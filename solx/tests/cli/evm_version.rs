@@ -3,6 +3,7 @@
 //!
 
 use predicates::prelude::*;
+use test_case::test_case;
 
 #[test]
 fn default() -> anyhow::Result<()> {
@@ -85,3 +86,48 @@ fn standard_json() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn blob_opcodes() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let evm_version = era_compiler_common::EVMVersion::Cancun.to_string();
+    let args = &[
+        "--evm-version",
+        evm_version.as_str(),
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_BLOB_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn blob_opcodes_before_cancun_solc(via_ir: bool) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let evm_version = era_compiler_common::EVMVersion::Shanghai.to_string();
+    let mut args = vec![
+        "--evm-version",
+        evm_version.as_str(),
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_BLOB_PATH,
+    ];
+    if via_ir {
+        args.push("--via-ir");
+    }
+
+    // `solc` rejects the blob builtins itself, whereas the solx check is tested in the unit tests.
+    let result = crate::cli::execute_solx(args.as_slice())?;
+    result
+        .failure()
+        .stderr(predicate::str::is_match("blobhash|blobbasefee")?);
+
+    Ok(())
+}
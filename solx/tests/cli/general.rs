@@ -88,3 +88,42 @@ fn same_output_directory_and_terminal(bin_output_file_name: &str) -> anyhow::Res
 
     Ok(())
 }
+
+#[test]
+fn backend_error_location() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_UNSUPPORTED_PATH,
+        "--disable-lint",
+        "selfdestruct",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::is_match(
+        r"The `SELFDESTRUCT` instruction is not supported\n\s*--> tests/data/contracts/solidity/Unsupported\.sol:9:13\n",
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn backend_error_location_via_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_UNSUPPORTED_PATH,
+        "--disable-lint",
+        "selfdestruct",
+        "--via-ir",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::is_match(
+        r"The `SELFDESTRUCT` instruction is not supported\n\s*--> tests/data/contracts/solidity/Unsupported\.sol:\d+:\d+\n",
+    )?);
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH,
+        "--ignore-warning",
+        "10004",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Using `tx.origin` for authorization").not())
+        .stderr(predicate::str::contains(
+            "Code size checks cannot reliably tell externally owned accounts from contracts",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn path() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINTS_PATH,
+        "--ignore-warning",
+        "*:tests/data/contracts/**",
+        "--deny-warnings",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Using `tx.origin` for authorization").not());
+
+    Ok(())
+}
+
+#[test]
+fn path_backend() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_input = TempDir::with_prefix("solx_input")?;
+    let path = crate::common::write_large_contract(tmp_dir_input.path())?;
+    let filter = format!("*:{}/*.sol", tmp_dir_input.path().display());
+
+    // The oversized contract is reported by both solx and the LLVM backend, and the backend
+    // warnings must be located at the source file rather than the contract.
+    let args = &[
+        path.to_str().expect("Always valid"),
+        "--ignore-warning",
+        filter.as_str(),
+        "--deny-warnings",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Warning").not());
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn missing_file() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--link", crate::common::TEST_NON_EXISTENT_PATH];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("reading:"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_library() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--link",
+        crate::common::TEST_LINKER_BYTECODE_PATH,
+        "--libraries",
+        crate::common::LIBRARY_LINKER_ADDRESS_INCORRECT_SIZE,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Incorrect size of address"));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_invalid_hexadecimal() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--link",
+        "--standard-json",
+        crate::common::TEST_LINKER_STANDARD_JSON_INPUT_INVALID_HEXADECIMAL_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("hexadecimal string decoding"));
+
+    Ok(())
+}
+
+#[test]
+fn excess_args() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--link", crate::common::TEST_LINKER_BYTECODE_PATH, "--bin"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "No other options except bytecode files",
+    ));
+
+    Ok(())
+}
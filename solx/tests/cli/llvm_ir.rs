@@ -3,18 +3,74 @@
 //!
 
 use predicates::prelude::*;
-use test_case::test_case;
 
-#[test_case(crate::common::TEST_LLVM_IR_CONTRACT_PATH)]
-fn default(path: &str) -> anyhow::Result<()> {
+#[test]
+fn default() -> anyhow::Result<()> {
     crate::common::setup()?;
 
-    let args = &[path, "--llvm-ir"];
+    let args = &[
+        crate::common::TEST_LLVM_IR_CONTRACT_DEPLOY_PATH,
+        crate::common::TEST_LLVM_IR_CONTRACT_PATH,
+        "--llvm-ir",
+        "--bin",
+    ];
 
     let result = crate::cli::execute_solx(args)?;
-    result
-        .failure()
-        .stderr(predicate::str::contains("LLVM IR is not supported yet."));
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    Ok(())
+}
+
+#[test]
+fn missing_runtime_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_LLVM_IR_CONTRACT_MISSING_RUNTIME_PATH,
+        "--llvm-ir",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Runtime code module `MissingRuntime.runtime` of `MissingRuntime` is missing.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn missing_deploy_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_LLVM_IR_CONTRACT_PATH,
+        "--llvm-ir",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "has no corresponding deploy code module.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn missing_entry() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_LLVM_IR_CONTRACT_MISSING_ENTRY_PATH,
+        "--llvm-ir",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "LLVM IR module entry function `__entry` is missing.",
+    ));
 
     Ok(())
 }
@@ -28,7 +84,7 @@ fn invalid_input_text() -> anyhow::Result<()> {
     let result = crate::cli::execute_solx(args)?;
     result
         .failure()
-        .stderr(predicate::str::contains("LLVM IR is not supported yet."));
+        .stderr(predicate::str::contains("LLVM IR parsing:"));
 
     Ok(())
 }
@@ -45,7 +101,7 @@ fn invalid_input_solidity() -> anyhow::Result<()> {
     let result = crate::cli::execute_solx(args)?;
     result
         .failure()
-        .stderr(predicate::str::contains("LLVM IR is not supported yet."));
+        .stderr(predicate::str::contains("LLVM IR parsing:"));
 
     Ok(())
 }
@@ -60,9 +116,9 @@ fn invalid_input_llvm_ir() -> anyhow::Result<()> {
     ];
 
     let result = crate::cli::execute_solx(args)?;
-    result
-        .failure()
-        .stderr(predicate::str::contains("LLVM IR is not supported yet."));
+    result.failure().stderr(predicate::str::contains(
+        "target triple `eravm` is not supported",
+    ));
 
     Ok(())
 }
@@ -93,7 +149,7 @@ fn linker_error() -> anyhow::Result<()> {
     let result = crate::cli::execute_solx(args)?;
     result
         .failure()
-        .stderr(predicate::str::contains("LLVM IR is not supported yet."));
+        .stderr(predicate::str::contains("is not supported"));
 
     Ok(())
 }
@@ -117,18 +173,35 @@ fn excess_mode_standard_json() -> anyhow::Result<()> {
 }
 
 #[test]
-fn standard_json_invalid() -> anyhow::Result<()> {
+fn standard_json() -> anyhow::Result<()> {
     crate::common::setup()?;
 
     let args = &[
         "--standard-json",
-        crate::common::TEST_LLVM_IR_STANDARD_JSON_INVALID_PATH,
+        crate::common::TEST_LLVM_IR_STANDARD_JSON_PATH,
     ];
 
     let result = crate::cli::execute_solx(args)?;
     result
         .success()
-        .stdout(predicate::str::contains("LLVM IR is not supported yet."));
+        .stdout(predicate::str::contains("\"bytecode\""));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_LLVM_IR_STANDARD_JSON_INVALID_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "target triple `eravm` is not supported",
+    ));
 
     Ok(())
 }
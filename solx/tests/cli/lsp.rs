@@ -0,0 +1,233 @@
+//!
+//! CLI tests for the eponymous subcommand.
+//!
+
+use predicates::prelude::*;
+
+/// The URI of the test document with a syntax error.
+const URI_INVALID: &str = "file:///tmp/solx%20lsp/Invalid.sol";
+
+/// The URI of the valid test document.
+const URI_VALID: &str = "file:///tmp/solx%20lsp/Valid.sol";
+
+/// The URI of the test document triggering a lint.
+const URI_LINT: &str = "file:///tmp/solx%20lsp/Origin.sol";
+
+///
+/// Frames the JSON-RPC messages with the LSP headers.
+///
+fn frame(messages: &[serde_json::Value]) -> String {
+    messages
+        .iter()
+        .map(|message| {
+            let content = message.to_string();
+            format!("Content-Length: {}\r\n\r\n{content}", content.len())
+        })
+        .collect()
+}
+
+///
+/// Returns the `textDocument/didOpen` notification for the document.
+///
+fn did_open(uri: &str, text: &str) -> serde_json::Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/didOpen",
+        "params": {
+            "textDocument": {
+                "uri": uri,
+                "languageId": "solidity",
+                "version": 1,
+                "text": text,
+            },
+        },
+    })
+}
+
+///
+/// Returns the `textDocument/didChange` notification replacing the document content.
+///
+fn did_change(uri: &str, text: &str) -> serde_json::Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/didChange",
+        "params": {
+            "textDocument": {
+                "uri": uri,
+                "version": 2,
+            },
+            "contentChanges": [{ "text": text }],
+        },
+    })
+}
+
+///
+/// Executes the LSP server initialized with the `initialization_options`, with the `messages`
+/// followed by the shutdown sequence.
+///
+fn execute_lsp(
+    initialization_options: serde_json::Value,
+    mut messages: Vec<serde_json::Value>,
+) -> anyhow::Result<assert_cmd::assert::Assert> {
+    messages.insert(
+        0,
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 0,
+            "method": "initialize",
+            "params": { "initializationOptions": initialization_options },
+        }),
+    );
+    messages.push(serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "shutdown" }));
+    messages.push(serde_json::json!({ "jsonrpc": "2.0", "method": "exit" }));
+
+    let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    Ok(cmd
+        .arg("lsp")
+        .write_stdin(frame(messages.as_slice()))
+        .assert())
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let result = execute_lsp(
+        serde_json::Value::Null,
+        vec![
+            did_open(
+                URI_INVALID,
+                "// SPDX-License-Identifier: MIT\npragma solidity >=0.8.0;\ncontract Invalid {\n    function f() public {\n        uint256 x = 1\n    }\n}\n",
+            ),
+            did_open(
+                URI_VALID,
+                "// SPDX-License-Identifier: MIT\npragma solidity >=0.8.0;\ncontract Valid {\n    function f() public pure returns (uint256) {\n        return 42;\n    }\n}\n",
+            ),
+        ],
+    )?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"capabilities\""))
+        .stdout(predicate::str::contains("textDocument/publishDiagnostics"))
+        .stdout(predicate::str::contains("Expected ';' but got '}'"))
+        .stdout(predicate::str::contains("\"severity\":1"))
+        .stdout(predicate::str::contains(URI_VALID))
+        .stdout(predicate::str::contains("\"diagnostics\":[]"));
+
+    Ok(())
+}
+
+#[test]
+fn lint() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let result = execute_lsp(
+        serde_json::Value::Null,
+        vec![did_open(
+            URI_LINT,
+            "// SPDX-License-Identifier: MIT\npragma solidity >=0.8.0;\ncontract Origin {\n    function f() public view returns (address) {\n        /* \u{fc}\u{1d11e} */ return tx.origin;\n    }\n}\n",
+        )],
+    )?;
+    result
+        .success()
+        .stdout(predicate::str::contains(URI_LINT))
+        .stdout(predicate::str::contains("\"code\":\"10004\""))
+        .stdout(predicate::str::contains(
+            "Using `tx.origin` for authorization",
+        ))
+        .stdout(predicate::str::contains(
+            "\"range\":{\"start\":{\"line\":4,\"character\":25},\"end\":{\"line\":4,\"character\":34}}",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn change() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let result = execute_lsp(
+        serde_json::Value::Null,
+        vec![
+            did_open(
+                URI_VALID,
+                "// SPDX-License-Identifier: MIT\npragma solidity >=0.8.0;\ncontract Valid {\n    function f() public pure returns (uint256) {\n        return 42;\n    }\n}\n",
+            ),
+            did_change(
+                URI_VALID,
+                "// SPDX-License-Identifier: MIT\npragma solidity >=0.8.0;\ncontract Valid {\n    function f() public pure returns (uint256) {\n        return 42\n    }\n}\n",
+            ),
+        ],
+    )?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"diagnostics\":[]"))
+        .stdout(predicate::str::contains("Expected ';' but got '}'"));
+
+    Ok(())
+}
+
+#[test]
+fn initialization_options() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let result = execute_lsp(
+        serde_json::json!({ "solxDisabledLints": ["tx-origin"] }),
+        vec![did_open(
+            URI_LINT,
+            "// SPDX-License-Identifier: MIT\npragma solidity >=0.8.0;\ncontract Origin {\n    function f() public view returns (address) {\n        return tx.origin;\n    }\n}\n",
+        )],
+    )?;
+    result
+        .success()
+        .stdout(predicate::str::contains(URI_LINT))
+        .stdout(predicate::str::contains("\"code\":\"10004\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn initialization_options_invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let result = execute_lsp(serde_json::json!({ "viaIR": "yes" }), vec![])?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Initialization options parsing"));
+
+    Ok(())
+}
+
+#[test]
+fn unsupported_method() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let result = execute_lsp(
+        serde_json::Value::Null,
+        vec![serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "textDocument/hover",
+            "params": {},
+        })],
+    )?;
+    result.success().stdout(predicate::str::contains(
+        "Method `textDocument/hover` is not supported.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn excess_args() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--bin", "lsp"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "No other options are allowed in LSP mode.",
+    ));
+
+    Ok(())
+}
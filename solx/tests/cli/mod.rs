@@ -7,16 +7,34 @@ use std::process::Command;
 use assert_cmd::assert::OutputAssertExt;
 use assert_cmd::cargo::CommandCargoExt;
 
+mod abi;
 mod allow_paths;
+mod asm;
 mod base_path;
 mod bin;
+mod bin_runtime;
+mod cache_dir;
+mod combined_json;
+mod contract_memory_limit;
+mod contract_timeout;
 mod debug_output_dir;
+mod deny_size_limits;
+mod deny_warning;
+mod deny_warnings;
+mod devdoc;
+mod diagnostics_format;
+mod disable_lint;
 mod evm_version;
 mod general;
+mod hashes;
+mod ignore_warning;
+mod in_process;
 mod include_path;
 mod libraries;
+mod link;
 mod llvm_ir;
 mod llvm_options;
+mod lsp;
 mod metadata;
 mod metadata_hash;
 mod metadata_literal;
@@ -26,10 +44,15 @@ mod output_dir;
 mod overwrite;
 mod recursive_process;
 mod remappings;
+mod size_report;
 mod standard_json;
+mod storage_layout;
 mod threads;
+mod time_report;
+mod userdoc;
 mod version;
 mod via_ir;
+mod watch;
 mod yul;
 
 ///
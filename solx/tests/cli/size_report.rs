@@ -0,0 +1,73 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--size-report",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Initcode"))
+        .stderr(predicate::str::contains("Headroom"))
+        .stderr(predicate::str::contains("Metadata"))
+        .stderr(predicate::str::contains(format!(
+            "{}:Test",
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn exceeded() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_input = TempDir::with_prefix("solx_input")?;
+    let path = crate::common::write_large_contract(tmp_dir_input.path())?;
+
+    let args = &[
+        path.to_str().expect("Always valid"),
+        "--size-report",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Contract runtime code size is"))
+        .stderr(predicate::str::contains(
+            "exceeds the EIP-170 limit of 24576 bytes",
+        ))
+        .stderr(predicate::str::contains("EIP-3860").not());
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--size-report",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Size report cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
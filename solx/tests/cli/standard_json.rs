@@ -21,6 +21,167 @@ fn default() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn default_no_source_map() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("sourceMap").not());
+
+    Ok(())
+}
+
+#[test]
+fn source_map() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_SOURCE_MAP_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"sourceMap\":\""));
+
+    Ok(())
+}
+
+#[test]
+fn assembly() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_ASSEMBLY_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"assembly\":\".code "))
+        .stdout(predicate::str::contains("\"opcodes\":\"PUSH"))
+        .stdout(predicate::str::contains("\"object\":").not());
+
+    Ok(())
+}
+
+#[test]
+fn statistics() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_STATISTICS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"statistics\":{"))
+        .stdout(predicate::str::contains("\"solcTime\":"))
+        .stdout(predicate::str::contains("\"linkingTime\":"))
+        .stdout(predicate::str::contains("\"A:C\":{"))
+        .stdout(predicate::str::contains("\"ethirBlocks\":"))
+        .stdout(predicate::str::contains("\"runtimeBytecodeSize\":"))
+        .stdout(predicate::str::contains("\"metadataSize\":"))
+        .stdout(predicate::str::contains("\"deployHeadroom\":"))
+        .stdout(predicate::str::contains("\"runtimeHeadroom\":"));
+
+    Ok(())
+}
+
+#[test]
+fn disabled_lints() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_DISABLED_LINTS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"errorCode\":\"10005\""))
+        .stdout(predicate::str::contains("\"errorCode\":\"10004\"").not())
+        .stdout(predicate::str::contains("\"ast\":").not());
+
+    Ok(())
+}
+
+#[test]
+fn warnings() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_WARNINGS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"errorCode\":\"10004\"").not())
+        .stdout(predicate::str::contains(
+            "\"formattedMessage\":\"Error: Code size checks",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn gas_estimates() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_GAS_ESTIMATES_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"gasEstimates\":"))
+        .stdout(predicate::str::contains("\"codeDepositCost\":"))
+        .stdout(predicate::str::contains("\"f(uint256)\":"))
+        .stdout(predicate::str::contains("\"g(uint256)\":\"infinite\""))
+        .stdout(predicate::str::contains("methodIdentifiers").not());
+
+    Ok(())
+}
+
+#[test]
+fn gas_estimates_per_contract() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_GAS_ESTIMATES_PER_CONTRACT_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    let output = result.success().get_output().stdout.clone();
+
+    let output: serde_json::Value = serde_json::from_slice(output.as_slice())?;
+    let contract_c = &output["contracts"]["A"]["C"]["evm"];
+    assert!(contract_c["gasEstimates"]["external"]["f(uint256)"].is_string());
+    assert!(contract_c.get("methodIdentifiers").is_none());
+    let contract_d = &output["contracts"]["A"]["D"]["evm"];
+    assert_eq!(contract_d["methodIdentifiers"]["h()"], "b8c9d365");
+    assert!(contract_d.get("gasEstimates").is_none());
+
+    Ok(())
+}
+
 #[test]
 fn invalid_input_yul() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -69,6 +230,30 @@ fn recursion() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn backend_error_location() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    // The source is only available in the input, so the location must be rendered with it.
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLX_UNSUPPORTED_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "The `SELFDESTRUCT` instruction is not supported",
+        ))
+        .stdout(predicate::str::contains("--> Unsupported.sol:9:13"))
+        .stdout(predicate::str::contains(
+            " 9 |             selfdestruct(recipient);",
+        ));
+
+    Ok(())
+}
+
 #[test]
 fn invalid_path() -> anyhow::Result<()> {
     crate::common::setup()?;
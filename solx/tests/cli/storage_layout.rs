@@ -0,0 +1,60 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::path::PathBuf;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_DOCUMENTED_PATH,
+        "--storage-layout",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Contract Storage Layout:"))
+        .stdout(predicate::str::contains("\"label\":\"value\""));
+
+    Ok(())
+}
+
+#[test]
+fn output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_solx = TempDir::with_prefix("solx_output")?;
+
+    let input_path = PathBuf::from(crate::common::TEST_SOLIDITY_CONTRACT_DOCUMENTED_PATH);
+    let input_file = input_path
+        .file_name()
+        .expect("Always exists")
+        .to_str()
+        .expect("Always valid");
+
+    let args = &[
+        input_path.to_str().expect("Always valid"),
+        "--storage-layout",
+        "--output-dir",
+        tmp_dir_solx.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let output_file = tmp_dir_solx.path().join(input_file).join(format!(
+        "Documented_storage.{}",
+        era_compiler_common::EXTENSION_JSON
+    ));
+    assert!(output_file.exists());
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--time-report",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary"))
+        .stderr(predicate::str::contains("\"solcTime\":"))
+        .stderr(predicate::str::contains("\"parsingTime\":"))
+        .stderr(predicate::str::contains("\"compilationTime\":"))
+        .stderr(predicate::str::contains("\"linkingTime\":"))
+        .stderr(predicate::str::contains("\"ethirTraversalTime\":"))
+        .stderr(predicate::str::contains("\"llvmIrGenerationTime\":"))
+        .stderr(predicate::str::contains("\"llvmBuildTime\":"))
+        .stderr(predicate::str::contains("\"ethirBlocks\":"))
+        .stderr(predicate::str::contains("\"ethirFunctions\":"))
+        .stderr(predicate::str::contains("\"deployBytecodeSize\":"))
+        .stderr(predicate::str::contains("\"runtimeBytecodeSize\":"));
+
+    Ok(())
+}
+
+#[test]
+fn via_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--via-ir",
+        "--time-report",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("\"llvmIrGenerationTime\":"))
+        .stderr(predicate::str::contains("\"ethirBlocks\":").not());
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--yul",
+        "--time-report",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("\"parsingTime\":"))
+        .stderr(predicate::str::contains("\"solcTime\":").not());
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--time-report",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Time report must be requested with `settings.solxStatistics` in standard JSON input.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,221 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::path::Path;
+use std::process::Command;
+use std::process::Stdio;
+use std::time::Duration;
+use std::time::Instant;
+
+use assert_cmd::cargo::CommandCargoExt;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// The maximum time to wait for a recompilation.
+const TIMEOUT: Duration = Duration::from_secs(120);
+
+///
+/// Waits until the file at `path` contains at least `count` occurrences of `pattern`.
+///
+fn wait_for_occurrences(path: &Path, pattern: &str, count: usize) -> anyhow::Result<String> {
+    let start = Instant::now();
+    loop {
+        let content = std::fs::read_to_string(path)?;
+        if content.matches(pattern).count() >= count {
+            return Ok(content);
+        }
+        if start.elapsed() > TIMEOUT {
+            anyhow::bail!("Timeout waiting for {count} occurrences of `{pattern}`:\n{content}");
+        }
+        std::thread::sleep(solx::Watcher::POLL_INTERVAL);
+    }
+}
+
+#[test]
+fn recompile_affected() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solx_watch")?;
+    let base_path = tmp_dir.path().join("Base.sol");
+    let derived_path = tmp_dir.path().join("Derived.sol");
+    let other_path = tmp_dir.path().join("Other.sol");
+    std::fs::write(
+        base_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Base {}\n",
+    )?;
+    std::fs::write(
+        derived_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nimport \"./Base.sol\";\ncontract Derived is Base {}\n",
+    )?;
+    std::fs::write(
+        other_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Other {}\n",
+    )?;
+
+    let stderr_path = tmp_dir.path().join("stderr.txt");
+    let mut child = Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+        .args([
+            base_path.to_str().expect("Always valid"),
+            derived_path.to_str().expect("Always valid"),
+            other_path.to_str().expect("Always valid"),
+            "--watch",
+            "--bin",
+        ])
+        .stdout(Stdio::null())
+        .stderr(std::fs::File::create(stderr_path.as_path())?)
+        .spawn()?;
+
+    let result = (|| -> anyhow::Result<String> {
+        wait_for_occurrences(stderr_path.as_path(), "Watching", 1)?;
+        std::thread::sleep(Duration::from_secs(1));
+        std::fs::write(
+            base_path.as_path(),
+            "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Base { uint256 public value; }\n",
+        )?;
+        wait_for_occurrences(stderr_path.as_path(), "Watching", 2)
+    })();
+    child.kill()?;
+    child.wait()?;
+
+    let stderr = result?;
+    assert!(stderr.contains("Compiling 3 contract(s)..."), "{stderr}");
+    assert!(stderr.contains("Compiling 2 contract(s)..."), "{stderr}");
+
+    Ok(())
+}
+
+#[test]
+fn recover_after_failure() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solx_watch")?;
+    let base_path = tmp_dir.path().join("Base.sol");
+    let derived_path = tmp_dir.path().join("Derived.sol");
+    let other_path = tmp_dir.path().join("Other.sol");
+    std::fs::write(
+        base_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Base {}\n",
+    )?;
+    std::fs::write(
+        derived_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nimport \"./Base.sol\";\ncontract Derived is Base {}\n",
+    )?;
+    std::fs::write(
+        other_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Other {}\n",
+    )?;
+
+    let stderr_path = tmp_dir.path().join("stderr.txt");
+    let mut child = Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+        .args([
+            base_path.to_str().expect("Always valid"),
+            derived_path.to_str().expect("Always valid"),
+            other_path.to_str().expect("Always valid"),
+            "--watch",
+            "--bin",
+        ])
+        .stdout(Stdio::null())
+        .stderr(std::fs::File::create(stderr_path.as_path())?)
+        .spawn()?;
+
+    let result = (|| -> anyhow::Result<String> {
+        wait_for_occurrences(stderr_path.as_path(), "Watching", 1)?;
+        std::thread::sleep(Duration::from_secs(1));
+        std::fs::write(
+            other_path.as_path(),
+            "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Other {\n",
+        )?;
+        wait_for_occurrences(stderr_path.as_path(), "Watching", 2)?;
+        std::thread::sleep(Duration::from_secs(1));
+        std::fs::write(
+            base_path.as_path(),
+            "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Base { uint256 public value; }\n",
+        )?;
+        wait_for_occurrences(stderr_path.as_path(), "Watching", 3)?;
+        std::thread::sleep(Duration::from_secs(1));
+        std::fs::write(
+            other_path.as_path(),
+            "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Other {}\n",
+        )?;
+        wait_for_occurrences(stderr_path.as_path(), "Watching", 4)
+    })();
+    child.kill()?;
+    child.wait()?;
+
+    let stderr = result?;
+    assert_eq!(
+        stderr.matches("Compiling 3 contract(s)...").count(),
+        2,
+        "{stderr}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn diagnostics_format() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solx_watch")?;
+    let other_path = tmp_dir.path().join("Other.sol");
+    std::fs::write(
+        other_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Other {\n",
+    )?;
+
+    let stderr_path = tmp_dir.path().join("stderr.txt");
+    let mut child = Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+        .args([
+            other_path.to_str().expect("Always valid"),
+            "--watch",
+            "--bin",
+            "--diagnostics-format",
+            "json",
+        ])
+        .stdout(Stdio::null())
+        .stderr(std::fs::File::create(stderr_path.as_path())?)
+        .spawn()?;
+
+    let result = wait_for_occurrences(stderr_path.as_path(), "Watching", 1);
+    child.kill()?;
+    child.wait()?;
+
+    let stderr = result?;
+    assert!(stderr.contains("\"severity\":\"error\""), "{stderr}");
+    assert!(stderr.contains("\"line\":"), "{stderr}");
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_YUL_CONTRACT_PATH, "--yul", "--watch"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Watch mode is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--watch",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Watch mode cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -32,6 +32,38 @@ fn solc() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn data() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_YUL_CONTRACT_DATA_PATH, "--yul", "--bin"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("deadbeef"));
+
+    Ok(())
+}
+
+#[test]
+fn verbatim() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_VERBATIM_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The `verbatim_1i_1o` instruction is not supported",
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn invalid_input() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -22,6 +22,17 @@ pub const TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH: &str =
 pub const TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH: &str =
     "tests/data/contracts/solidity/caller/Callable.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_DOCUMENTED_PATH: &str =
+    "tests/data/contracts/solidity/Documented.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_LINTS_PATH: &str = "tests/data/contracts/solidity/Lints.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_UNSUPPORTED_PATH: &str =
+    "tests/data/contracts/solidity/Unsupported.sol";
+
 /// A test input file.
 pub const TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH: &str =
     "tests/data/contracts/solidity/SimpleContract.sol";
@@ -42,15 +53,42 @@ pub const TEST_SOLIDITY_CONTRACT_OPTIMIZED_PATH: &str =
 pub const TEST_SOLIDITY_CONTRACT_INTERFACE_EMPTY_YUL_PATH: &str =
     "tests/data/contracts/solidity/InterfaceEmptyYul.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_BLOB_PATH: &str = "tests/data/contracts/solidity/Blob.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_IMMUTABLE_PATH: &str =
+    "tests/data/contracts/solidity/Immutable.sol";
+
 /// A test input file.
 pub const SOLIDITY_BIN_OUTPUT_NAME: &str = "Test.bin";
 
 /// A test input file.
 pub const TEST_YUL_CONTRACT_PATH: &str = "tests/data/contracts/yul/Test.yul";
 
+/// A test input file.
+pub const TEST_YUL_CONTRACT_DATA_PATH: &str = "tests/data/contracts/yul/Data.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_VERBATIM_PATH: &str = "tests/data/contracts/yul/Verbatim.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_BLOB_PATH: &str = "tests/data/contracts/yul/Blob.yul";
+
 /// A test input file.
 pub const TEST_LLVM_IR_CONTRACT_PATH: &str = "tests/data/contracts/llvm_ir/Test.ll";
 
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_DEPLOY_PATH: &str = "tests/data/contracts/llvm_ir/TestDeploy.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_MISSING_RUNTIME_PATH: &str =
+    "tests/data/contracts/llvm_ir/MissingRuntime.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_MISSING_ENTRY_PATH: &str =
+    "tests/data/contracts/llvm_ir/MissingEntry.ll";
+
 /// A test input file.
 pub const TEST_LLVM_IR_CONTRACT_INVALID_PATH: &str = "tests/data/contracts/llvm_ir/Invalid.ll";
 
@@ -78,6 +116,34 @@ pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_EMPTY_SOURCES_PATH: &str =
 pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_MISSING_SOURCES_PATH: &str =
     "tests/data/standard_json_input/solidity_solc_missing_sources.json";
 
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_SOURCE_MAP_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_source_map.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_ASSEMBLY_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_assembly.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_DISABLED_LINTS_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_disabled_lints.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_WARNINGS_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_warnings.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_STATISTICS_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_statistics.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_GAS_ESTIMATES_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_gas_estimates.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_GAS_ESTIMATES_PER_CONTRACT_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_gas_estimates_per_contract.json";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_INVALID_PATH: &str =
     "tests/data/standard_json_input/solidity_solc_invalid.json";
@@ -90,6 +156,10 @@ pub const TEST_SOLIDITY_STANDARD_JSON_SOLX_RECURSION_PATH: &str =
 pub const TEST_SOLIDITY_STANDARD_JSON_SOLX_INVALID_PATH: &str =
     "tests/data/standard_json_input/solidity_solx_invalid.json";
 
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLX_UNSUPPORTED_PATH: &str =
+    "tests/data/standard_json_input/solidity_solx_unsupported.json";
+
 /// A test input file.
 pub const TEST_YUL_STANDARD_JSON_SOLC_PATH: &str = "tests/data/standard_json_input/yul_solc.json";
 
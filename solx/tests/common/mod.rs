@@ -11,6 +11,7 @@ pub use self::r#const::*;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Once;
 
@@ -36,6 +37,33 @@ pub fn setup() -> anyhow::Result<()> {
     Ok(())
 }
 
+///
+/// Writes a contract exceeding the EIP-170 runtime code size limit to the `directory`.
+///
+/// The contract returns a constant large enough for the runtime code to exceed the limit,
+/// while its initcode stays below the EIP-3860 limit.
+///
+pub fn write_large_contract(directory: &Path) -> anyhow::Result<PathBuf> {
+    const DATA_SIZE: usize = 30000;
+
+    let path = directory.join("Large.sol");
+    let source_code = format!(
+        r#"// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract Large {{
+    function data() external pure returns (bytes memory) {{
+        return hex"{}";
+    }}
+}}
+"#,
+        "ff".repeat(DATA_SIZE),
+    );
+    std::fs::write(path.as_path(), source_code)?;
+    Ok(path)
+}
+
 ///
 /// Reads source code files from the disk.
 ///
@@ -97,10 +125,14 @@ pub fn build_solidity_standard_json(
 
     let build = project.compile_to_evm(
         &mut vec![],
+        None,
         metadata_hash_type,
         optimizer_settings,
         vec![],
         None,
+        None,
+        false,
+        solx::ProcessLimits::default(),
     )?;
     build.check_errors()?;
 
@@ -139,10 +171,14 @@ pub fn build_yul(
     )?;
     let build = project.compile_to_evm(
         &mut vec![],
+        None,
         era_compiler_common::HashType::Ipfs,
         optimizer_settings,
         vec![],
         None,
+        None,
+        false,
+        solx::ProcessLimits::default(),
     )?;
     build.check_errors()?;
 
@@ -185,10 +221,14 @@ pub fn build_yul_standard_json(
     )?;
     let build = project.compile_to_evm(
         &mut vec![],
+        None,
         era_compiler_common::HashType::Ipfs,
         optimizer_settings,
         vec![],
         None,
+        None,
+        false,
+        solx::ProcessLimits::default(),
     )?;
     build.check_errors()?;
 
@@ -222,10 +262,14 @@ pub fn build_llvm_ir_standard_json(
     )?;
     let build = project.compile_to_evm(
         &mut vec![],
+        None,
         era_compiler_common::HashType::Ipfs,
         optimizer_settings,
         vec![],
         None,
+        None,
+        false,
+        solx::ProcessLimits::default(),
     )?;
     build.check_errors()?;
 
@@ -0,0 +1,9 @@
+; ModuleID = 'MissingEntry'
+source_filename = "MissingEntry"
+target datalayout = "E-p:256:256-i256:256:256-S256-a:256:256"
+target triple = "evm-unknown-unknown"
+
+define void @main() {
+entry:
+  ret void
+}
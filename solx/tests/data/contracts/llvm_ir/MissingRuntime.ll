@@ -0,0 +1,15 @@
+; ModuleID = 'MissingRuntime'
+source_filename = "MissingRuntime"
+target datalayout = "E-p:256:256-i256:256:256-S256-a:256:256"
+target triple = "evm-unknown-unknown"
+
+; Function Attrs: noreturn nounwind
+declare void @llvm.evm.return(ptr addrspace(1), i256) #0
+
+define void @__entry() local_unnamed_addr #0 {
+entry:
+  tail call void @llvm.evm.return(ptr addrspace(1) null, i256 0)
+  unreachable
+}
+
+attributes #0 = { noreturn nounwind }
@@ -0,0 +1,32 @@
+; ModuleID = 'solx/tests/data/contracts/solidity/Test.sol:Test'
+source_filename = "solx/tests/data/contracts/solidity/Test.sol:Test"
+target datalayout = "E-p:256:256-i256:256:256-S256-a:256:256"
+target triple = "evm-unknown-unknown"
+
+; Function Attrs: nofree nosync nounwind memory(none)
+declare i256 @llvm.evm.datasize(metadata) #0
+
+; Function Attrs: nofree nosync nounwind memory(none)
+declare i256 @llvm.evm.dataoffset(metadata) #0
+
+; Function Attrs: nounwind
+declare void @llvm.evm.codecopy(ptr addrspace(1), ptr addrspace(4), i256) #1
+
+; Function Attrs: noreturn nounwind
+declare void @llvm.evm.return(ptr addrspace(1), i256) #2
+
+; Function Attrs: nofree noinline noreturn null_pointer_is_valid
+define void @__entry() local_unnamed_addr #3 {
+entry:
+  %datasize = tail call i256 @llvm.evm.datasize(metadata !"solx/tests/data/contracts/solidity/Test.sol:Test.runtime")
+  %dataoffset = tail call i256 @llvm.evm.dataoffset(metadata !"solx/tests/data/contracts/solidity/Test.sol:Test.runtime")
+  %code_pointer = inttoptr i256 %dataoffset to ptr addrspace(4)
+  tail call void @llvm.evm.codecopy(ptr addrspace(1) null, ptr addrspace(4) %code_pointer, i256 %datasize)
+  tail call void @llvm.evm.return(ptr addrspace(1) null, i256 %datasize)
+  unreachable
+}
+
+attributes #0 = { nofree nosync nounwind memory(none) }
+attributes #1 = { nounwind }
+attributes #2 = { noreturn nounwind }
+attributes #3 = { nofree noinline noreturn null_pointer_is_valid }
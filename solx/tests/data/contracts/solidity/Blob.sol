@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.24;
+
+contract Blob {
+    function hash(uint256 index) external view returns (bytes32) {
+        return blobhash(index);
+    }
+
+    function baseFee() external view returns (uint256) {
+        return block.blobbasefee;
+    }
+}
@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+/// @title A documented contract
+/// @notice Stores a single value.
+contract Documented {
+    uint256 public value;
+
+    /// @notice Sets the value.
+    /// @dev Overwrites the previous value.
+    /// @param newValue The new value.
+    function set(uint256 newValue) external {
+        value = newValue;
+    }
+}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.6.5;
+
+contract Immutable {
+    uint256 public immutable value;
+
+    constructor(uint256 _value) {
+        value = _value;
+    }
+}
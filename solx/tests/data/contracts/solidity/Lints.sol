@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract Lints {
+    address owner;
+
+    constructor() {
+        owner = msg.sender;
+    }
+
+    function isOwner() public view returns (bool) {
+        return tx.origin == owner;
+    }
+
+    function isContract(address account) public view returns (bool) {
+        return account.code.length > 0;
+    }
+}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract Unsupported {
+    function destroy(address payable recipient) public {
+        uint256 balance = address(this).balance;
+        if (balance > 0) {
+            selfdestruct(recipient);
+        }
+    }
+}
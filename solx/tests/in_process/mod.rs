@@ -0,0 +1,96 @@
+//!
+//! The library-level tests of the in-process compilation mode.
+//!
+
+use std::collections::BTreeMap;
+
+use solx::project::Project;
+use solx_solc::CollectableError;
+
+/// The stack size of the thread pool workers, as required by the LLVM code generation.
+const RAYON_WORKER_STACK_SIZE: usize = 16 * 1024 * 1024;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    assert!(
+        solx::process::EXECUTABLE.get().is_none(),
+        "The `solx` executable path must not be set"
+    );
+
+    era_compiler_llvm_context::initialize_target(era_compiler_common::Target::EVM);
+
+    let sources: BTreeMap<String, solx_solc::StandardJsonInputSource> =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH])
+            .into_iter()
+            .map(|(path, source)| (path, solx_solc::StandardJsonInputSource::from(source)))
+            .collect();
+
+    let mut solc_input = solx_solc::StandardJsonInput::try_from_solidity_sources(
+        sources,
+        solx_solc::StandardJsonInputLibraries::default(),
+        Default::default(),
+        solx_solc::StandardJsonInputOptimizer::default(),
+        None,
+        false,
+        solx_solc::StandardJsonInputSelection::new(false),
+        solx_solc::StandardJsonInputMetadata::default(),
+        vec![],
+    )?;
+    let mut solc_output = {
+        let _lock = crate::common::UNIT_TEST_LOCK.lock();
+        solx_solc::Compiler::default().standard_json(
+            &mut solc_input,
+            &mut vec![],
+            None,
+            vec![],
+            None,
+        )
+    }?;
+    solc_output.check_errors()?;
+
+    let project = Project::try_from_solc_output(
+        solc_input.settings.libraries,
+        false,
+        &mut solc_output,
+        None,
+    )?;
+    solc_output.check_errors()?;
+
+    let thread_pool = rayon::ThreadPoolBuilder::new()
+        .stack_size(RAYON_WORKER_STACK_SIZE)
+        .build()?;
+    let build = thread_pool.install(|| {
+        project.compile_to_evm(
+            &mut vec![],
+            None,
+            era_compiler_common::HashType::Ipfs,
+            era_compiler_llvm_context::OptimizerSettings::cycles(),
+            vec![],
+            None,
+            None,
+            true,
+            solx::ProcessLimits::default(),
+        )
+    })?;
+    build.check_errors()?;
+
+    let build = build.link(BTreeMap::new());
+    build.check_errors()?;
+
+    build.write_to_standard_json(&mut solc_output)?;
+    solc_output.check_errors()?;
+
+    let bytecode = solc_output
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+        .expect("Missing file")
+        .values()
+        .filter_map(|contract| contract.evm.as_ref())
+        .filter_map(|evm| evm.bytecode.as_ref())
+        .map(|bytecode| bytecode.object.as_str())
+        .next()
+        .expect("Missing bytecode");
+    assert!(!bytecode.is_empty(), "The bytecode is empty");
+
+    Ok(())
+}
@@ -0,0 +1,10 @@
+//!
+//! The in-process compilation tests entry module.
+//!
+//! The tests are built into a separate binary, so the `solx` executable path is never set.
+//!
+
+#![cfg(test)]
+
+mod common;
+mod in_process;
@@ -0,0 +1,72 @@
+//!
+//! Unit tests for the target EVM version checks.
+//!
+
+use std::path::PathBuf;
+
+use solx::project::Project;
+
+/// The stack size of the thread pool workers, as required by the LLVM code generation.
+const RAYON_WORKER_STACK_SIZE: usize = 16 * 1024 * 1024;
+
+#[test]
+fn blob_opcodes_before_cancun() -> anyhow::Result<()> {
+    crate::common::setup()?;
+    era_compiler_llvm_context::initialize_target(era_compiler_common::Target::EVM);
+
+    // `solc` rejects the blob builtins before Cancun itself, so the Yul project is compiled
+    // directly to reach the backend check.
+    let project = Project::try_from_yul_paths(
+        &[PathBuf::from(crate::common::TEST_YUL_CONTRACT_BLOB_PATH)],
+        solx_solc::StandardJsonInputLibraries::default(),
+        None,
+        None,
+    )?;
+
+    let thread_pool = rayon::ThreadPoolBuilder::new()
+        .stack_size(RAYON_WORKER_STACK_SIZE)
+        .build()?;
+    let build = thread_pool.install(|| {
+        project.compile_to_evm(
+            &mut vec![],
+            Some(era_compiler_common::EVMVersion::Shanghai),
+            era_compiler_common::HashType::None,
+            era_compiler_llvm_context::OptimizerSettings::none(),
+            vec![],
+            None,
+            None,
+            true,
+            solx::ProcessLimits::default(),
+        )
+    })?;
+
+    let error = build
+        .results
+        .get(crate::common::TEST_YUL_CONTRACT_BLOB_PATH)
+        .expect("Missing contract")
+        .as_ref()
+        .expect_err("The contract must not compile");
+    assert!(
+        error
+            .message
+            .starts_with("The `BLOBHASH` instruction used in function `"),
+        "Unexpected error: {}",
+        error.message
+    );
+    assert!(
+        error
+            .message
+            .ends_with("is only available since EVM version `cancun`, but `shanghai` is targeted"),
+        "Unexpected error: {}",
+        error.message
+    );
+    assert_eq!(
+        error
+            .source_location
+            .as_ref()
+            .map(|source_location| source_location.file.as_str()),
+        Some(crate::common::TEST_YUL_CONTRACT_BLOB_PATH)
+    );
+
+    Ok(())
+}
@@ -11,9 +11,8 @@ fn get_bytecode(
     path: &str,
     name: &str,
     libraries: solx_solc::StandardJsonInputLibraries,
-    version: &semver::Version,
     via_ir: bool,
-) -> Vec<u8> {
+) -> String {
     let sources = crate::common::read_sources(&[path]);
 
     let build = crate::common::build_solidity_standard_json(
@@ -21,12 +20,11 @@ fn get_bytecode(
         libraries,
         era_compiler_common::HashType::None,
         BTreeSet::new(),
-        version,
         via_ir,
         era_compiler_llvm_context::OptimizerSettings::none(),
     )
     .expect("Build failure");
-    let bytecode_hexadecimal = build
+    build
         .contracts
         .get(path)
         .expect("Missing file")
@@ -39,8 +37,7 @@ fn get_bytecode(
         .as_ref()
         .expect("Missing bytecode")
         .object
-        .as_str();
-    hex::decode(bytecode_hexadecimal).expect("Invalid bytecode")
+        .to_owned()
 }
 
 #[test_case(false)]
@@ -50,18 +47,19 @@ fn library_not_passed_compile_time(via_ir: bool) {
         crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
         "SimpleContract",
         solx_solc::StandardJsonInputLibraries::default(),
-        &solx_solc::Compiler::LAST_SUPPORTED_VERSION,
         via_ir,
     );
 
-    let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
-        bytecode.as_slice(),
-        "bytecode",
-        false,
+    let placeholder = solx::Linker::placeholder(
+        format!(
+            "{}:SimpleLibrary",
+            crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+        )
+        .as_str(),
     );
     assert!(
-        memory_buffer.is_elf_eravm(),
-        "The bytecode is not an ELF file"
+        bytecode.contains(placeholder.as_str()),
+        "The bytecode does not contain the library placeholder"
     );
 }
 
@@ -72,7 +70,6 @@ fn library_not_passed_post_compile_time(via_ir: bool) {
         crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
         "SimpleContract",
         solx_solc::StandardJsonInputLibraries::default(),
-        &solx_solc::Compiler::LAST_SUPPORTED_VERSION,
         via_ir,
     );
     let full_path = format!(
@@ -80,16 +77,133 @@ fn library_not_passed_post_compile_time(via_ir: bool) {
         crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
     );
     let mut bytecodes = BTreeMap::new();
-    bytecodes.insert(full_path.clone(), hex::encode(bytecode));
+    bytecodes.insert(full_path.clone(), bytecode);
 
     let input = solx::LinkerInput::new(bytecodes, vec![]);
-    let output = solx::Linker::link_eravm(input).expect("Linker failed");
+    let output = solx::Linker::link(input).expect("Linker failed");
     assert!(
         output.unlinked.contains_key(full_path.as_str()),
         "The bytecode is linked"
     );
 }
 
+#[test_case(false)]
+#[test_case(true)]
+fn library_not_passed_link_references(via_ir: bool) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let build = crate::common::build_solidity_standard_json(
+        sources,
+        solx_solc::StandardJsonInputLibraries::default(),
+        era_compiler_common::HashType::None,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::none(),
+    )
+    .expect("Build failure");
+    let link_references = &build
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH)
+        .expect("Missing file")
+        .get("SimpleContract")
+        .expect("Missing contract")
+        .evm
+        .as_ref()
+        .expect("Missing EVM data")
+        .deployed_bytecode
+        .as_ref()
+        .expect("Missing bytecode")
+        .link_references;
+
+    let references = link_references
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH)
+        .and_then(|file| file.get("SimpleLibrary"))
+        .expect("Missing link references");
+    assert!(!references.is_empty(), "The link references are empty");
+    assert!(references
+        .iter()
+        .all(|reference| reference.length == era_compiler_common::BYTE_LENGTH_ETH_ADDRESS));
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn library_link_references_patched(via_ir: bool) {
+    let libraries =
+        vec!["tests/data/contracts/solidity/SimpleContract.sol:SimpleLibrary=0x1234567890abcdef1234567890abcdef12345678".to_owned()];
+    let compile_time_libraries =
+        solx_solc::StandardJsonInputLibraries::try_from(libraries.as_slice())
+            .expect("Always valid");
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let build = crate::common::build_solidity_standard_json(
+        sources,
+        solx_solc::StandardJsonInputLibraries::default(),
+        era_compiler_common::HashType::None,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::none(),
+    )
+    .expect("Build failure");
+    let bytecode = build
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH)
+        .expect("Missing file")
+        .get("SimpleContract")
+        .expect("Missing contract")
+        .evm
+        .as_ref()
+        .expect("Missing EVM data")
+        .bytecode
+        .as_ref()
+        .expect("Missing bytecode");
+    let references = bytecode
+        .link_references
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH)
+        .and_then(|file| file.get("SimpleLibrary"))
+        .expect("Missing link references");
+
+    let mut bytecode_patched = bytecode.object.clone();
+    for reference in references.iter() {
+        let start = reference.start * 2;
+        bytecode_patched.replace_range(
+            start..start + reference.length * 2,
+            "1234567890abcdef1234567890abcdef12345678",
+        );
+    }
+
+    let full_path = format!(
+        "{}:SimpleContract",
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+    );
+    let mut bytecodes = BTreeMap::new();
+    bytecodes.insert(full_path.clone(), bytecode.object.clone());
+    let input = solx::LinkerInput::new(bytecodes, libraries);
+    let output = solx::Linker::link(input).expect("Linker failed");
+    let bytecode_linked = output
+        .linked
+        .get(full_path.as_str())
+        .expect("The bytecode is not linked")
+        .bytecode
+        .as_str();
+    assert_eq!(
+        bytecode_patched, bytecode_linked,
+        "The patched bytecode is not equal to the linked one"
+    );
+
+    let bytecode_compile_time = get_bytecode(
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
+        "SimpleContract",
+        compile_time_libraries,
+        via_ir,
+    );
+    assert_eq!(
+        bytecode_patched, bytecode_compile_time,
+        "The patched bytecode is not equal to the one linked at compile time"
+    );
+}
+
 #[test_case(false)]
 #[test_case(true)]
 fn library_passed_compile_time(via_ir: bool) {
@@ -102,16 +216,16 @@ fn library_passed_compile_time(via_ir: bool) {
         crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
         "SimpleContract",
         libraries,
-        &solx_solc::Compiler::LAST_SUPPORTED_VERSION,
         via_ir,
     );
 
+    let bytecode = hex::decode(bytecode).expect("Invalid bytecode");
     let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
         bytecode.as_slice(),
         "bytecode",
         false,
     );
-    assert!(!memory_buffer.is_elf_eravm(), "The bytecode is an ELF file");
+    assert!(!memory_buffer.is_elf_evm(), "The bytecode is an ELF file");
 }
 
 #[test_case(false)]
@@ -124,7 +238,6 @@ fn library_passed_post_compile_time(via_ir: bool) {
         crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
         "SimpleContract",
         solx_solc::StandardJsonInputLibraries::default(),
-        &solx_solc::Compiler::LAST_SUPPORTED_VERSION,
         via_ir,
     );
     let full_path = format!(
@@ -132,10 +245,10 @@ fn library_passed_post_compile_time(via_ir: bool) {
         crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
     );
     let mut bytecodes = BTreeMap::new();
-    bytecodes.insert(full_path.clone(), hex::encode(bytecode));
+    bytecodes.insert(full_path.clone(), bytecode);
 
     let input = solx::LinkerInput::new(bytecodes, libraries);
-    let output = solx::Linker::link_eravm(input).expect("Linker failed");
+    let output = solx::Linker::link(input).expect("Linker failed");
     assert!(
         output.linked.contains_key(full_path.as_str()),
         "The bytecode is not linked"
@@ -145,36 +258,37 @@ fn library_passed_post_compile_time(via_ir: bool) {
 #[test_case(false)]
 #[test_case(true)]
 fn library_passed_post_compile_time_second_call(via_ir: bool) {
-    let library_arguments =
+    let libraries =
         vec!["tests/data/contracts/solidity/SimpleContract.sol:SimpleLibrary=0x1234567890abcdef1234567890abcdef12345678".to_owned()];
-    let linker_symbols =
-        solx_solc::StandardJsonInputLibraries::try_from(library_arguments.as_slice())
-            .expect("Always valid")
-            .as_linker_symbols()
-            .expect("Always valid");
 
     let bytecode = get_bytecode(
         crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
         "SimpleContract",
         solx_solc::StandardJsonInputLibraries::default(),
-        &solx_solc::Compiler::LAST_SUPPORTED_VERSION,
         via_ir,
     );
+    let full_path = format!(
+        "{}:SimpleContract",
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+    );
+    let mut bytecodes = BTreeMap::new();
+    bytecodes.insert(full_path.clone(), bytecode);
 
-    let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
-        bytecode.as_slice(),
-        "bytecode",
-        false,
+    let input = solx::LinkerInput::new(
+        bytecodes.clone(),
+        vec!["tests/data/contracts/solidity/fake.sol:Fake=0x0000000000000000000000000000000000000000".to_owned()],
     );
-    let memory_buffer_linked_empty = memory_buffer
-        .link_module_eravm(&BTreeMap::new(), &BTreeMap::new())
-        .expect("Link failure");
-    let memory_buffer_linked = memory_buffer_linked_empty
-        .link_module_eravm(&linker_symbols, &BTreeMap::new())
-        .expect("Link failure");
+    let output = solx::Linker::link(input).expect("Linker failed");
     assert!(
-        !memory_buffer_linked.is_elf_eravm(),
-        "The bytecode is an ELF file"
+        output.unlinked.contains_key(full_path.as_str()),
+        "The bytecode is linked"
+    );
+
+    let input = solx::LinkerInput::new(bytecodes, libraries);
+    let output = solx::Linker::link(input).expect("Linker failed");
+    assert!(
+        output.linked.contains_key(full_path.as_str()),
+        "The bytecode is not linked"
     );
 }
 
@@ -191,7 +305,6 @@ fn library_passed_post_compile_time_redundant_args(via_ir: bool) {
         crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
         "SimpleContract",
         solx_solc::StandardJsonInputLibraries::default(),
-        &solx_solc::Compiler::LAST_SUPPORTED_VERSION,
         via_ir,
     );
     let full_path = format!(
@@ -199,10 +312,10 @@ fn library_passed_post_compile_time_redundant_args(via_ir: bool) {
         crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
     );
     let mut bytecodes = BTreeMap::new();
-    bytecodes.insert(full_path.clone(), hex::encode(bytecode));
+    bytecodes.insert(full_path.clone(), bytecode);
 
     let input = solx::LinkerInput::new(bytecodes, libraries);
-    let output = solx::Linker::link_eravm(input).expect("Linker failed");
+    let output = solx::Linker::link(input).expect("Linker failed");
     assert!(
         output.linked.contains_key(full_path.as_str()),
         "The bytecode is not linked"
@@ -211,34 +324,32 @@ fn library_passed_post_compile_time_redundant_args(via_ir: bool) {
 
 #[test_case(false)]
 #[test_case(true)]
-#[should_panic(expected = "Input binary is not an EraVM ELF file")]
-fn library_passed_post_compile_time_non_elf(via_ir: bool) {
-    let library_arguments =
+fn library_passed_post_compile_time_already_linked(via_ir: bool) {
+    let libraries =
         vec!["tests/data/contracts/solidity/SimpleContract.sol:SimpleLibrary=0x1234567890abcdef1234567890abcdef12345678".to_owned()];
-    let libraries = solx_solc::StandardJsonInputLibraries::try_from(library_arguments.as_slice())
-        .expect("Always valid")
-        .as_linker_symbols()
-        .expect("Always valid");
+    let compile_time_libraries =
+        solx_solc::StandardJsonInputLibraries::try_from(libraries.as_slice())
+            .expect("Always valid");
 
     let bytecode = get_bytecode(
         crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
         "SimpleContract",
-        solx_solc::StandardJsonInputLibraries::default(),
-        &solx_solc::Compiler::LAST_SUPPORTED_VERSION,
+        compile_time_libraries,
         via_ir,
     );
+    let full_path = format!(
+        "{}:SimpleContract",
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+    );
+    let mut bytecodes = BTreeMap::new();
+    bytecodes.insert(full_path.clone(), bytecode);
 
-    let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
-        bytecode.as_slice(),
-        "bytecode",
-        false,
+    let input = solx::LinkerInput::new(bytecodes, libraries);
+    let output = solx::Linker::link(input).expect("Linker failed");
+    assert!(
+        output.ignored.contains_key(full_path.as_str()),
+        "The linked bytecode is not ignored"
     );
-    let memory_buffer_linked = memory_buffer
-        .link_module_eravm(&libraries, &BTreeMap::new())
-        .expect("Link failure");
-    let _memory_buffer_linked_non_elf = memory_buffer_linked
-        .link_module_eravm(&libraries, &BTreeMap::new())
-        .expect("Link failure");
 }
 
 #[test_case(false)]
@@ -248,40 +359,38 @@ fn library_produce_equal_bytecode_in_both_cases(via_ir: bool) {
         vec!["tests/data/contracts/solidity/SimpleContract.sol:SimpleLibrary=0x1234567890abcdef1234567890abcdef12345678".to_owned()];
     let libraries = solx_solc::StandardJsonInputLibraries::try_from(library_arguments.as_slice())
         .expect("Always valid");
-    let linker_symbols = libraries.as_linker_symbols().expect("Always valid");
 
     let bytecode_compile_time = get_bytecode(
         crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
         "SimpleContract",
         libraries,
-        &solx_solc::Compiler::LAST_SUPPORTED_VERSION,
         via_ir,
     );
-    let memory_buffer_compile_time = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
-        bytecode_compile_time.as_slice(),
-        "bytecode_compile_time",
-        false,
-    );
 
     let bytecode_post_compile_time = get_bytecode(
         crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
         "SimpleContract",
         solx_solc::StandardJsonInputLibraries::default(),
-        &solx_solc::Compiler::LAST_SUPPORTED_VERSION,
         via_ir,
     );
-    let memory_buffer_post_compile_time =
-        inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
-            bytecode_post_compile_time.as_slice(),
-            "bytecode_post_compile_time",
-            false,
-        );
-    let memory_buffer_linked_post_compile_time = memory_buffer_post_compile_time
-        .link_module_eravm(&linker_symbols, &BTreeMap::new())
-        .expect("Link failure");
+    let full_path = format!(
+        "{}:SimpleContract",
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+    );
+    let mut bytecodes = BTreeMap::new();
+    bytecodes.insert(full_path.clone(), bytecode_post_compile_time);
+
+    let input = solx::LinkerInput::new(bytecodes, library_arguments);
+    let output = solx::Linker::link(input).expect("Linker failed");
+    let bytecode_linked_post_compile_time = output
+        .linked
+        .get(full_path.as_str())
+        .expect("The bytecode is not linked")
+        .bytecode
+        .as_str();
 
-    assert!(
-        memory_buffer_compile_time.as_slice() == memory_buffer_linked_post_compile_time.as_slice(),
+    assert_eq!(
+        bytecode_compile_time, bytecode_linked_post_compile_time,
         "The bytecodes are not equal"
     );
 }
@@ -315,7 +424,6 @@ fn library_produce_equal_bytecode_in_both_cases(via_ir: bool) {
 fn libraries_passed_post_compile_time_complex(
     sources: &[&str],
     libraries: Vec<String>,
-
     via_ir: bool,
 ) {
     let sources = crate::common::read_sources(sources);
@@ -325,7 +433,6 @@ fn libraries_passed_post_compile_time_complex(
         solx_solc::StandardJsonInputLibraries::default(),
         era_compiler_common::HashType::None,
         BTreeSet::new(),
-        &solx_solc::Compiler::LAST_SUPPORTED_VERSION,
         via_ir,
         era_compiler_llvm_context::OptimizerSettings::none(),
     )
@@ -333,7 +440,7 @@ fn libraries_passed_post_compile_time_complex(
     let bytecodes = build
         .contracts
         .into_iter()
-        .map(|(path, contracts)| {
+        .flat_map(|(path, contracts)| {
             contracts
                 .into_iter()
                 .map(|(name, contract)| {
@@ -347,11 +454,10 @@ fn libraries_passed_post_compile_time_complex(
                 })
                 .collect::<BTreeMap<String, String>>()
         })
-        .flatten()
         .collect::<BTreeMap<String, String>>();
 
     let input = solx::LinkerInput::new(bytecodes, libraries);
-    let output = solx::Linker::link_eravm(input).expect("Linker failed");
+    let output = solx::Linker::link(input).expect("Linker failed");
     assert!(!output.linked.is_empty(), "No linked objects found");
     assert!(
         !output.ignored.is_empty(),
@@ -384,7 +490,6 @@ fn libraries_not_passed_post_compile_time_complex(sources: &[&str], via_ir: bool
         solx_solc::StandardJsonInputLibraries::default(),
         era_compiler_common::HashType::None,
         BTreeSet::new(),
-        &solx_solc::Compiler::LAST_SUPPORTED_VERSION,
         via_ir,
         era_compiler_llvm_context::OptimizerSettings::none(),
     )
@@ -392,7 +497,7 @@ fn libraries_not_passed_post_compile_time_complex(sources: &[&str], via_ir: bool
     let bytecodes = build
         .contracts
         .into_iter()
-        .map(|(path, contracts)| {
+        .flat_map(|(path, contracts)| {
             contracts
                 .into_iter()
                 .map(|(name, contract)| {
@@ -406,14 +511,64 @@ fn libraries_not_passed_post_compile_time_complex(sources: &[&str], via_ir: bool
                 })
                 .collect::<BTreeMap<String, String>>()
         })
-        .flatten()
         .collect::<BTreeMap<String, String>>();
 
     let input = solx::LinkerInput::new(bytecodes, vec![]);
-    let output = solx::Linker::link_eravm(input).expect("Linker failed");
+    let output = solx::Linker::link(input).expect("Linker failed");
     assert!(
         !output.ignored.is_empty(),
         "No objects were linked at compile time"
     );
     assert!(!output.unlinked.is_empty(), "No unlinked objects found");
 }
+
+#[test]
+fn libraries_partially_passed_post_compile_time() {
+    let upper_placeholder =
+        solx::Linker::placeholder("tests/data/contracts/solidity/Libraries.sol:UpperLibrary");
+    let lower_placeholder =
+        solx::Linker::placeholder("tests/data/contracts/solidity/Libraries.sol:LowerLibrary");
+    let bytecode = format!("73{upper_placeholder}5073{lower_placeholder}50");
+
+    let tmp_dir = tempfile::TempDir::with_prefix("solx_linker").expect("Always valid");
+    let path = tmp_dir.path().join("Partial.bin");
+    std::fs::write(path.as_path(), bytecode.as_bytes()).expect("Always valid");
+
+    let output = solx::link_evm(
+        &[path.clone()],
+        vec!["tests/data/contracts/solidity/Libraries.sol:UpperLibrary=0x1234567890abcdef1234567890abcdef12345678".to_owned()],
+    )
+    .expect("Linker failed");
+    let unlinked = output
+        .unlinked
+        .get(path.to_string_lossy().as_ref())
+        .expect("The bytecode is linked");
+    assert_eq!(unlinked.linker_symbols, vec![lower_placeholder.clone()]);
+
+    let bytecode_partially_linked =
+        format!("731234567890abcdef1234567890abcdef123456785073{lower_placeholder}50");
+    assert_eq!(
+        unlinked.bytecode.as_deref(),
+        Some(bytecode_partially_linked.as_str())
+    );
+    assert_eq!(
+        std::fs::read_to_string(path.as_path()).expect("Always valid"),
+        bytecode_partially_linked,
+        "The partially linked bytecode is not written back"
+    );
+}
+
+#[test]
+fn invalid_hexadecimal() {
+    let mut bytecodes = BTreeMap::new();
+    bytecodes.insert(
+        "Invalid.bin".to_owned(),
+        "Invalid hexadecimal string".to_owned(),
+    );
+
+    let input = solx::LinkerInput::new(bytecodes, vec![]);
+    let error = solx::Linker::link(input).expect_err("Linker must fail");
+    assert!(error
+        .to_string()
+        .contains("Object `Invalid.bin` hexadecimal string decoding"));
+}
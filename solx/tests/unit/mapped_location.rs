@@ -0,0 +1,127 @@
+//!
+//! Unit tests for the diagnostic source location rendering.
+//!
+
+use test_case::test_case;
+
+const SOURCE_CODE: &str =
+    "contract Test {\n    function f() public {\n        string memory s = \"ÿÿ\"; x;\n    }\n}\n";
+
+fn mapped_location(start: isize, end: isize) -> String {
+    let source_location = solx_solc::StandardJsonOutputErrorSourceLocation::new_with_offsets(
+        "Test.sol".to_owned(),
+        start,
+        end,
+    );
+    solx_solc::StandardJsonOutputErrorMappedLocation::try_from_source_location(
+        &source_location,
+        Some(SOURCE_CODE),
+    )
+    .to_string()
+}
+
+fn offset(pattern: &str) -> isize {
+    SOURCE_CODE.find(pattern).expect("Always exists") as isize
+}
+
+#[test]
+fn single_line() {
+    let start = offset("function");
+    assert_eq!(
+        mapped_location(start, start + "function".len() as isize),
+        "  --> Test.sol:2:5\n   |\n 2 |     function f() public {\n   |     ^^^^^^^^\n"
+    );
+}
+
+#[test]
+fn multi_line() {
+    let start = offset("function");
+    let end = offset("}\n}") + 1;
+    assert_eq!(
+        mapped_location(start, end),
+        "  --> Test.sol:2:5\n   |\n 2 |     function f() public {\n   |     ^^^^^^^^^^^^^^^^^^^^^\n 3 |         string memory s = \"ÿÿ\"; x;\n   |         ^^^^^^^^^^^^^^^^^^^^^^^^^^\n 4 |     }\n   |     ^\n"
+    );
+}
+
+#[test]
+fn utf8_columns() {
+    let start = offset("x;");
+    let source_location = solx_solc::StandardJsonOutputErrorSourceLocation::new_with_offsets(
+        "Test.sol".to_owned(),
+        start,
+        start + 1,
+    );
+    let mapped_location =
+        solx_solc::StandardJsonOutputErrorMappedLocation::try_from_source_location(
+            &source_location,
+            Some(SOURCE_CODE),
+        );
+    assert_eq!(mapped_location.line, Some(3));
+    assert_eq!(mapped_location.column, Some(32));
+    assert_eq!(mapped_location.length, Some(1));
+}
+
+#[test_case(-1, 0 ; "negative")]
+#[test_case(10, 5 ; "reversed")]
+#[test_case(1000, 1001 ; "out of bounds")]
+fn invalid(start: isize, end: isize) {
+    assert_eq!(mapped_location(start, end), "--> Test.sol\n");
+}
+
+#[test]
+fn secondary_source_locations() {
+    let mut sources = std::collections::BTreeMap::new();
+    sources.insert(
+        "Test.sol".to_owned(),
+        solx_solc::StandardJsonInputSource::from(SOURCE_CODE.to_owned()),
+    );
+    let start = offset("function");
+    let mut error = solx_solc::StandardJsonOutputError::new_error(
+        None,
+        "Test error",
+        Some(
+            solx_solc::StandardJsonOutputErrorSourceLocation::new_with_offsets(
+                "Test.sol".to_owned(),
+                start,
+                start + "function".len() as isize,
+            ),
+        ),
+        None,
+    );
+    error.secondary_source_locations.push(
+        solx_solc::StandardJsonOutputErrorSecondarySourceLocation::new(
+            "Test.sol".to_owned(),
+            start,
+            start + "function".len() as isize,
+            Some("Declared here.".to_owned()),
+        ),
+    );
+    error.render_with_sources(&sources);
+    assert!(error
+        .formatted_message
+        .ends_with("Note: Declared here.\n  --> Test.sol:2:5\n   |\n 2 |     function f() public {\n   |     ^^^^^^^^\n\n"));
+}
+
+#[test]
+fn colored() {
+    let start = offset("function");
+    let source_location = solx_solc::StandardJsonOutputErrorSourceLocation::new_with_offsets(
+        "Test.sol".to_owned(),
+        start,
+        start + "function".len() as isize,
+    );
+    let mapped_location =
+        solx_solc::StandardJsonOutputErrorMappedLocation::try_from_source_location(
+            &source_location,
+            Some(SOURCE_CODE),
+        );
+    let mut colored = String::new();
+    mapped_location
+        .write(
+            &mut colored,
+            Some(solx_solc::StandardJsonOutputErrorMappedLocation::COLOR_WARNING),
+        )
+        .expect("Always valid");
+    assert!(colored.contains("\x1b[1;34m-->\x1b[0m Test.sol:2:5\n"));
+    assert!(colored.contains("\x1b[1;33m^^^^^^^^\x1b[0m\n"));
+}
@@ -2,9 +2,14 @@
 //! The unit tests entry module.
 //!
 
+mod evm_version;
 mod ir_artifacts;
 mod libraries;
-// mod linker; TODO: Fix the linker
+mod linker;
+mod mapped_location;
 // mod optimizer; TODO: Fix stack-too-deep
+mod process_limits;
 mod remappings;
+mod source_map;
 mod standard_json;
+mod warnings;
@@ -0,0 +1,46 @@
+//!
+//! Unit tests for the compilation subprocess limits.
+//!
+
+#![cfg(unix)]
+
+use std::os::unix::process::ExitStatusExt;
+use std::process::ExitStatus;
+
+use test_case::test_case;
+
+#[test_case(
+    Some(64),
+    libc::SIGABRT,
+    "memory allocation of 1048576 bytes failed",
+    true
+)]
+#[test_case(Some(64), libc::SIGABRT, "LLVM ERROR: out of memory", true)]
+#[test_case(
+    Some(64),
+    101 << 8,
+    "failed to spawn thread: Os { code: 12, kind: OutOfMemory, message: \"Cannot allocate memory\" }",
+    true
+)]
+#[test_case(Some(64), libc::SIGABRT, "assertion failed", false)]
+#[test_case(Some(64), libc::SIGKILL, "", false)]
+#[test_case(
+    None,
+    libc::SIGABRT,
+    "memory allocation of 1048576 bytes failed",
+    false
+)]
+fn memory_limit_exceeded(
+    memory_limit: Option<u64>,
+    raw_status: i32,
+    stderr: &str,
+    is_exceeded: bool,
+) {
+    let limits = solx::ProcessLimits::new(None, memory_limit);
+    let status = ExitStatus::from_raw(raw_status);
+
+    assert_eq!(
+        limits.is_memory_limit_exceeded(&status, stderr.as_bytes()),
+        is_exceeded
+    );
+}
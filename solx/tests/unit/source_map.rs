@@ -0,0 +1,92 @@
+//!
+//! Unit tests for the source map.
+//!
+
+use solx::source_map::entry::Entry;
+use solx::source_map::jump::Jump;
+use solx::source_map::SourceMap;
+
+#[test]
+fn jump_types() {
+    let bytecode = [
+        0x60, 0x05, // PUSH1 0x05, the return address
+        0x60, 0x07, // PUSH1 0x07, the function
+        0x56, // JUMP into the function
+        0x5b, // JUMPDEST
+        0x00, // STOP
+        0x5b, // JUMPDEST, the function
+        0x56, // JUMP out of the function
+    ];
+    let locations = [
+        (0, Entry::new(0, 16, 0, Jump::Regular)),
+        (8, Entry::new(20, 4, 0, Jump::Regular)),
+    ];
+
+    let source_map =
+        SourceMap::from_locations(bytecode.as_slice(), bytecode.len(), locations.as_slice());
+    let jumps = source_map
+        .entries
+        .iter()
+        .map(|entry| entry.jump)
+        .collect::<Vec<Jump>>();
+    assert_eq!(
+        jumps,
+        vec![
+            Jump::Regular,
+            Jump::Regular,
+            Jump::In,
+            Jump::Regular,
+            Jump::Regular,
+            Jump::Regular,
+            Jump::Out,
+        ]
+    );
+    assert_eq!(source_map.to_string(), "0:16:0:-:0;;:::i;:::-;;;20:4::o");
+}
+
+#[test]
+fn regular_jump() {
+    let bytecode = [
+        0x60, 0x04, // PUSH1 0x04
+        0x56, // JUMP
+        0xfe, // INVALID
+        0x5b, // JUMPDEST
+        0x00, // STOP
+    ];
+    let locations = [
+        (0, Entry::new(0, 8, 0, Jump::Regular)),
+        (5, Entry::default()),
+    ];
+
+    let source_map =
+        SourceMap::from_locations(bytecode.as_slice(), bytecode.len(), locations.as_slice());
+    assert_eq!(source_map.entries[1].jump, Jump::Regular);
+    assert_eq!(source_map.to_string(), "0:8:0:-:0;;;;-1:-1:-1");
+}
+
+#[test]
+fn trailing_instructions() {
+    let bytecode = [
+        0x60, 0x00, // PUSH1 0x00
+        0x60, 0x00, // PUSH1 0x00
+        0xfd, // REVERT
+        0xfe, // INVALID
+        0xaa, 0xbb, // data
+    ];
+    let locations = [(0, Entry::new(0, 8, 0, Jump::Regular))];
+
+    let source_map = SourceMap::from_locations(bytecode.as_slice(), 6, locations.as_slice());
+    assert_eq!(source_map.entries.len(), 4);
+    assert_eq!(source_map.to_string(), "0:8:0:-:0;;;");
+}
+
+#[test]
+fn without_locations() {
+    let bytecode = [
+        0x60, 0x00, // PUSH1 0x00
+        0x00, // STOP
+    ];
+
+    let source_map = SourceMap::from_locations(bytecode.as_slice(), bytecode.len(), &[]);
+    assert_eq!(source_map.to_string(), "-1:-1:-1:-:0;");
+}
@@ -2,8 +2,11 @@
 //! Unit tests for standard JSON for all supported languages.
 //!
 
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 
+use test_case::test_case;
+
 #[test]
 fn standard_json_yul_solc() {
     let solc_input = solx_solc::StandardJsonInput::try_from(Some(
@@ -103,7 +106,6 @@ fn standard_json_yul_solc_urls_validated() {
 }
 
 #[test]
-#[should_panic]
 fn standard_json_llvm_ir_urls() {
     let solc_input = solx_solc::StandardJsonInput::try_from(Some(
         PathBuf::from("tests/data/standard_json_input/llvm_ir_urls.json").as_path(),
@@ -126,3 +128,136 @@ fn standard_json_llvm_ir_urls() {
         .object
         .is_empty())
 }
+
+#[test_case(false)]
+#[test_case(true)]
+fn immutable_references(via_ir: bool) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_IMMUTABLE_PATH]);
+
+    let build = crate::common::build_solidity_standard_json(
+        sources,
+        solx_solc::StandardJsonInputLibraries::default(),
+        era_compiler_common::HashType::None,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::none(),
+    )
+    .expect("Build failure");
+    let evm = build
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_IMMUTABLE_PATH)
+        .expect("Missing file")
+        .get("Immutable")
+        .expect("Missing contract")
+        .evm
+        .as_ref()
+        .expect("Missing EVM data");
+
+    let immutable_references = evm
+        .deployed_bytecode
+        .as_ref()
+        .expect("Missing runtime bytecode")
+        .immutable_references
+        .as_ref()
+        .expect("Missing immutable references");
+    assert_eq!(immutable_references.len(), 1, "Invalid immutable count");
+    assert!(immutable_references
+        .values()
+        .flatten()
+        .all(|reference| reference.length == era_compiler_common::BYTE_LENGTH_FIELD));
+
+    assert!(
+        evm.bytecode
+            .as_ref()
+            .expect("Missing deploy bytecode")
+            .immutable_references
+            .is_none(),
+        "The deploy bytecode must not have immutable references"
+    );
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn assembly_data_layout(via_ir: bool) {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let build = crate::common::build_solidity_standard_json(
+        sources,
+        solx_solc::StandardJsonInputLibraries::default(),
+        era_compiler_common::HashType::None,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::none(),
+    )
+    .expect("Build failure");
+    let evm = build
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+        .expect("Missing file")
+        .values()
+        .next()
+        .expect("Missing contract")
+        .evm
+        .as_ref()
+        .expect("Missing EVM data");
+
+    let deploy_bytecode = &evm
+        .bytecode
+        .as_ref()
+        .expect("Missing deploy bytecode")
+        .object;
+    let runtime_bytecode = &evm
+        .deployed_bytecode
+        .as_ref()
+        .expect("Missing runtime bytecode")
+        .object;
+    assert!(
+        deploy_bytecode.ends_with(runtime_bytecode.as_str()),
+        "The runtime code is not appended to the deploy code"
+    );
+
+    let assembly = evm.assembly.as_ref().expect("Missing assembly");
+    let runtime_identifier = assembly
+        .lines()
+        .filter_map(|line| line.strip_prefix(".code "))
+        .nth(1)
+        .expect("Missing runtime code");
+    let runtime_offset = (deploy_bytecode.len() - runtime_bytecode.len()) / 2;
+    assert!(
+        assembly
+            .contains(format!(".data {runtime_identifier} /* {runtime_offset:04x} */").as_str()),
+        "The runtime code data segment is not located at the code end"
+    );
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn assembly_unlinked(via_ir: bool) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let build = crate::common::build_solidity_standard_json(
+        sources,
+        solx_solc::StandardJsonInputLibraries::default(),
+        era_compiler_common::HashType::None,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::none(),
+    )
+    .expect("Build failure");
+    let evm = build
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH)
+        .expect("Missing file")
+        .get("SimpleContract")
+        .expect("Missing contract")
+        .evm
+        .as_ref()
+        .expect("Missing EVM data");
+
+    assert!(
+        evm.assembly.is_none(),
+        "The assembly of an unlinked contract must be omitted"
+    );
+}
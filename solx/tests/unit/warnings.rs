@@ -0,0 +1,66 @@
+//!
+//! Unit tests for warning suppression and promotion.
+//!
+
+use std::str::FromStr;
+
+use test_case::test_case;
+
+fn warning(code: &str, path: &str) -> solx_solc::StandardJsonOutputError {
+    solx_solc::StandardJsonOutputError::new_warning(
+        Some(code.parse().expect("Always valid")),
+        "Test warning",
+        Some(solx_solc::StandardJsonOutputErrorSourceLocation::new(
+            path.to_owned(),
+        )),
+        None,
+    )
+}
+
+#[test_case("1234", "contracts/Test.sol", true)]
+#[test_case("1234", "contracts/lib/Test.sol", true)]
+#[test_case("4321", "contracts/Test.sol", false)]
+#[test_case("*:contracts/*.sol", "contracts/Test.sol", true)]
+#[test_case("*:contracts/*.sol", "contracts/lib/Test.sol", false)]
+#[test_case("*:contracts/**", "contracts/lib/Test.sol", true)]
+#[test_case("*:**/Test.sol", "contracts/lib/Test.sol", true)]
+#[test_case("1234:contracts/?est.sol", "contracts/Test.sol", true)]
+#[test_case("4321:contracts/**", "contracts/Test.sol", false)]
+fn filter(filter: &str, path: &str, is_matched: bool) {
+    let filter =
+        solx_solc::StandardJsonInputWarningsFilter::from_str(filter).expect("Test failure");
+    assert_eq!(filter.matches(&warning("1234", path)), is_matched);
+}
+
+#[test_case("")]
+#[test_case(":contracts/**")]
+#[test_case("1234:")]
+fn filter_invalid(filter: &str) {
+    assert!(solx_solc::StandardJsonInputWarningsFilter::from_str(filter).is_err());
+}
+
+#[test]
+fn apply() {
+    let settings = solx_solc::StandardJsonInputWarnings::new(
+        vec![
+            solx_solc::StandardJsonInputWarningsFilter::from_str("*:lib/**").expect("Test failure"),
+        ],
+        vec![solx_solc::StandardJsonInputWarningsFilter::from_str("2222").expect("Test failure")],
+        vec![],
+        true,
+    );
+
+    let mut messages = vec![
+        warning("1111", "lib/Library.sol"),
+        warning("2222", "src/Test.sol"),
+        warning("3333", "src/Test.sol"),
+    ];
+    settings.apply(&mut messages);
+
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].severity, "warning");
+    assert_eq!(messages[1].severity, "error");
+    assert!(messages[1]
+        .formatted_message
+        .starts_with("Error: Test warning"));
+}